@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use radix_engine_common::crypto::IsHash;
+
 /// An ephemeral (never persisted) HD PrivateKey which contains
 /// the derivation path used to derive it.
 #[derive(Debug, PartialEq, Eq)]
@@ -34,6 +36,12 @@ impl HierarchicalDeterministicPrivateKey {
         )
     }
 
+    /// Signs the `msg_hash` with the private key, returning the signature
+    /// together with the public key.
+    pub fn sign(&self, msg_hash: &impl IsHash) -> SignatureWithPublicKey {
+        self.private_key.sign(msg_hash)
+    }
+
     /// The PrivateKey as hex string.
     pub fn to_hex(&self) -> String {
         self.private_key.to_hex()
@@ -102,4 +110,13 @@ mod tests {
             "d24cc6af91c3f103d7f46e5691ce2af9fea7d90cfb89a89d5bba4b513b34be3b"
         );
     }
+
+    #[test]
+    fn sign_includes_public_key() {
+        let sut = HierarchicalDeterministicPrivateKey::placeholder();
+        assert_eq!(
+            sut.sign(&hash("Test")).public_key(),
+            sut.public_key().public_key
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use radix_engine_common::crypto::IsHash;
+
 /// The **source** of a virtual hierarchical deterministic badge, contains a
 /// derivation path and public key, from which a private key is derived which
 /// produces virtual badges (signatures).
@@ -27,6 +29,16 @@ impl HierarchicalDeterministicPublicKey {
 }
 
 impl HierarchicalDeterministicPublicKey {
+    /// Verifies that `signature` is a valid signature of `for_hash` by the
+    /// private key derived at `derivation_path`.
+    pub fn verify(
+        &self,
+        signature: &Signature,
+        for_hash: &impl IsHash,
+    ) -> bool {
+        self.public_key.verify(signature, for_hash)
+    }
+
     pub fn to_hex(&self) -> String {
         self.public_key.to_hex()
     }
@@ -102,6 +114,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify() {
+        let private_key = MnemonicWithPassphrase::placeholder()
+            .derive_private_key(AccountPath::new(
+                NetworkID::Mainnet,
+                CAP26KeyKind::TransactionSigning,
+                0,
+            ));
+        let sut = HierarchicalDeterministicPublicKey::placeholder();
+        let signature = private_key.sign(&hash("Test")).signature();
+        assert!(sut.verify(&signature, &hash("Test")));
+        assert!(!HierarchicalDeterministicPublicKey::placeholder_other()
+            .verify(&signature, &hash("Test")));
+    }
+
     #[test]
     fn json() {
         let model = HierarchicalDeterministicPublicKey::placeholder();
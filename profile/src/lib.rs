@@ -3,6 +3,7 @@
 mod hierarchical_deterministic;
 mod identified_vec_via;
 mod logic;
+mod olympia;
 mod profilesnapshot_version;
 mod v100;
 mod wallet;
@@ -13,6 +14,7 @@ pub mod prelude {
     pub use crate::hierarchical_deterministic::*;
     pub use crate::identified_vec_via::*;
     pub use crate::logic::*;
+    pub use crate::olympia::*;
     pub use crate::profilesnapshot_version::*;
     pub use crate::v100::*;
     pub use crate::wallet::*;
@@ -89,4 +91,16 @@ impl UniffiCustomTypeConverter for Uuid {
     }
 }
 
+// Use `Decimal` as a custom type, with its base 10 `String` as the Builtin
+#[cfg(not(tarpaulin_include))] // Tested in binding tests (e.g. test*.swift files)
+impl UniffiCustomTypeConverter for Decimal {
+    type Builtin = String;
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Decimal::new(val).map_err(|e| e.into())
+    }
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.to_string()
+    }
+}
+
 uniffi::include_scaffolding!("radix_wallet_kit");
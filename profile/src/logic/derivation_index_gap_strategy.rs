@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+/// How holes among the derivation indices already used by a factor source
+/// are treated when deriving keys for new entities, holes typically stem
+/// from entities having been imported or recovered out of order.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DerivationIndexGapStrategy {
+    /// Unused indices lower than the highest used index are used first.
+    Fill,
+
+    /// Only indices higher than the highest used index are used.
+    Skip,
+}
+
+impl DerivationIndexGapStrategy {
+    /// Returns the `count` lowest indices not in `used` according to this
+    /// strategy, in increasing order.
+    pub fn next_indices(
+        &self,
+        used: &BTreeSet<HDPathValue>,
+        count: usize,
+    ) -> Vec<HDPathValue> {
+        let start = match self {
+            Self::Fill => 0,
+            Self::Skip => used.last().map(|i| i + 1).unwrap_or(0),
+        };
+        (start..)
+            .filter(|i| !used.contains(i))
+            .take(count)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn used(indices: &[HDPathValue]) -> BTreeSet<HDPathValue> {
+        indices.iter().cloned().collect()
+    }
+
+    #[test]
+    fn fill_without_gaps() {
+        assert_eq!(
+            DerivationIndexGapStrategy::Fill.next_indices(&used(&[0, 1]), 2),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn fill_with_gaps() {
+        assert_eq!(
+            DerivationIndexGapStrategy::Fill.next_indices(&used(&[0, 2, 5]), 4),
+            vec![1, 3, 4, 6]
+        );
+    }
+
+    #[test]
+    fn skip_with_gaps() {
+        assert_eq!(
+            DerivationIndexGapStrategy::Skip.next_indices(&used(&[0, 2, 5]), 2),
+            vec![6, 7]
+        );
+    }
+
+    #[test]
+    fn none_used() {
+        assert_eq!(
+            DerivationIndexGapStrategy::Fill.next_indices(&used(&[]), 2),
+            vec![0, 1]
+        );
+        assert_eq!(
+            DerivationIndexGapStrategy::Skip.next_indices(&used(&[]), 2),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &DerivationIndexGapStrategy::Fill,
+            json!("fill"),
+        );
+        assert_json_value_eq_after_roundtrip(
+            &DerivationIndexGapStrategy::Skip,
+            json!("skip"),
+        );
+    }
+}
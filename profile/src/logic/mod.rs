@@ -1,5 +1,9 @@
 #![allow(unused_imports)]
 
+mod derivation_index_gap_strategy;
 mod profile_next_derivation;
+mod profile_snapshot_migration;
 
+pub use derivation_index_gap_strategy::*;
 pub use profile_next_derivation::*;
+pub use profile_snapshot_migration::*;
@@ -57,35 +57,91 @@ impl Profile {
         explicit_main.unwrap_or(implicit_main).clone()
     }
 
-    fn next_derivation_index_for_entity_for_factor_source(
+    /// The next derivation index for a new entity of `kind` on `network_id`
+    /// controlled by the factor source with `factor_source_id`, i.e. the
+    /// index after the highest used index, so that it never collides with
+    /// the key of an existing entity, even if there are holes among the used
+    /// indices.
+    pub fn next_derivation_index_for_entity_for_factor_source(
         &self,
         kind: EntityKind,
         network_id: NetworkID,
         factor_source_id: FactorSourceIDFromHash,
     ) -> HDPathValue {
-        match kind {
-            EntityKind::Persona => panic!("Personas are not supported yet"),
-            EntityKind::Accounts => {}
+        self.next_derivation_indices_for_entity_for_factor_source(
+            kind,
+            network_id,
+            &factor_source_id,
+            1,
+            DerivationIndexGapStrategy::Skip,
+        )
+        .first()
+        .cloned()
+        .expect("There is always a next index")
+    }
+
+    /// The derivation indices of the keys of the entities of `kind` on
+    /// `network_id` created by the factor source with `factor_source_id`,
+    /// i.e. of the transaction signing key of unsecurified entities and of
+    /// all factor instances of securified entities.
+    pub fn used_derivation_indices_for_entity_for_factor_source(
+        &self,
+        kind: EntityKind,
+        network_id: NetworkID,
+        factor_source_id: &FactorSourceIDFromHash,
+    ) -> BTreeSet<HDPathValue> {
+        let used_indices = |security_state: &EntitySecurityState| {
+            let factor_instances = match security_state {
+                EntitySecurityState::Unsecured { value } => {
+                    vec![value.transaction_signing.clone()]
+                }
+                EntitySecurityState::Securified { value } => {
+                    value.all_factor_instances()
+                }
+            };
+            factor_instances
+                .into_iter()
+                .filter(|fi| fi.factor_source_id == *factor_source_id)
+                .map(|fi| fi.derivation_path().last_component().index())
+                .collect_vec()
         };
-        let index = self
-            .networks
+
+        self.networks
             .get(&network_id)
-            .map(|n| {
-                n.accounts
-                    .items()
-                    .into_iter()
-                    .filter(|a| match &a.security_state {
-                        EntitySecurityState::Unsecured { value } => {
-                            value.transaction_signing.factor_source_id
-                                == factor_source_id
-                        }
-                    })
-                    .collect_vec()
-                    .len()
+            .map(|n| match kind {
+                EntityKind::Accounts => n
+                    .accounts
+                    .iter()
+                    .flat_map(|a| used_indices(&a.security_state))
+                    .collect(),
+                EntityKind::Persona => n
+                    .personas
+                    .iter()
+                    .flat_map(|p| used_indices(&p.security_state))
+                    .collect(),
             })
-            .unwrap_or(0);
+            .unwrap_or_default()
+    }
 
-        index as u32
+    /// The `count` next derivation indices for new entities of `kind` on
+    /// `network_id` controlled by the factor source with `factor_source_id`,
+    /// treating holes among the used indices according to `gap_strategy`.
+    pub fn next_derivation_indices_for_entity_for_factor_source(
+        &self,
+        kind: EntityKind,
+        network_id: NetworkID,
+        factor_source_id: &FactorSourceIDFromHash,
+        count: usize,
+        gap_strategy: DerivationIndexGapStrategy,
+    ) -> Vec<HDPathValue> {
+        gap_strategy.next_indices(
+            &self.used_derivation_indices_for_entity_for_factor_source(
+                kind,
+                network_id,
+                factor_source_id,
+            ),
+            count,
+        )
     }
 
     pub fn next_derivation_index_for_entity(
@@ -235,6 +291,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn factor_source_by_id_trusted_contact() {
+        let mut profile = Profile::placeholder();
+        let trusted_contact = TrustedContactFactorSource::placeholder();
+        profile
+            .factor_sources
+            .append(trusted_contact.clone().into());
+        assert_eq!(
+            profile.factor_source_by_id::<TrustedContactFactorSource>(
+                &trusted_contact.factor_source_id()
+            ),
+            Ok(trusted_contact)
+        );
+    }
+
+    #[test]
+    fn factor_source_by_id_trusted_contact_wrong_kind() {
+        let mut profile = Profile::placeholder();
+        let trusted_contact = TrustedContactFactorSource::placeholder();
+        profile
+            .factor_sources
+            .append(trusted_contact.clone().into());
+        assert_eq!(
+            profile.factor_source_by_id::<DeviceFactorSource>(
+                &trusted_contact.factor_source_id()
+            ),
+            Err(CommonError::CastFactorSourceWrongKind {
+                expected: FactorSourceKind::Device,
+                found: FactorSourceKind::TrustedContact
+            })
+        );
+    }
+
     #[test]
     fn bdfs_success_without_explicit_main_flag() {
         let profile =
@@ -278,6 +367,138 @@ mod tests {
         );
     }
 
+    #[test]
+    fn next_derivation_index_for_entity_account_bdfs_mainnet_securified() {
+        let mut profile = Profile::placeholder();
+        let alice = Account::placeholder_mainnet_alice();
+        let role = RoleOfFactorInstances::new(
+            [alice
+                .security_state
+                .as_unsecured()
+                .unwrap()
+                .transaction_signing
+                .clone()],
+            1,
+            [],
+        )
+        .unwrap();
+        let securified = SecuredEntityControl::new(
+            AccessControllerAddress::placeholder(),
+            SecurityStructureOfFactorInstances::new(
+                Uuid::new_v4(),
+                MatrixOfFactorInstances::new(role.clone(), role.clone(), role),
+            ),
+        );
+        profile.update_account(&alice.address, |a| {
+            a.security_state = securified.clone().into()
+        });
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Accounts,
+                NetworkID::Mainnet
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn used_derivation_indices_for_entity_account_bdfs_mainnet() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile.used_derivation_indices_for_entity_for_factor_source(
+                EntityKind::Accounts,
+                NetworkID::Mainnet,
+                &profile.bdfs().id
+            ),
+            BTreeSet::from_iter([0, 1])
+        );
+    }
+
+    #[test]
+    fn next_derivation_index_for_entity_with_gap() {
+        let mut profile = Profile::placeholder();
+        let alice = Account::placeholder_mainnet_alice();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            _ = n.accounts.remove_by_id(&alice.address)
+        });
+        // Bob uses index 1, so 1 (the number of accounts) must not be used.
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Accounts,
+                NetworkID::Mainnet
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn used_derivation_indices_for_entity_includes_securified() {
+        let mut profile = Profile::placeholder();
+        let alice = Account::placeholder_mainnet_alice();
+        let role = RoleOfFactorInstances::new(
+            [alice
+                .security_state
+                .as_unsecured()
+                .unwrap()
+                .transaction_signing
+                .clone()],
+            1,
+            [],
+        )
+        .unwrap();
+        let securified = SecuredEntityControl::new(
+            AccessControllerAddress::placeholder(),
+            SecurityStructureOfFactorInstances::new(
+                Uuid::new_v4(),
+                MatrixOfFactorInstances::new(role.clone(), role.clone(), role),
+            ),
+        );
+        profile.update_account(&alice.address, |a| {
+            a.security_state = securified.clone().into()
+        });
+        assert_eq!(
+            profile.used_derivation_indices_for_entity_for_factor_source(
+                EntityKind::Accounts,
+                NetworkID::Mainnet,
+                &profile.bdfs().id
+            ),
+            BTreeSet::from_iter([0, 1])
+        );
+    }
+
+    #[test]
+    fn used_derivation_indices_for_entity_other_factor_source() {
+        let profile = Profile::placeholder();
+        assert!(profile
+            .used_derivation_indices_for_entity_for_factor_source(
+                EntityKind::Accounts,
+                NetworkID::Mainnet,
+                &FactorSourceIDFromHash::placeholder_ledger()
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn next_derivation_indices_for_entity_with_gap() {
+        let mut profile = Profile::placeholder();
+        let alice = Account::placeholder_mainnet_alice();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            _ = n.accounts.remove_by_id(&alice.address)
+        });
+        let bdfs_id = profile.bdfs().id;
+        let next = |gap_strategy| {
+            profile.next_derivation_indices_for_entity_for_factor_source(
+                EntityKind::Accounts,
+                NetworkID::Mainnet,
+                &bdfs_id,
+                2,
+                gap_strategy,
+            )
+        };
+        assert_eq!(next(DerivationIndexGapStrategy::Fill), vec![0, 2]);
+        assert_eq!(next(DerivationIndexGapStrategy::Skip), vec![2, 3]);
+    }
+
     #[test]
     fn next_derivation_index_for_entity_account_bdfs_stokenet() {
         let profile = Profile::placeholder();
@@ -302,4 +523,54 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn next_derivation_index_for_entity_persona_bdfs_mainnet_no_personas() {
+        let profile = Profile::placeholder();
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Persona,
+                NetworkID::Mainnet
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn next_derivation_index_for_entity_persona_bdfs_mainnet() {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.personas = Personas::placeholder_mainnet()
+        });
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Persona,
+                NetworkID::Mainnet
+            ),
+            2
+        );
+        assert_eq!(
+            profile.next_derivation_index_for_entity(
+                EntityKind::Persona,
+                NetworkID::Stokenet
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn next_derivation_index_for_entity_persona_olympia_dfs_mainnet() {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.personas = Personas::placeholder_mainnet()
+        });
+        assert_eq!(
+            profile.next_derivation_index_for_entity_for_factor_source(
+                EntityKind::Persona,
+                NetworkID::Mainnet,
+                DeviceFactorSource::placeholder_olympia().id
+            ),
+            0
+        );
+    }
 }
@@ -0,0 +1,240 @@
+use crate::prelude::*;
+
+use serde_json::Value;
+
+/// A transform of the raw JSON of a Profile snapshot with snapshot version
+/// `from` into the JSON of snapshot version `to`.
+struct ProfileSnapshotMigration {
+    from: u16,
+    to: u16,
+    migrate: fn(&mut Value),
+}
+
+/// All migrations, ordered by snapshot version, each migrating the JSON to
+/// the `from` version of the next migration, the last one migrating to the
+/// current `ProfileSnapshotVersion`.
+///
+/// Empty since `V100` is the first snapshot version, when a new version is
+/// introduced a migration from the previous version must be added here.
+const MIGRATIONS: &[ProfileSnapshotMigration] = &[];
+
+fn snapshot_version(json: &Value) -> Option<u16> {
+    json["header"]["snapshotVersion"]
+        .as_u64()
+        .and_then(|v| u16::try_from(v).ok())
+}
+
+impl Profile {
+    /// Decodes a Profile from the bytes of its JSON snapshot, migrating
+    /// the JSON from older snapshot versions to the current
+    /// `ProfileSnapshotVersion` before decoding it.
+    ///
+    /// Returns `Err` if the snapshot version is newer than the current
+    /// version, if there is no migration from the snapshot version or if
+    /// the (migrated) JSON is not a valid Profile snapshot.
+    pub fn from_json_bytes(json: &[u8]) -> Result<Self> {
+        Self::from_json_bytes_with_migrations(json, MIGRATIONS)
+    }
+
+    fn from_json_bytes_with_migrations(
+        json: &[u8],
+        migrations: &[ProfileSnapshotMigration],
+    ) -> Result<Self> {
+        let fail = || CommonError::FailedToDeserializeJSONToValue {
+            json_byte_count: json.len(),
+            type_name: std::any::type_name::<Self>().to_string(),
+        };
+        let mut value: Value =
+            serde_json::from_slice(json).map_err(|_| fail())?;
+        let mut version = snapshot_version(&value).ok_or_else(fail)?;
+        let current = ProfileSnapshotVersion::default().discriminant();
+
+        if version > current {
+            return Err(CommonError::ProfileSnapshotVersionTooNew {
+                found: version,
+                current,
+            });
+        }
+
+        while version < current {
+            let migration =
+                migrations.iter().find(|m| m.from == version).ok_or(
+                    CommonError::UnsupportedProfileSnapshotVersion(version),
+                )?;
+            debug!(
+                "Migrating Profile snapshot from version {} to {}",
+                migration.from, migration.to
+            );
+            (migration.migrate)(&mut value);
+            value["header"]["snapshotVersion"] = json!(migration.to);
+            version = migration.to;
+        }
+
+        serde_json::from_value(value).map_err(|_| fail())
+    }
+}
+
+/// Decodes a Profile from the bytes of its JSON snapshot, migrating it from
+/// older snapshot versions if needed.
+#[uniffi::export]
+pub fn new_profile_from_json_bytes(json: Vec<u8>) -> Result<Profile> {
+    Profile::from_json_bytes(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json_with_version(version: u16) -> serde_json::Value {
+        let mut json = serde_json::to_value(Profile::placeholder()).unwrap();
+        json["header"]["snapshotVersion"] = json!(version);
+        json
+    }
+
+    #[test]
+    fn current_version_is_not_migrated() {
+        let json = serde_json::to_vec(&Profile::placeholder()).unwrap();
+        assert_eq!(Profile::from_json_bytes(&json), Ok(Profile::placeholder()));
+    }
+
+    /// Pretend version 98 lacked `personas` and version 99 lacked
+    /// `authorizedDapps` on each network.
+    const TEST_MIGRATIONS: &[ProfileSnapshotMigration] = &[
+        ProfileSnapshotMigration {
+            from: 98,
+            to: 99,
+            migrate: |json| add_to_each_network(json, "personas"),
+        },
+        ProfileSnapshotMigration {
+            from: 99,
+            to: 100,
+            migrate: |json| add_to_each_network(json, "authorizedDapps"),
+        },
+    ];
+
+    fn add_to_each_network(json: &mut Value, key: &str) {
+        for network in json["networks"].as_array_mut().unwrap() {
+            _ = network
+                .as_object_mut()
+                .unwrap()
+                .entry(key)
+                .or_insert(json!([]))
+        }
+    }
+
+    fn json_with_version_without(
+        version: u16,
+        keys: &[&str],
+    ) -> serde_json::Value {
+        let mut json = json_with_version(version);
+        for network in json["networks"].as_array_mut().unwrap() {
+            let network = network.as_object_mut().unwrap();
+            keys.iter().for_each(|k| _ = network.remove(*k));
+        }
+        json
+    }
+
+    fn from_json_with_test_migrations(
+        json: serde_json::Value,
+    ) -> Result<Profile> {
+        Profile::from_json_bytes_with_migrations(
+            &serde_json::to_vec(&json).unwrap(),
+            TEST_MIGRATIONS,
+        )
+    }
+
+    #[test]
+    fn migrations_are_run_in_order() {
+        assert_eq!(
+            from_json_with_test_migrations(json_with_version_without(
+                98,
+                &["personas", "authorizedDapps"]
+            )),
+            Ok(Profile::placeholder())
+        );
+    }
+
+    #[test]
+    fn migrations_start_at_snapshot_version() {
+        assert_eq!(
+            from_json_with_test_migrations(json_with_version_without(
+                99,
+                &["authorizedDapps"]
+            )),
+            Ok(Profile::placeholder())
+        );
+    }
+
+    #[test]
+    fn migrations_older_than_first_migration() {
+        assert_eq!(
+            from_json_with_test_migrations(json_with_version(97)),
+            Err(CommonError::UnsupportedProfileSnapshotVersion(97))
+        );
+    }
+
+    #[test]
+    fn no_migrations_from_version_before_100() {
+        let json = serde_json::to_vec(&json_with_version(99)).unwrap();
+        assert_eq!(
+            Profile::from_json_bytes(&json),
+            Err(CommonError::UnsupportedProfileSnapshotVersion(99))
+        );
+    }
+
+    #[test]
+    fn too_new() {
+        let json = serde_json::to_vec(&json_with_version(101)).unwrap();
+        assert_eq!(
+            Profile::from_json_bytes(&json),
+            Err(CommonError::ProfileSnapshotVersionTooNew {
+                found: 101,
+                current: 100
+            })
+        );
+    }
+
+    #[test]
+    fn too_old() {
+        let json = serde_json::to_vec(&json_with_version(98)).unwrap();
+        assert_eq!(
+            Profile::from_json_bytes(&json),
+            Err(CommonError::UnsupportedProfileSnapshotVersion(98))
+        );
+    }
+
+    #[test]
+    fn missing_snapshot_version() {
+        let mut json = json_with_version(100);
+        json["header"]
+            .as_object_mut()
+            .unwrap()
+            .remove("snapshotVersion");
+        assert!(matches!(
+            Profile::from_json_bytes(&serde_json::to_vec(&json).unwrap()),
+            Err(CommonError::FailedToDeserializeJSONToValue { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert!(matches!(
+            Profile::from_json_bytes(&[0xde, 0xad]),
+            Err(CommonError::FailedToDeserializeJSONToValue { .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_profile_from_json_bytes() {
+        let json = serde_json::to_vec(&Profile::placeholder()).unwrap();
+        assert_eq!(
+            super::new_profile_from_json_bytes(json),
+            Ok(Profile::placeholder())
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod olympia_account_to_migrate;
+mod olympia_account_type;
+mod olympia_wallet_export;
+
+pub use olympia_account_to_migrate::*;
+pub use olympia_account_type::*;
+pub use olympia_wallet_export::*;
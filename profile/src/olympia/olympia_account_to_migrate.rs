@@ -0,0 +1,167 @@
+use crate::prelude::*;
+
+/// An account of the Radix Olympia wallet, as read from an Olympia wallet
+/// export payload, which can be imported into Profile as a Babylon account
+/// controlled by the same secp256k1 key.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct OlympiaAccountToMigrate {
+    /// If the account is controlled by the Olympia wallet mnemonic or by a
+    /// Ledger hardware wallet.
+    pub account_type: OlympiaAccountType,
+
+    /// The secp256k1 public key controlling this account.
+    pub public_key: Secp256k1PublicKey,
+
+    /// The BIP44 like derivation path of `public_key`.
+    pub path: BIP44LikePath,
+
+    /// The name of the account in the Olympia wallet, if any.
+    pub display_name: Option<DisplayName>,
+}
+
+impl OlympiaAccountToMigrate {
+    /// Instantiates a new `OlympiaAccountToMigrate`, the derivation path is
+    /// formed from `address_index`.
+    pub fn new(
+        account_type: OlympiaAccountType,
+        public_key: Secp256k1PublicKey,
+        address_index: HDPathValue,
+        display_name: Option<DisplayName>,
+    ) -> Self {
+        Self {
+            account_type,
+            public_key,
+            path: BIP44LikePath::new(address_index),
+            display_name,
+        }
+    }
+
+    /// The address of the Babylon account virtually derived from the public
+    /// key of this Olympia account on `network_id`.
+    pub fn babylon_address(&self, network_id: NetworkID) -> AccountAddress {
+        AccountAddress::new(self.public_key.clone().into(), network_id)
+    }
+
+    /// Creates the Babylon `Account` on `network_id` controlled by the public
+    /// key of this Olympia account, derived by the factor source with
+    /// `factor_source_id`.
+    pub fn to_babylon_account(
+        &self,
+        network_id: NetworkID,
+        factor_source_id: FactorSourceIDFromHash,
+        appearance_id: AppearanceID,
+    ) -> Account {
+        let transaction_signing = HierarchicalDeterministicFactorInstance::new(
+            factor_source_id,
+            HierarchicalDeterministicPublicKey::new(
+                self.public_key.clone().into(),
+                self.path.derivation_path(),
+            ),
+        );
+        let display_name = self.display_name.clone().unwrap_or_default();
+        Account {
+            network_id,
+            address: self.babylon_address(network_id),
+            display_name,
+            security_state:
+                UnsecuredEntityControl::with_transaction_signing_only(
+                    transaction_signing,
+                )
+                .expect("BIP44 like paths have no key kind")
+                .into(),
+            appearance_id,
+            flags: EntityFlags::default(),
+            on_ledger_settings: OnLedgerSettings::default(),
+        }
+    }
+}
+
+impl HasPlaceholder for OlympiaAccountToMigrate {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            OlympiaAccountType::Software,
+            Secp256k1PublicKey::placeholder_alice(),
+            0,
+            DisplayName::new("Savings").ok(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            OlympiaAccountType::Hardware,
+            Secp256k1PublicKey::placeholder_bob(),
+            1,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            OlympiaAccountToMigrate::placeholder(),
+            OlympiaAccountToMigrate::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            OlympiaAccountToMigrate::placeholder_other()
+                .path
+                .to_string(),
+            "m/44H/1022H/0H/0/1H"
+        );
+    }
+
+    #[test]
+    fn babylon_address_depends_on_network() {
+        let sut = OlympiaAccountToMigrate::placeholder();
+        assert_ne!(
+            sut.babylon_address(NetworkID::Mainnet),
+            sut.babylon_address(NetworkID::Stokenet)
+        );
+    }
+
+    #[test]
+    fn to_babylon_account() {
+        let sut = OlympiaAccountToMigrate::placeholder();
+        let account = sut.to_babylon_account(
+            NetworkID::Mainnet,
+            FactorSourceIDFromHash::placeholder_other(),
+            AppearanceID::default(),
+        );
+        assert_eq!(account.address, sut.babylon_address(NetworkID::Mainnet));
+        assert_eq!(account.display_name.value, "Savings");
+        let value = account.security_state.as_unsecured().unwrap();
+        assert_eq!(
+            value.transaction_signing.factor_source_id,
+            FactorSourceIDFromHash::placeholder_other()
+        );
+        assert_eq!(
+            value.transaction_signing.public_key.public_key,
+            Secp256k1PublicKey::placeholder_alice().into()
+        );
+        assert_eq!(
+            value.transaction_signing.derivation_path(),
+            BIP44LikePath::placeholder().into()
+        );
+    }
+
+    #[test]
+    fn to_babylon_account_unnamed() {
+        let account = OlympiaAccountToMigrate::placeholder_other()
+            .to_babylon_account(
+                NetworkID::Mainnet,
+                FactorSourceIDFromHash::placeholder_ledger(),
+                AppearanceID::default(),
+            );
+        assert_eq!(account.display_name.value, "Unnamed");
+    }
+}
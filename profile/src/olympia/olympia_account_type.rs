@@ -0,0 +1,77 @@
+use crate::prelude::*;
+
+/// The kind of an account in the Radix Olympia wallet, either controlled by
+/// the mnemonic of the Olympia wallet or by a Ledger hardware wallet.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, uniffi::Enum,
+)]
+pub enum OlympiaAccountType {
+    /// An account controlled by the mnemonic of the Olympia wallet, which is
+    /// imported as a `DeviceFactorSource`.
+    Software,
+
+    /// An account controlled by a Ledger hardware wallet, which is imported
+    /// as a `LedgerHardwareWalletFactorSource`.
+    Hardware,
+}
+
+impl OlympiaAccountType {
+    /// The kind of factor source controlling accounts of this type.
+    pub fn factor_source_kind(&self) -> FactorSourceKind {
+        match self {
+            Self::Software => FactorSourceKind::Device,
+            Self::Hardware => FactorSourceKind::LedgerHQHardwareWallet,
+        }
+    }
+}
+
+impl FromStr for OlympiaAccountType {
+    type Err = CommonError;
+
+    /// Parses the account type of an account in an Olympia wallet export
+    /// payload, `"S"` for software and `"H"` for hardware accounts.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "S" => Ok(Self::Software),
+            "H" => Ok(Self::Hardware),
+            _ => Err(CommonError::InvalidOlympiaWalletExportPayload(
+                s.to_owned(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "S".parse::<OlympiaAccountType>(),
+            Ok(OlympiaAccountType::Software)
+        );
+        assert_eq!(
+            "H".parse::<OlympiaAccountType>(),
+            Ok(OlympiaAccountType::Hardware)
+        );
+        assert_eq!(
+            "X".parse::<OlympiaAccountType>(),
+            Err(CommonError::InvalidOlympiaWalletExportPayload(
+                "X".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn factor_source_kind() {
+        assert_eq!(
+            OlympiaAccountType::Software.factor_source_kind(),
+            FactorSourceKind::Device
+        );
+        assert_eq!(
+            OlympiaAccountType::Hardware.factor_source_kind(),
+            FactorSourceKind::LedgerHQHardwareWallet
+        );
+    }
+}
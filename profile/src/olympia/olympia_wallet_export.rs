@@ -0,0 +1,297 @@
+use crate::prelude::*;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// The accounts exported by the Radix Olympia wallet, read from one or many
+/// payloads, typically scanned as QR codes.
+///
+/// Each payload has the format:
+///
+/// `<payload count>^<payload index>^<mnemonic word count>]<accounts>`
+///
+/// Where `<accounts>` are `~` separated, each account having the format:
+///
+/// `<S|H>^<base64 compressed public key>^<address index>^<name>}`
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct OlympiaWalletExport {
+    /// The number of words of the mnemonic of the Olympia wallet.
+    pub mnemonic_word_count: BIP39WordCount,
+
+    /// The exported accounts, in the order of the payloads.
+    pub accounts: Vec<OlympiaAccountToMigrate>,
+}
+
+/// Parses the Olympia wallet export `payloads` into an `OlympiaWalletExport`.
+#[uniffi::export]
+pub fn new_olympia_wallet_export_from_payloads(
+    payloads: Vec<String>,
+) -> Result<OlympiaWalletExport> {
+    OlympiaWalletExport::from_payloads(payloads)
+}
+
+struct Payload {
+    count: usize,
+    index: usize,
+    mnemonic_word_count: BIP39WordCount,
+    accounts: Vec<OlympiaAccountToMigrate>,
+}
+
+impl OlympiaWalletExport {
+    const HEADER_END: char = ']';
+    const SEPARATOR: char = '^';
+    const ACCOUNT_SEPARATOR: char = '~';
+    const ACCOUNT_NAME_END: char = '}';
+
+    /// Parses the Olympia wallet export `payloads`, which can be passed in
+    /// any order.
+    ///
+    /// Returns `Err` if any payload is malformed or if any payload is
+    /// missing.
+    pub fn from_payloads<I, S>(payloads: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let payloads = payloads
+            .into_iter()
+            .map(|p| Self::parse_payload(p.as_ref()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sorted_by_key(|p| p.index)
+            .collect_vec();
+
+        let Some(first) = payloads.first() else {
+            return Err(CommonError::OlympiaWalletExportIncomplete {
+                expected: 1,
+                found: 0,
+            });
+        };
+        let (count, mnemonic_word_count) =
+            (first.count, first.mnemonic_word_count);
+        let is_complete = payloads.len() == count
+            && payloads.iter().enumerate().all(|(i, p)| {
+                p.index == i
+                    && p.count == count
+                    && p.mnemonic_word_count == mnemonic_word_count
+            });
+        if !is_complete {
+            return Err(CommonError::OlympiaWalletExportIncomplete {
+                expected: count as u32,
+                found: payloads.len() as u32,
+            });
+        }
+
+        Ok(Self {
+            mnemonic_word_count,
+            accounts: payloads.into_iter().flat_map(|p| p.accounts).collect(),
+        })
+    }
+
+    fn parse_payload(payload: &str) -> Result<Payload> {
+        let invalid = || {
+            CommonError::InvalidOlympiaWalletExportPayload(payload.to_owned())
+        };
+
+        let (header, body) =
+            payload.split_once(Self::HEADER_END).ok_or_else(invalid)?;
+        let header = header.split(Self::SEPARATOR).collect_vec();
+        let [count, index, word_count] = header.as_slice() else {
+            return Err(invalid());
+        };
+        let count = count.parse::<usize>().map_err(|_| invalid())?;
+        let index = index.parse::<usize>().map_err(|_| invalid())?;
+        let mnemonic_word_count = word_count
+            .parse::<u8>()
+            .ok()
+            .and_then(BIP39WordCount::from_repr)
+            .ok_or_else(invalid)?;
+        if index >= count {
+            return Err(invalid());
+        }
+
+        let accounts = body
+            .split(Self::ACCOUNT_SEPARATOR)
+            .filter(|a| !a.is_empty())
+            .map(Self::parse_account)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Payload {
+            count,
+            index,
+            mnemonic_word_count,
+            accounts,
+        })
+    }
+
+    fn parse_account(account: &str) -> Result<OlympiaAccountToMigrate> {
+        let invalid = || {
+            CommonError::InvalidOlympiaWalletExportPayload(account.to_owned())
+        };
+
+        let account = account
+            .strip_suffix(Self::ACCOUNT_NAME_END)
+            .ok_or_else(invalid)?;
+        let components = account.splitn(4, Self::SEPARATOR).collect_vec();
+        let [account_type, public_key, address_index, name] =
+            components.as_slice()
+        else {
+            return Err(invalid());
+        };
+
+        let account_type = account_type.parse::<OlympiaAccountType>()?;
+        let public_key = STANDARD
+            .decode(public_key)
+            .map_err(|_| invalid())
+            .and_then(|b| Secp256k1PublicKey::try_from(b.as_slice()))?;
+        let address_index = address_index
+            .parse::<HDPathValue>()
+            .map_err(|_| invalid())?;
+        let name = name.chars().take(DisplayName::MAX_LEN).collect::<String>();
+
+        Ok(OlympiaAccountToMigrate::new(
+            account_type,
+            public_key,
+            address_index,
+            DisplayName::new(name.trim()).ok(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    fn base64(public_key: Secp256k1PublicKey) -> String {
+        STANDARD.encode(public_key.to_bytes())
+    }
+
+    fn alice() -> String {
+        format!(
+            "S^{}^0^Savings}}",
+            base64(Secp256k1PublicKey::placeholder_alice())
+        )
+    }
+
+    fn bob() -> String {
+        format!("H^{}^1^}}", base64(Secp256k1PublicKey::placeholder_bob()))
+    }
+
+    #[test]
+    fn single_payload() {
+        let payload = format!("1^0^12]{}~{}", alice(), bob());
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([payload]),
+            Ok(OlympiaWalletExport {
+                mnemonic_word_count: BIP39WordCount::Twelve,
+                accounts: vec![
+                    OlympiaAccountToMigrate::placeholder(),
+                    OlympiaAccountToMigrate::placeholder_other()
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn many_payloads_any_order() {
+        let first = format!("2^0^24]{}", alice());
+        let second = format!("2^1^24]{}", bob());
+        let sut = OlympiaWalletExport::from_payloads([second, first]).unwrap();
+        assert_eq!(sut.mnemonic_word_count, BIP39WordCount::TwentyFour);
+        assert_eq!(
+            sut.accounts,
+            vec![
+                OlympiaAccountToMigrate::placeholder(),
+                OlympiaAccountToMigrate::placeholder_other()
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_payload() {
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([format!("2^1^12]{}", bob())]),
+            Err(CommonError::OlympiaWalletExportIncomplete {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn no_payloads() {
+        assert_eq!(
+            OlympiaWalletExport::from_payloads(Vec::<String>::new()),
+            Err(CommonError::OlympiaWalletExportIncomplete {
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn name_is_truncated() {
+        let payload = format!(
+            "1^0^12]S^{}^0^{}}}",
+            base64(Secp256k1PublicKey::placeholder_alice()),
+            "a".repeat(40)
+        );
+        let sut = OlympiaWalletExport::from_payloads([payload]).unwrap();
+        assert_eq!(
+            sut.accounts[0].display_name.clone().unwrap().value,
+            "a".repeat(DisplayName::MAX_LEN)
+        );
+    }
+
+    #[test]
+    fn invalid_header() {
+        let payload = format!("1^0]{}", alice());
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([payload.clone()]),
+            Err(CommonError::InvalidOlympiaWalletExportPayload(payload))
+        );
+    }
+
+    #[test]
+    fn invalid_word_count() {
+        let payload = format!("1^0^13]{}", alice());
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([payload.clone()]),
+            Err(CommonError::InvalidOlympiaWalletExportPayload(payload))
+        );
+    }
+
+    #[test]
+    fn invalid_account_missing_name_end() {
+        let account = alice().trim_end_matches('}').to_string();
+        assert_eq!(
+            OlympiaWalletExport::from_payloads([format!("1^0^12]{}", account)]),
+            Err(CommonError::InvalidOlympiaWalletExportPayload(account))
+        );
+    }
+
+    #[test]
+    fn invalid_public_key() {
+        assert!(OlympiaWalletExport::from_payloads(["1^0^12]S^AAAA^0^Foo}"])
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_from_payloads() {
+        assert_eq!(
+            new_olympia_wallet_export_from_payloads(
+                vec!["1^0^12]".to_string()]
+            ),
+            Ok(OlympiaWalletExport {
+                mnemonic_word_count: BIP39WordCount::Twelve,
+                accounts: Vec::new()
+            })
+        );
+    }
+}
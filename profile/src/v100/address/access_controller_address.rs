@@ -0,0 +1,160 @@
+use crate::prelude::*;
+
+/// The address of an AccessController, a bech32 encoded address that starts
+/// with the prefix `"accesscontroller_"`. Securified entities - Accounts and
+/// Personas - are controlled by an AccessController.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    SerializeDisplay,
+    DeserializeFromStr,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{address}")]
+pub struct AccessControllerAddress {
+    pub address: String,
+    pub network_id: NetworkID,
+}
+
+#[uniffi::export]
+pub fn new_access_controller_address(
+    bech32: String,
+) -> Result<AccessControllerAddress> {
+    AccessControllerAddress::try_from_bech32(bech32.as_str())
+}
+
+impl EntityAddress for AccessControllerAddress {
+    fn entity_type() -> AbstractEntityType {
+        AbstractEntityType::AccessController
+    }
+
+    // Underscored to decrease visibility. You SHOULD NOT call this function directly,
+    // instead use `try_from_bech32` which performs proper validation. Impl types SHOULD
+    // `panic` if `address` does not start with `Self::entity_type().hrp()`
+    fn __with_address_and_network_id(
+        address: &str,
+        network_id: NetworkID,
+    ) -> Self {
+        assert!(address.starts_with(&Self::entity_type().hrp()), "Invalid address, you SHOULD NOT call this function directly, you should use `try_from_bech32` instead.");
+        Self {
+            address: address.to_string(),
+            network_id,
+        }
+    }
+}
+
+impl FromStr for AccessControllerAddress {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AccessControllerAddress::try_from_bech32(s)
+    }
+}
+
+impl HasPlaceholder for AccessControllerAddress {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        "accesscontroller_rdx1c09f0qgjegdmmjh6cgcm8x3rm3x60ph0lq28cnnjhxq80pd00qdy0q"
+            .parse()
+            .expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        "accesscontroller_rdx1cvlz86qkqqu4jj3n398k2e8pky6gh0t6qzydgtz2ede7atk44tvnx2"
+            .parse()
+            .expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            AccessControllerAddress::placeholder(),
+            AccessControllerAddress::placeholder()
+        );
+        assert_eq!(
+            AccessControllerAddress::placeholder_other(),
+            AccessControllerAddress::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            AccessControllerAddress::placeholder(),
+            AccessControllerAddress::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn display() {
+        let s = "accesscontroller_rdx1c09f0qgjegdmmjh6cgcm8x3rm3x60ph0lq28cnnjhxq80pd00qdy0q";
+        let a = AccessControllerAddress::try_from_bech32(s).unwrap();
+        assert_eq!(format!("{a}"), s);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let a = AccessControllerAddress::placeholder();
+        assert_json_value_eq_after_roundtrip(
+            &a,
+            json!("accesscontroller_rdx1c09f0qgjegdmmjh6cgcm8x3rm3x60ph0lq28cnnjhxq80pd00qdy0q"),
+        );
+        assert_json_roundtrip(&a);
+    }
+
+    #[test]
+    fn json_roundtrip_fails_for_invalid() {
+        assert_json_value_fails::<AccessControllerAddress>(
+            json!("accesscontroller_rdx1c09f0qgjegdmmjh6cgcm8x3rm3x60ph0lq28cnnjhxq80pd00qdy0x")
+        );
+        assert_json_value_fails::<AccessControllerAddress>(
+            json!("resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd")
+        );
+        assert_json_value_fails::<AccessControllerAddress>(json!(
+            "super invalid"
+        ));
+    }
+
+    #[test]
+    fn network_id_stokenet() {
+        let a: AccessControllerAddress =
+            "accesscontroller_tdx_2_1cvh86tqr49g84cn9an6m2dtgskjn8yazq2wjgyu5n9extgdzf6hxxk"
+                .parse()
+                .unwrap();
+        assert_eq!(a.network_id, NetworkID::Stokenet);
+    }
+
+    #[test]
+    fn network_id_mainnet() {
+        assert_eq!(
+            AccessControllerAddress::placeholder().network_id,
+            NetworkID::Mainnet
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        let s = "accesscontroller_rdx1c09f0qgjegdmmjh6cgcm8x3rm3x60ph0lq28cnnjhxq80pd00qdy0q";
+        let a = AccessControllerAddress::try_from_bech32(s).unwrap();
+        let b = new_access_controller_address(s.to_string()).unwrap();
+        assert_eq!(b.address, s);
+        assert_eq!(a, b);
+    }
+}
@@ -33,6 +33,19 @@ pub trait EntityAddress: Sized {
     /// it.
     #[cfg(not(tarpaulin_include))] // false negative
     fn from_public_key<P>(public_key: P, network_id: NetworkID) -> Self
+    where
+        P: Into<EnginePublicKey> + Clone,
+    {
+        let address =
+            Self::bech32_from_public_key(public_key, network_id.discriminant());
+        Self::__with_address_and_network_id(&address, network_id)
+    }
+
+    /// Bech32 encodes the address of `public_key` on the network with the raw
+    /// ID `network_id_value`, which might be a custom network, in which case
+    /// the HRP suffix is `"tdx_{id}_"`, see `CustomNetworkDefinition`.
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn bech32_from_public_key<P>(public_key: P, network_id_value: u8) -> String
     where
         P: Into<EnginePublicKey> + Clone,
     {
@@ -44,13 +57,12 @@ pub trait EntityAddress: Sized {
                 virtual_identity_address_from_public_key(&public_key)
             }
             AbstractEntityType::Resource => panic!("resource"),
+            AbstractEntityType::AccessController => {
+                panic!("access controller")
+            }
         };
 
-        let address = Self::address_from_node_id(
-            component.into_node_id(),
-            network_id.discriminant(),
-        );
-        Self::__with_address_and_network_id(&address, network_id)
+        Self::address_from_node_id(component.into_node_id(), network_id_value)
     }
 
     #[cfg(not(tarpaulin_include))] // false negative
@@ -1,3 +1,4 @@
+mod access_controller_address;
 mod account_address;
 mod decode_address_helper;
 mod entity_address;
@@ -6,6 +7,7 @@ mod non_fungible_global_id;
 mod non_fungible_local_id;
 mod resource_address;
 
+pub use access_controller_address::*;
 pub use account_address::*;
 pub use decode_address_helper::*;
 pub use entity_address::*;
@@ -34,6 +34,14 @@ pub struct AppPreferences {
 
     /// Default config related to making of transactions
     pub transaction: Transaction,
+
+    /// Definitions of networks unknown to this library, e.g. private test
+    /// networks, added by the user. Omitted from JSON when empty.
+    #[serde(
+        default,
+        skip_serializing_if = "CustomNetworkDefinitions::is_empty"
+    )]
+    pub custom_networks: CustomNetworkDefinitions,
 }
 
 #[uniffi::export]
@@ -53,6 +61,7 @@ impl AppPreferences {
         p2p_links: P2PLinks,
         security: Security,
         transaction: Transaction,
+        custom_networks: CustomNetworkDefinitions,
     ) -> Self {
         Self {
             display,
@@ -60,6 +69,7 @@ impl AppPreferences {
             p2p_links,
             security,
             transaction,
+            custom_networks,
         }
     }
 }
@@ -73,6 +83,7 @@ impl HasPlaceholder for AppPreferences {
             P2PLinks::placeholder(),
             Security::placeholder(),
             Transaction::placeholder(),
+            CustomNetworkDefinitions::default(),
         )
     }
 
@@ -84,6 +95,9 @@ impl HasPlaceholder for AppPreferences {
             P2PLinks::placeholder(),
             Security::placeholder_other(),
             Transaction::placeholder_other(),
+            CustomNetworkDefinitions::from_iter([
+                CustomNetworkDefinition::placeholder(),
+            ]),
         )
     }
 }
@@ -144,6 +158,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn custom_networks_omitted_from_json_when_empty() {
+        let json = serde_json::to_value(AppPreferences::placeholder()).unwrap();
+        assert!(json.get("customNetworks").is_none());
+        assert_eq!(
+            serde_json::from_value::<AppPreferences>(json)
+                .unwrap()
+                .custom_networks,
+            CustomNetworkDefinitions::default()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_with_custom_networks() {
+        let sut = AppPreferences::placeholder_other();
+        assert!(!sut.custom_networks.is_empty());
+        assert_json_roundtrip(&sut);
+    }
+
     #[test]
     fn get_transaction() {
         assert_eq!(
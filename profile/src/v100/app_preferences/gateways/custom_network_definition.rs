@@ -0,0 +1,326 @@
+use crate::prelude::*;
+use radix_engine_common::network::NetworkDefinition;
+
+/// A user supplied definition of a Radix network unknown to this library,
+/// e.g. a private test network, identified by its raw network ID.
+///
+/// `NetworkID` only models networks known to this library, networks are
+/// instead looked up by their raw `u8` ID in Profile, which falls back to
+/// these definitions, see `Profile::network_definition`,
+/// `Profile::new_gateway` and `Profile::account_address_on_network`.
+#[derive(
+    Serialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("{} ({})", self.display_description, self.id)]
+pub struct CustomNetworkDefinition {
+    /// The raw identifier of the network, never the one of a `NetworkID`.
+    pub id: u8,
+
+    /// A String identifier (always lowercase) with the name of the Network that MUST match what Gateway returns.
+    #[serde(rename = "name")]
+    pub logical_name: String,
+
+    /// The suffix of the Bech32 human readable part of addresses on this
+    /// network, e.g. `"tdx_30_"` in `"account_tdx_30_1..."`.
+    pub hrp_suffix: String,
+
+    /// A name of the network intended for display purposes only.
+    pub display_description: String,
+}
+
+/// The custom networks the user has defined, identified by their raw network
+/// ID.
+pub type CustomNetworkDefinitions = IdentifiedVecVia<CustomNetworkDefinition>;
+
+impl Identifiable for CustomNetworkDefinition {
+    type ID = u8;
+
+    fn id(&self) -> Self::ID {
+        self.id
+    }
+}
+
+impl Default for CustomNetworkDefinitions {
+    /// Instantiates an empty collection of custom network definitions.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[uniffi::export]
+pub fn new_custom_network_definition(
+    id: u8,
+    logical_name: String,
+    hrp_suffix: String,
+    display_description: String,
+) -> Result<CustomNetworkDefinition> {
+    CustomNetworkDefinition::new(
+        id,
+        logical_name,
+        hrp_suffix,
+        display_description,
+    )
+}
+
+impl CustomNetworkDefinition {
+    /// Validates and creates the definition of the custom network `id`.
+    ///
+    /// Returns `Err` if `id` is the ID of a known network, if `logical_name`
+    /// is empty or not lowercase or if `hrp_suffix` is not `"tdx_{id}_"`,
+    /// with `id` in lowercase hex, which is the only suffix from which the
+    /// Radix Engine Toolkit can decode the network ID of addresses on
+    /// networks it does not know.
+    pub fn new(
+        id: u8,
+        logical_name: impl AsRef<str>,
+        hrp_suffix: impl AsRef<str>,
+        display_description: impl AsRef<str>,
+    ) -> Result<Self> {
+        if NetworkID::from_repr(id).is_some() {
+            return Err(CommonError::CustomNetworkIDIsKnownNetwork(id));
+        }
+        let logical_name = logical_name.as_ref().to_owned();
+        if logical_name.is_empty()
+            || logical_name != logical_name.to_lowercase()
+        {
+            return Err(CommonError::InvalidCustomNetworkLogicalName(
+                logical_name,
+            ));
+        }
+        let expected_hrp_suffix = format!("tdx_{:x}_", id);
+        let hrp_suffix = hrp_suffix.as_ref().to_owned();
+        if hrp_suffix != expected_hrp_suffix {
+            return Err(CommonError::InvalidCustomNetworkHRPSuffix {
+                expected: expected_hrp_suffix,
+                found: hrp_suffix,
+            });
+        }
+        Ok(Self {
+            id,
+            logical_name,
+            hrp_suffix,
+            display_description: display_description.as_ref().to_owned(),
+        })
+    }
+
+    /// The `NetworkDefinition` used by the Radix Engine for this network.
+    pub fn network_definition(&self) -> NetworkDefinition {
+        NetworkDefinition {
+            id: self.id,
+            logical_name: self.logical_name.clone(),
+            hrp_suffix: self.hrp_suffix.clone(),
+        }
+    }
+
+    /// The `RadixNetwork` of this network, used by Gateways to it.
+    pub fn radix_network(&self) -> RadixNetwork {
+        RadixNetwork {
+            logical_name: self.logical_name.clone(),
+            id: self.id,
+            display_description: self.display_description.clone(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomNetworkDefinition {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CustomNetworkDefinition, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wrapper {
+            id: u8,
+            name: String,
+            hrp_suffix: String,
+            display_description: String,
+        }
+        let wrapped = Wrapper::deserialize(deserializer)?;
+        CustomNetworkDefinition::new(
+            wrapped.id,
+            wrapped.name,
+            wrapped.hrp_suffix,
+            wrapped.display_description,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+impl HasPlaceholder for CustomNetworkDefinition {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(0x30, "ziggurat", "tdx_30_", "Ziggurat (Private Network)")
+            .expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(0xa0, "babel", "tdx_a0_", "Babel (Private Network)")
+            .expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            CustomNetworkDefinition::placeholder(),
+            CustomNetworkDefinition::placeholder()
+        );
+        assert_eq!(
+            CustomNetworkDefinition::placeholder_other(),
+            CustomNetworkDefinition::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            CustomNetworkDefinition::placeholder(),
+            CustomNetworkDefinition::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", CustomNetworkDefinition::placeholder()),
+            "Ziggurat (Private Network) (48)"
+        );
+    }
+
+    #[test]
+    fn new_known_network_is_err() {
+        assert_eq!(
+            CustomNetworkDefinition::new(0x01, "mainnet", "rdx", "Mainnet"),
+            Err(CommonError::CustomNetworkIDIsKnownNetwork(0x01))
+        );
+    }
+
+    #[test]
+    fn new_invalid_logical_name_is_err() {
+        assert_eq!(
+            CustomNetworkDefinition::new(0x30, "Ziggurat", "tdx_30_", "Z"),
+            Err(CommonError::InvalidCustomNetworkLogicalName(
+                "Ziggurat".to_owned()
+            ))
+        );
+        assert_eq!(
+            CustomNetworkDefinition::new(0x30, "", "tdx_30_", "Z"),
+            Err(CommonError::InvalidCustomNetworkLogicalName("".to_owned()))
+        );
+    }
+
+    #[test]
+    fn new_invalid_hrp_suffix_is_err() {
+        assert_eq!(
+            CustomNetworkDefinition::new(0x30, "ziggurat", "zig", "Z"),
+            Err(CommonError::InvalidCustomNetworkHRPSuffix {
+                expected: "tdx_30_".to_owned(),
+                found: "zig".to_owned()
+            })
+        );
+        assert_eq!(
+            CustomNetworkDefinition::new(0xa0, "babel", "tdx_160_", "B"),
+            Err(CommonError::InvalidCustomNetworkHRPSuffix {
+                expected: "tdx_a0_".to_owned(),
+                found: "tdx_160_".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn hrp_suffix() {
+        assert_eq!(
+            CustomNetworkDefinition::placeholder().hrp_suffix,
+            "tdx_30_"
+        );
+        assert_eq!(
+            CustomNetworkDefinition::placeholder_other().hrp_suffix,
+            "tdx_a0_"
+        );
+    }
+
+    #[test]
+    fn network_definition() {
+        let sut = CustomNetworkDefinition::placeholder();
+        let definition = sut.network_definition();
+        assert_eq!(definition.id, 0x30);
+        assert_eq!(definition.logical_name, "ziggurat");
+        assert_eq!(definition.hrp_suffix, "tdx_30_");
+    }
+
+    #[test]
+    fn radix_network() {
+        let sut = CustomNetworkDefinition::placeholder();
+        let network = sut.radix_network();
+        assert_eq!(network.id, 0x30);
+        assert_eq!(network.logical_name, "ziggurat");
+        assert_eq!(network.display_description, "Ziggurat (Private Network)");
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = CustomNetworkDefinition::placeholder();
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "id": 48,
+                "name": "ziggurat",
+                "hrpSuffix": "tdx_30_",
+                "displayDescription": "Ziggurat (Private Network)"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_invalid_hrp_suffix_fails() {
+        assert_json_value_fails::<CustomNetworkDefinition>(json!({
+            "id": 48,
+            "name": "ziggurat",
+            "hrpSuffix": "zig",
+            "displayDescription": "Ziggurat (Private Network)"
+        }));
+    }
+
+    #[test]
+    fn json_known_network_fails() {
+        assert_json_value_fails::<CustomNetworkDefinition>(json!({
+            "id": 1,
+            "name": "mainnet",
+            "hrpSuffix": "tdx_1_",
+            "displayDescription": "Mainnet"
+        }));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            new_custom_network_definition(
+                0x30,
+                "ziggurat".to_owned(),
+                "tdx_30_".to_owned(),
+                "Ziggurat (Private Network)".to_owned()
+            ),
+            Ok(CustomNetworkDefinition::placeholder())
+        );
+    }
+}
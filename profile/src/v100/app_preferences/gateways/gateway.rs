@@ -45,10 +45,16 @@ impl Gateway {
         url: String,
         id: NetworkID,
     ) -> Result<Arc<Self>, crate::CommonError> {
+        let network = RadixNetwork::lookup_by_id(id)?;
+        Self::with_network(url, network).map(Arc::new)
+    }
+
+    /// Creates a Gateway with `url` to `network`, which might be a custom
+    /// network, see `Profile::new_gateway`.
+    pub fn with_network(url: String, network: RadixNetwork) -> Result<Self> {
         let url = Url::try_from(url.as_str())
             .map_err(|_| CommonError::InvalidURL(url))?;
-        let network = RadixNetwork::lookup_by_id(id)?;
-        Ok(Self { url, network }.into())
+        Ok(Self { url, network })
     }
 }
 
@@ -240,6 +246,24 @@ mod tests {
             9
         );
     }
+
+    #[test]
+    fn with_custom_network() {
+        let network = CustomNetworkDefinition::placeholder().radix_network();
+        let url = "https://gateway.ziggurat.example.com/";
+        let sut =
+            Gateway::with_network(url.to_owned(), network.clone()).unwrap();
+        assert_eq!(sut.url.as_str(), url);
+        assert_eq!(sut.network, network);
+    }
+
+    #[test]
+    fn with_network_invalid_url() {
+        assert_eq!(
+            Gateway::with_network("".to_owned(), RadixNetwork::mainnet()),
+            Err(CommonError::InvalidURL("".to_owned()))
+        );
+    }
 }
 
 #[cfg(test)]
@@ -150,6 +150,36 @@ impl Gateways {
         self.other.append(gateway);
         true
     }
+
+    /// Adds `gateway` to the `other` list, without changing the `current` Gateway.
+    ///
+    /// Returns `Err` if `gateway` already is saved, either as `current` or in `other`.
+    pub fn add(&mut self, gateway: Gateway) -> Result<()> {
+        if self.current.id() == gateway.id() || !self.append(gateway.clone()) {
+            return Err(CommonError::GatewayAlreadyPresent(
+                gateway.url.to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Removes `gateway` from the `other` list.
+    ///
+    /// Returns `Err` if `gateway` is the `current` Gateway, if it is a well known
+    /// Gateway, e.g. mainnet, or if it is not saved.
+    pub fn remove(&mut self, gateway: &Gateway) -> Result<()> {
+        let url = gateway.url.to_string();
+        if self.current.id() == gateway.id() {
+            return Err(CommonError::CannotRemoveCurrentGateway);
+        }
+        if gateway.is_wellknown() {
+            return Err(CommonError::CannotRemoveWellKnownGateway(url));
+        }
+        self.other
+            .remove_by_id(&gateway.id())
+            .map(|_| ())
+            .ok_or(CommonError::UnknownGateway(url))
+    }
 }
 
 impl Default for Gateways {
@@ -192,9 +222,9 @@ mod tests {
     #[test]
     fn change_current_to_existing() {
         let mut sut = Gateways::default();
-        assert_eq!(sut.current.network.id, NetworkID::Mainnet);
+        assert_eq!(sut.current.network.id, NetworkID::Mainnet.discriminant());
         assert_eq!(sut.change_current(Gateway::stokenet()), Ok(true));
-        assert_eq!(sut.current.network.id, NetworkID::Stokenet);
+        assert_eq!(sut.current.network.id, NetworkID::Stokenet.discriminant());
     }
 
     #[test]
@@ -220,12 +250,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add() {
+        let mut sut = Gateways::default();
+        assert_eq!(sut.add(Gateway::nebunet()), Ok(()));
+        assert!(sut.other.contains(&Gateway::nebunet()));
+        assert_eq!(sut.current, Gateway::mainnet());
+    }
+
+    #[test]
+    fn add_already_present() {
+        let mut sut = Gateways::default();
+        let err = |g: Gateway| {
+            Err(CommonError::GatewayAlreadyPresent(g.url.to_string()))
+        };
+        assert_eq!(sut.add(Gateway::mainnet()), err(Gateway::mainnet()));
+        assert_eq!(sut.add(Gateway::stokenet()), err(Gateway::stokenet()));
+        assert_eq!(sut, Gateways::default());
+    }
+
+    #[test]
+    fn remove() {
+        let mut sut = Gateways::default();
+        sut.add(Gateway::nebunet()).unwrap();
+        assert_eq!(sut.remove(&Gateway::nebunet()), Ok(()));
+        assert_eq!(sut, Gateways::default());
+    }
+
+    #[test]
+    fn remove_current() {
+        let mut sut = Gateways::placeholder();
+        assert_eq!(
+            sut.remove(&Gateway::rcnet()),
+            Err(CommonError::CannotRemoveCurrentGateway)
+        );
+    }
+
+    #[test]
+    fn remove_wellknown() {
+        let mut sut = Gateways::placeholder();
+        assert_eq!(
+            sut.remove(&Gateway::stokenet()),
+            Err(CommonError::CannotRemoveWellKnownGateway(
+                Gateway::stokenet().url.to_string()
+            ))
+        );
+        assert_eq!(sut, Gateways::placeholder());
+    }
+
+    #[test]
+    fn remove_unknown() {
+        let mut sut = Gateways::default();
+        assert_eq!(
+            sut.remove(&Gateway::hammunet()),
+            Err(CommonError::UnknownGateway(
+                Gateway::hammunet().url.to_string()
+            ))
+        );
+    }
+
     #[test]
     fn change_current_to_current() {
         let mut sut = Gateways::default();
-        assert_eq!(sut.current.network.id, NetworkID::Mainnet);
+        assert_eq!(sut.current.network.id, NetworkID::Mainnet.discriminant());
         assert_eq!(sut.change_current(Gateway::mainnet()), Ok(false));
-        assert_eq!(sut.current.network.id, NetworkID::Mainnet);
+        assert_eq!(sut.current.network.id, NetworkID::Mainnet.discriminant());
     }
 
     #[test]
@@ -242,9 +331,9 @@ mod tests {
     #[test]
     fn change_current_to_new() {
         let mut sut = Gateways::default();
-        assert_eq!(sut.current.network.id, NetworkID::Mainnet);
+        assert_eq!(sut.current.network.id, NetworkID::Mainnet.discriminant());
         assert_eq!(sut.change_current(Gateway::nebunet()), Ok(true));
-        assert_eq!(sut.current.network.id, NetworkID::Nebunet);
+        assert_eq!(sut.current.network.id, NetworkID::Nebunet.discriminant());
         assert_eq!(
             sut.other.items(),
             [Gateway::stokenet(), Gateway::mainnet()]
@@ -1,7 +1,9 @@
+mod custom_network_definition;
 mod gateway;
 mod gateways;
 mod radix_network;
 
+pub use custom_network_definition::*;
 pub use gateway::*;
 pub use gateways::*;
 pub use radix_network::*;
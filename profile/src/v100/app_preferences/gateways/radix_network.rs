@@ -2,7 +2,7 @@ use crate::prelude::*;
 
 use crate::NetworkID::{self, *};
 
-/// A version of the Radix Network, for a NetworkID with an identifier (name) and display description (display name)
+/// A version of the Radix Network, for a NetworkID or a custom network, with an identifier (name) and display description (display name)
 #[derive(
     Serialize,
     Deserialize,
@@ -17,14 +17,15 @@ use crate::NetworkID::{self, *};
     uniffi::Record,
 )]
 #[serde(rename_all = "camelCase")]
-#[display("{} ({})", self.display_description, self.id.discriminant())]
+#[display("{} ({})", self.display_description, self.id)]
 pub struct RadixNetwork {
     /// A String identifier (always lowercase) with the name of the Network that MUST match what Gateway returns.
     #[serde(rename = "name")]
     pub logical_name: String,
 
-    /// The canonical identifier of this network.
-    pub id: NetworkID,
+    /// The canonical identifier of this network, the raw value of a
+    /// `NetworkID` or the ID of a `CustomNetworkDefinition`.
+    pub id: u8,
 
     /// A name of the network intended for display purposes only.
     pub display_description: String,
@@ -40,7 +41,7 @@ impl RadixNetwork {
     fn declare(id: NetworkID, display: &str) -> Self {
         Self {
             logical_name: id.network_definition().logical_name,
-            id,
+            id: id.discriminant(),
             display_description: display.to_string(),
         }
     }
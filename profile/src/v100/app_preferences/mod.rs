@@ -3,6 +3,7 @@ mod app_preferences;
 mod gateways;
 mod p2p_links;
 mod security;
+mod security_structure_configuration;
 mod transaction;
 
 pub use app_display_settings::*;
@@ -10,4 +11,5 @@ pub use app_preferences::*;
 pub use gateways::*;
 pub use p2p_links::*;
 pub use security::*;
+pub use security_structure_configuration::*;
 pub use transaction::*;
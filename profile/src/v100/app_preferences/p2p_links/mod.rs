@@ -1,7 +1,9 @@
 mod p2p_link;
 mod p2p_links;
+mod radix_connect_package;
 mod radix_connect_password;
 
 pub use p2p_link::*;
 pub use p2p_links::*;
+pub use radix_connect_package::*;
 pub use radix_connect_password::*;
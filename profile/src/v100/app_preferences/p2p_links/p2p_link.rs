@@ -74,6 +74,12 @@ impl P2PLink {
     }
 }
 
+/// The ID of `p2p_link`, which is the hash of its connection password.
+#[uniffi::export]
+pub fn p2p_link_id(p2p_link: &P2PLink) -> Hex32Bytes {
+    p2p_link.id().into()
+}
+
 impl HasPlaceholder for P2PLink {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -192,3 +198,17 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn id() {
+        let sut = P2PLink::placeholder();
+        assert_eq!(
+            p2p_link_id(&sut),
+            Hex32Bytes::from(sut.connection_password.hash())
+        );
+    }
+}
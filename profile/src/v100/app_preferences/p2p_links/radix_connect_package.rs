@@ -0,0 +1,464 @@
+use crate::prelude::*;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sha2::{Digest, Sha256};
+
+/// The max number of bytes of a single chunk of a message sent over
+/// Radix Connect, same as the Radix Connector browser extension.
+pub const RADIX_CONNECT_CHUNK_BYTE_COUNT: usize = 15441;
+
+/// The first package of a message sent over Radix Connect, describing the
+/// chunks that follows, so that the receiver can reassemble and verify the
+/// message.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RadixConnectMessageMetaData {
+    /// The ID of the request or response this message contains, shared by
+    /// all packages of the message.
+    pub message_id: Uuid,
+
+    /// The number of chunk packages of the message.
+    pub chunk_count: u32,
+
+    /// The number of bytes of the reassembled message.
+    pub message_byte_count: u64,
+
+    /// SHA-256 hash of the reassembled message.
+    pub hash_of_message: Hex32Bytes,
+}
+
+/// A chunk of at most `RADIX_CONNECT_CHUNK_BYTE_COUNT` bytes of a message
+/// sent over Radix Connect.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RadixConnectMessageChunk {
+    /// The ID of the request or response this chunk is part of.
+    pub message_id: Uuid,
+
+    /// The zero based index of this chunk.
+    pub chunk_index: u32,
+
+    /// The bytes of this chunk, serialized as base64.
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub chunk_data: Vec<u8>,
+}
+
+fn serialize_base64<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&STANDARD.encode(bytes))
+}
+
+fn deserialize_base64<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    STANDARD.decode(s).map_err(de::Error::custom)
+}
+
+/// A package sent between the wallet and the Radix Connector browser
+/// extension, a message is sent as one `MetaData` package followed by
+/// `chunk_count` many `Chunk` packages.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Enum,
+)]
+#[serde(from = "WirePackage", into = "WirePackage")]
+pub enum RadixConnectPackage {
+    MetaData { value: RadixConnectMessageMetaData },
+    Chunk { value: RadixConnectMessageChunk },
+}
+
+/// The JSON representation of `RadixConnectPackage`, tagged by `packageType`.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "packageType", rename_all = "camelCase")]
+enum WirePackage {
+    MetaData(RadixConnectMessageMetaData),
+    Chunk(RadixConnectMessageChunk),
+}
+
+impl From<WirePackage> for RadixConnectPackage {
+    fn from(value: WirePackage) -> Self {
+        match value {
+            WirePackage::MetaData(value) => Self::MetaData { value },
+            WirePackage::Chunk(value) => Self::Chunk { value },
+        }
+    }
+}
+
+impl From<RadixConnectPackage> for WirePackage {
+    fn from(value: RadixConnectPackage) -> Self {
+        match value {
+            RadixConnectPackage::MetaData { value } => Self::MetaData(value),
+            RadixConnectPackage::Chunk { value } => Self::Chunk(value),
+        }
+    }
+}
+
+/// SHA-256 hash of `message`, used to verify reassembled messages.
+fn hash_of_message(message: &[u8]) -> Hex32Bytes {
+    Hex32Bytes::from_bytes(&Sha256::digest(message).into())
+}
+
+impl RadixConnectPackage {
+    /// The ID of the message this package is part of.
+    pub fn message_id(&self) -> Uuid {
+        match self {
+            Self::MetaData { value } => value.message_id,
+            Self::Chunk { value } => value.message_id,
+        }
+    }
+
+    /// Splits `message`, e.g. a JSON encoded wallet interaction, into a
+    /// `MetaData` package describing `message` followed by chunks of at most
+    /// `RADIX_CONNECT_CHUNK_BYTE_COUNT` bytes, all with `message_id`.
+    pub fn split(message_id: Uuid, message: &[u8]) -> Vec<Self> {
+        let chunks = message
+            .chunks(RADIX_CONNECT_CHUNK_BYTE_COUNT)
+            .enumerate()
+            .map(|(index, chunk)| Self::Chunk {
+                value: RadixConnectMessageChunk {
+                    message_id,
+                    chunk_index: index as u32,
+                    chunk_data: chunk.to_vec(),
+                },
+            })
+            .collect_vec();
+
+        let meta_data = Self::MetaData {
+            value: RadixConnectMessageMetaData {
+                message_id,
+                chunk_count: chunks.len() as u32,
+                message_byte_count: message.len() as u64,
+                hash_of_message: hash_of_message(message),
+            },
+        };
+
+        std::iter::once(meta_data).chain(chunks).collect()
+    }
+
+    /// Reassembles the message from `packages`, which must contain exactly
+    /// one `MetaData` package and all of its chunks, in any order.
+    ///
+    /// Returns `Err` if any package belongs to another message, if chunks
+    /// are missing or duplicated, or if the byte count or hash of the
+    /// reassembled message does not match the `MetaData`.
+    pub fn reassemble(packages: &[Self]) -> Result<Vec<u8>> {
+        let meta_data = packages
+            .iter()
+            .find_map(|p| match p {
+                Self::MetaData { value } => Some(value),
+                Self::Chunk { .. } => None,
+            })
+            .ok_or(CommonError::RadixConnectMessageMissingMetaData)?;
+
+        if packages
+            .iter()
+            .any(|p| p.message_id() != meta_data.message_id)
+        {
+            return Err(CommonError::RadixConnectMessageIDMismatch);
+        }
+
+        let chunks = packages
+            .iter()
+            .filter_map(|p| match p {
+                Self::MetaData { .. } => None,
+                Self::Chunk { value } => Some(value),
+            })
+            .sorted_by_key(|c| c.chunk_index)
+            .collect_vec();
+
+        if packages.len() != chunks.len() + 1
+            || !chunks
+                .iter()
+                .map(|c| c.chunk_index)
+                .eq(0..meta_data.chunk_count)
+        {
+            return Err(CommonError::InvalidRadixConnectMessageChunks);
+        }
+
+        let message = chunks
+            .into_iter()
+            .flat_map(|c| c.chunk_data.clone())
+            .collect_vec();
+
+        if message.len() as u64 != meta_data.message_byte_count {
+            return Err(CommonError::RadixConnectMessageByteCountMismatch {
+                expected: meta_data.message_byte_count,
+                found: message.len() as u64,
+            });
+        }
+
+        if hash_of_message(&message) != meta_data.hash_of_message {
+            return Err(CommonError::RadixConnectMessageHashMismatch);
+        }
+
+        Ok(message)
+    }
+
+    /// Serializes this package as JSON bytes, to be sent over the wire.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect(
+            "Should always be able to JSON serialize a RadixConnectPackage.",
+        )
+    }
+
+    /// Deserializes a package received over the wire from JSON bytes.
+    pub fn from_json_bytes(json: &[u8]) -> Result<Self> {
+        serde_json::from_slice(json).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: std::any::type_name::<Self>().to_string(),
+            }
+        })
+    }
+}
+
+#[uniffi::export]
+pub fn new_radix_connect_packages_from_message(
+    message_id: Uuid,
+    message: Vec<u8>,
+) -> Vec<RadixConnectPackage> {
+    RadixConnectPackage::split(message_id, &message)
+}
+
+#[uniffi::export]
+pub fn radix_connect_packages_reassemble(
+    packages: Vec<RadixConnectPackage>,
+) -> Result<Vec<u8>> {
+    RadixConnectPackage::reassemble(&packages)
+}
+
+#[uniffi::export]
+pub fn radix_connect_package_to_json_bytes(
+    package: &RadixConnectPackage,
+) -> Vec<u8> {
+    package.to_json_bytes()
+}
+
+#[uniffi::export]
+pub fn new_radix_connect_package_from_json_bytes(
+    json: Vec<u8>,
+) -> Result<RadixConnectPackage> {
+    RadixConnectPackage::from_json_bytes(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn message_id() -> Uuid {
+        Uuid::from_str("ffffffff-ffff-ffff-ffff-ffffffffffff").unwrap()
+    }
+
+    fn long_message() -> Vec<u8> {
+        (0..(2 * RADIX_CONNECT_CHUNK_BYTE_COUNT + 1))
+            .map(|i| i as u8)
+            .collect()
+    }
+
+    #[test]
+    fn split_short_message() {
+        let packages = RadixConnectPackage::split(message_id(), b"Hello");
+        assert_eq!(
+            packages,
+            vec![
+                RadixConnectPackage::MetaData {
+                    value: RadixConnectMessageMetaData {
+                        message_id: message_id(),
+                        chunk_count: 1,
+                        message_byte_count: 5,
+                        hash_of_message: Hex32Bytes::from_hex("185f8db32271fe25f561a6fc938b2e264306ec304eda518007d1764826381969").unwrap(),
+                    }
+                },
+                RadixConnectPackage::Chunk {
+                    value: RadixConnectMessageChunk {
+                        message_id: message_id(),
+                        chunk_index: 0,
+                        chunk_data: b"Hello".to_vec(),
+                    }
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn split_long_message() {
+        let packages =
+            RadixConnectPackage::split(message_id(), &long_message());
+        assert_eq!(packages.len(), 4);
+        let RadixConnectPackage::Chunk { value: last } = &packages[3] else {
+            panic!("Expected last package to be a chunk");
+        };
+        assert_eq!(last.chunk_index, 2);
+        assert_eq!(
+            last.chunk_data,
+            vec![long_message().last().cloned().unwrap()]
+        );
+    }
+
+    #[test]
+    fn reassemble_any_order() {
+        let mut packages =
+            RadixConnectPackage::split(message_id(), &long_message());
+        packages.reverse();
+        assert_eq!(
+            RadixConnectPackage::reassemble(&packages),
+            Ok(long_message())
+        );
+    }
+
+    #[test]
+    fn reassemble_missing_meta_data() {
+        let packages =
+            RadixConnectPackage::split(message_id(), &long_message());
+        assert_eq!(
+            RadixConnectPackage::reassemble(&packages[1..]),
+            Err(CommonError::RadixConnectMessageMissingMetaData)
+        );
+    }
+
+    #[test]
+    fn reassemble_missing_chunk() {
+        let mut packages =
+            RadixConnectPackage::split(message_id(), &long_message());
+        packages.remove(2);
+        assert_eq!(
+            RadixConnectPackage::reassemble(&packages),
+            Err(CommonError::InvalidRadixConnectMessageChunks)
+        );
+    }
+
+    #[test]
+    fn reassemble_duplicated_chunk() {
+        let mut packages = RadixConnectPackage::split(message_id(), b"Hello");
+        packages.push(packages[1].clone());
+        assert_eq!(
+            RadixConnectPackage::reassemble(&packages),
+            Err(CommonError::InvalidRadixConnectMessageChunks)
+        );
+    }
+
+    #[test]
+    fn reassemble_chunk_of_other_message() {
+        let mut packages = RadixConnectPackage::split(message_id(), b"Hello");
+        packages.extend(RadixConnectPackage::split(Uuid::nil(), b"Hello"));
+        assert_eq!(
+            RadixConnectPackage::reassemble(&packages),
+            Err(CommonError::RadixConnectMessageIDMismatch)
+        );
+    }
+
+    #[test]
+    fn reassemble_wrong_byte_count() {
+        let mut packages = RadixConnectPackage::split(message_id(), b"Hello");
+        if let RadixConnectPackage::Chunk { value } = &mut packages[1] {
+            value.chunk_data.push(b'!');
+        }
+        assert_eq!(
+            RadixConnectPackage::reassemble(&packages),
+            Err(CommonError::RadixConnectMessageByteCountMismatch {
+                expected: 5,
+                found: 6
+            })
+        );
+    }
+
+    #[test]
+    fn reassemble_wrong_hash() {
+        let mut packages = RadixConnectPackage::split(message_id(), b"Hello");
+        if let RadixConnectPackage::Chunk { value } = &mut packages[1] {
+            value.chunk_data = b"Hallo".to_vec();
+        }
+        assert_eq!(
+            RadixConnectPackage::reassemble(&packages),
+            Err(CommonError::RadixConnectMessageHashMismatch)
+        );
+    }
+
+    #[test]
+    fn json() {
+        let packages = RadixConnectPackage::split(message_id(), b"Hello");
+        assert_eq!(
+            serde_json::to_value(&packages).unwrap(),
+            json!([
+                {
+                    "packageType": "metaData",
+                    "messageId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "chunkCount": 1,
+                    "messageByteCount": 5,
+                    "hashOfMessage": "185f8db32271fe25f561a6fc938b2e264306ec304eda518007d1764826381969"
+                },
+                {
+                    "packageType": "chunk",
+                    "messageId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "chunkIndex": 0,
+                    "chunkData": "SGVsbG8="
+                }
+            ])
+        );
+        packages.iter().for_each(assert_json_roundtrip);
+    }
+
+    #[test]
+    fn json_bytes_roundtrip() {
+        RadixConnectPackage::split(message_id(), b"Hello")
+            .into_iter()
+            .for_each(|p| {
+                assert_eq!(
+                    RadixConnectPackage::from_json_bytes(&p.to_json_bytes()),
+                    Ok(p)
+                )
+            });
+    }
+
+    #[test]
+    fn from_json_bytes_invalid_chunk_data() {
+        let json = json!({
+            "packageType": "chunk",
+            "messageId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "chunkIndex": 0,
+            "chunkData": "not base64!"
+        })
+        .to_string();
+        assert!(RadixConnectPackage::from_json_bytes(json.as_bytes()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn split_reassemble_roundtrip() {
+        let message = "Hello Radix".repeat(2000).into_bytes();
+        let packages = new_radix_connect_packages_from_message(
+            Uuid::nil(),
+            message.clone(),
+        );
+        assert_eq!(packages.len(), 3);
+        assert_eq!(radix_connect_packages_reassemble(packages), Ok(message));
+    }
+
+    #[test]
+    fn json_bytes_roundtrip() {
+        RadixConnectPackage::split(Uuid::nil(), b"Hello")
+            .into_iter()
+            .for_each(|p| {
+                assert_eq!(
+                    new_radix_connect_package_from_json_bytes(
+                        radix_connect_package_to_json_bytes(&p)
+                    ),
+                    Ok(p)
+                )
+            });
+    }
+}
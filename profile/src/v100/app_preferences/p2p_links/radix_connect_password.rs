@@ -31,6 +31,73 @@ impl RadixConnectPassword {
     pub fn hash(&self) -> Hash {
         hash(self.value.bytes())
     }
+
+    /// Generates a new random password, e.g. for linking with another
+    /// wallet client.
+    pub fn generate() -> Self {
+        Self::new(Hex32Bytes::generate())
+    }
+
+    /// Parses the payload of the QR code displayed by the Radix Connector
+    /// browser extension, which is the password as 64 hex characters,
+    /// surrounding whitespace is ignored.
+    ///
+    /// The payload is not part of the error since it is a secret.
+    pub fn from_qr_payload(payload: &str) -> Result<Self> {
+        Hex32Bytes::from_hex(payload.trim())
+            .map(Self::new)
+            .map_err(|_| CommonError::InvalidRadixConnectPasswordQRPayload)
+    }
+}
+
+impl RadixConnectPassword {
+    /// Encrypts `message`, e.g. a WebRTC offer sent via the Signaling Server,
+    /// with this password as AES-GCM-256 key, returning the combined sealed
+    /// box: the 12 bytes nonce followed by the ciphertext and the 16 bytes
+    /// authentication tag, same as the Radix Connector browser extension and
+    /// the iOS and Android wallets.
+    pub fn seal(&self, message: &[u8]) -> Vec<u8> {
+        EncryptionScheme::Version1.encrypt(message, &self.value)
+    }
+
+    /// Decrypts and authenticates the combined sealed box `sealed` with this
+    /// password, returning the message.
+    ///
+    /// Returns `Err` if `sealed` was not sealed with this password or has
+    /// been tampered with.
+    pub fn open(&self, sealed: &[u8]) -> Result<Vec<u8>> {
+        EncryptionScheme::Version1
+            .decrypt(sealed, &self.value)
+            .map_err(|_| CommonError::RadixConnectMessageDecryptionFailed)
+    }
+}
+
+#[uniffi::export]
+pub fn new_radix_connect_password_generate() -> RadixConnectPassword {
+    RadixConnectPassword::generate()
+}
+
+#[uniffi::export]
+pub fn new_radix_connect_password_from_qr_payload(
+    payload: String,
+) -> Result<RadixConnectPassword> {
+    RadixConnectPassword::from_qr_payload(&payload)
+}
+
+#[uniffi::export]
+pub fn radix_connect_password_seal(
+    password: &RadixConnectPassword,
+    message: Vec<u8>,
+) -> Vec<u8> {
+    password.seal(&message)
+}
+
+#[uniffi::export]
+pub fn radix_connect_password_open(
+    password: &RadixConnectPassword,
+    sealed: Vec<u8>,
+) -> Result<Vec<u8>> {
+    password.open(&sealed)
 }
 
 impl HasPlaceholder for RadixConnectPassword {
@@ -131,6 +198,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn generate_is_random() {
+        assert_ne!(
+            RadixConnectPassword::generate(),
+            RadixConnectPassword::generate()
+        );
+    }
+
+    #[test]
+    fn from_qr_payload() {
+        assert_eq!(
+            RadixConnectPassword::from_qr_payload(&"cafe".repeat(16)),
+            Ok(RadixConnectPassword::placeholder_cafe())
+        );
+    }
+
+    #[test]
+    fn from_qr_payload_ignores_surrounding_whitespace() {
+        assert_eq!(
+            RadixConnectPassword::from_qr_payload(&format!(
+                " {}\n",
+                "babe".repeat(16)
+            )),
+            Ok(RadixConnectPassword::placeholder_babe())
+        );
+    }
+
+    #[test]
+    fn from_qr_payload_invalid() {
+        let invalid = |payload: &str| {
+            assert_eq!(
+                RadixConnectPassword::from_qr_payload(payload),
+                Err(CommonError::InvalidRadixConnectPasswordQRPayload)
+            )
+        };
+        invalid("");
+        invalid(&"cafe".repeat(15));
+        invalid(&"cafe".repeat(17));
+        invalid(&"zafe".repeat(16));
+    }
+
+    #[test]
+    fn seal_open_roundtrip() {
+        let sut = RadixConnectPassword::placeholder_cafe();
+        let sealed = sut.seal(b"Hello Radix");
+        assert_eq!(sealed.len(), 12 + 11 + 16);
+        assert_eq!(sut.open(&sealed), Ok(b"Hello Radix".to_vec()));
+    }
+
+    #[test]
+    fn seal_uses_random_nonce() {
+        let sut = RadixConnectPassword::placeholder_cafe();
+        assert_ne!(sut.seal(b"Hello Radix"), sut.seal(b"Hello Radix"));
+    }
+
+    /// Test Case 14 of "The Galois/Counter Mode of Operation (GCM)" by
+    /// McGrew and Viega, as a combined sealed box.
+    #[test]
+    fn open_gcm_spec_test_case_14() {
+        let sut =
+            RadixConnectPassword::new(Hex32Bytes::from_bytes(&[0x00; 32]));
+        let sealed = hex_decode(
+            "000000000000000000000000cea7403d4d606b6e074ec5d3baf39d18d0d1c8a799996bf0265b98b5d48ab919",
+        )
+        .unwrap();
+        assert_eq!(sut.open(&sealed), Ok(vec![0x00; 16]));
+    }
+
+    /// Test Case 13 of "The Galois/Counter Mode of Operation (GCM)" by
+    /// McGrew and Viega, an empty message, as a combined sealed box.
+    #[test]
+    fn open_gcm_spec_test_case_13() {
+        let sut =
+            RadixConnectPassword::new(Hex32Bytes::from_bytes(&[0x00; 32]));
+        let sealed = hex_decode(
+            "000000000000000000000000530f8afbc74536b9a963b4f1c4cb738b",
+        )
+        .unwrap();
+        assert_eq!(sut.open(&sealed), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn open_wrong_password() {
+        let sealed =
+            RadixConnectPassword::placeholder_cafe().seal(b"Hello Radix");
+        assert_eq!(
+            RadixConnectPassword::placeholder_babe().open(&sealed),
+            Err(CommonError::RadixConnectMessageDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn open_tampered() {
+        let sut = RadixConnectPassword::placeholder_cafe();
+        let mut sealed = sut.seal(b"Hello Radix");
+        sealed[12] ^= 0x01;
+        assert_eq!(
+            sut.open(&sealed),
+            Err(CommonError::RadixConnectMessageDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn open_too_short() {
+        assert_eq!(
+            RadixConnectPassword::placeholder_cafe().open(&[0x00; 11]),
+            Err(CommonError::RadixConnectMessageDecryptionFailed)
+        );
+    }
+
     #[test]
     fn hash() {
         assert_eq!(
@@ -156,3 +333,34 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn generate() {
+        assert_ne!(
+            new_radix_connect_password_generate(),
+            new_radix_connect_password_generate()
+        );
+    }
+
+    #[test]
+    fn seal_open_roundtrip() {
+        let sut = RadixConnectPassword::placeholder_dead();
+        let sealed = radix_connect_password_seal(&sut, b"Hello Radix".to_vec());
+        assert_eq!(
+            radix_connect_password_open(&sut, sealed),
+            Ok(b"Hello Radix".to_vec())
+        );
+    }
+
+    #[test]
+    fn from_qr_payload() {
+        assert_eq!(
+            new_radix_connect_password_from_qr_payload("dead".repeat(16)),
+            Ok(RadixConnectPassword::placeholder_dead())
+        );
+    }
+}
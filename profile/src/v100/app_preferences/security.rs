@@ -1,13 +1,8 @@
 use crate::prelude::*;
 
-// FIXME: MFA this is in fact not used, so ok to be a `bool` for now. The AppPreferences Security type has
-// a field `structure_configuration_references` but no client can populate it yet, so the list will always
-// be empty, thus save to used a serializable trivial type such as `bool` as a placeholder for now.
-pub type SecurityStructureConfigurationReference = bool;
-
 /// Controls e.g. if Profile Snapshot gets synced to iCloud or not, and whether
-/// developer mode is enabled or not. In future (MFA) we will also save a list of
-/// MFA security structure configurations.
+/// developer mode is enabled or not. Also contains the list of MFA security
+/// structure configurations the user has created.
 #[derive(
     Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Hash, uniffi::Record,
 )]
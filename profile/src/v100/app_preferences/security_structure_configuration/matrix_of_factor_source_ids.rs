@@ -0,0 +1,228 @@
+use crate::prelude::*;
+
+/// The factor sources of each role of a security structure configuration.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixOfFactorSourceIDs {
+    /// Used for signing transactions.
+    pub primary_role: RoleOfFactorSourceIDs,
+
+    /// Used to recover the entity, e.g. if the primary role factors are lost.
+    pub recovery_role: RoleOfFactorSourceIDs,
+
+    /// Used to confirm recovery.
+    pub confirmation_role: RoleOfFactorSourceIDs,
+}
+
+impl MatrixOfFactorSourceIDs {
+    /// Returns `Err` if the matrix is invalid, see `validate`.
+    pub fn new(
+        primary_role: RoleOfFactorSourceIDs,
+        recovery_role: RoleOfFactorSourceIDs,
+        confirmation_role: RoleOfFactorSourceIDs,
+    ) -> Result<Self> {
+        let matrix = Self {
+            primary_role,
+            recovery_role,
+            confirmation_role,
+        };
+        matrix.validate()?;
+        Ok(matrix)
+    }
+
+    /// Returns `Err` if any role is invalid, if any factor source is used
+    /// in both the primary and the recovery role, since losing it would
+    /// mean losing both the ability to sign and to recover, or if a trusted
+    /// contact is used in any other role than the recovery role.
+    pub fn validate(&self) -> Result<()> {
+        self.primary_role.validate()?;
+        self.recovery_role.validate()?;
+        self.confirmation_role.validate()?;
+
+        if let Some(id) = self
+            .primary_role
+            .all_factors()
+            .into_iter()
+            .chain(self.confirmation_role.all_factors())
+            .find(|id| id.kind() == FactorSourceKind::TrustedContact)
+        {
+            return Err(CommonError::TrustedContactOnlyAllowedInRecoveryRole(
+                id,
+            ));
+        }
+
+        let primary = self.primary_role.all_factors();
+        if let Some(id) = self
+            .recovery_role
+            .all_factors()
+            .into_iter()
+            .find(|id| primary.contains(id))
+        {
+            return Err(CommonError::FactorSourceInBothPrimaryAndRecoveryRole(
+                id,
+            ));
+        }
+        Ok(())
+    }
+
+    /// All factor sources of all roles, without duplicates.
+    pub fn all_factors(&self) -> Vec<FactorSourceID> {
+        [
+            &self.primary_role,
+            &self.recovery_role,
+            &self.confirmation_role,
+        ]
+        .into_iter()
+        .flat_map(|r| r.all_factors())
+        .unique()
+        .collect()
+    }
+}
+
+impl HasPlaceholder for MatrixOfFactorSourceIDs {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            RoleOfFactorSourceIDs::placeholder(),
+            RoleOfFactorSourceIDs::placeholder_other(),
+            RoleOfFactorSourceIDs::placeholder_other(),
+        )
+        .expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            RoleOfFactorSourceIDs::placeholder_other(),
+            RoleOfFactorSourceIDs::placeholder(),
+            RoleOfFactorSourceIDs::placeholder(),
+        )
+        .expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            MatrixOfFactorSourceIDs::placeholder(),
+            MatrixOfFactorSourceIDs::placeholder()
+        );
+        assert_eq!(
+            MatrixOfFactorSourceIDs::placeholder_other(),
+            MatrixOfFactorSourceIDs::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            MatrixOfFactorSourceIDs::placeholder(),
+            MatrixOfFactorSourceIDs::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn factor_in_both_primary_and_recovery() {
+        assert_eq!(
+            MatrixOfFactorSourceIDs::new(
+                RoleOfFactorSourceIDs::placeholder(),
+                RoleOfFactorSourceIDs::placeholder(),
+                RoleOfFactorSourceIDs::placeholder_other(),
+            ),
+            Err(CommonError::FactorSourceInBothPrimaryAndRecoveryRole(
+                FactorSourceIDFromHash::placeholder_device().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn factor_in_both_primary_and_confirmation_is_allowed() {
+        assert!(MatrixOfFactorSourceIDs::new(
+            RoleOfFactorSourceIDs::placeholder(),
+            RoleOfFactorSourceIDs::placeholder_other(),
+            RoleOfFactorSourceIDs::placeholder(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn trusted_contact_in_recovery_role() {
+        let trusted_contact: FactorSourceID =
+            FactorSourceIDFromAddress::placeholder().into();
+        assert!(MatrixOfFactorSourceIDs::new(
+            RoleOfFactorSourceIDs::placeholder(),
+            RoleOfFactorSourceIDs::new([], 0, [trusted_contact]).unwrap(),
+            RoleOfFactorSourceIDs::placeholder_other(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn trusted_contact_in_primary_role() {
+        let trusted_contact: FactorSourceID =
+            FactorSourceIDFromAddress::placeholder().into();
+        assert_eq!(
+            MatrixOfFactorSourceIDs::new(
+                RoleOfFactorSourceIDs::new([trusted_contact.clone()], 1, [])
+                    .unwrap(),
+                RoleOfFactorSourceIDs::placeholder_other(),
+                RoleOfFactorSourceIDs::placeholder_other(),
+            ),
+            Err(CommonError::TrustedContactOnlyAllowedInRecoveryRole(
+                trusted_contact
+            ))
+        );
+    }
+
+    #[test]
+    fn trusted_contact_in_confirmation_role() {
+        let trusted_contact: FactorSourceID =
+            FactorSourceIDFromAddress::placeholder().into();
+        assert_eq!(
+            MatrixOfFactorSourceIDs::new(
+                RoleOfFactorSourceIDs::placeholder(),
+                RoleOfFactorSourceIDs::placeholder_other(),
+                RoleOfFactorSourceIDs::new([], 0, [trusted_contact.clone()])
+                    .unwrap(),
+            ),
+            Err(CommonError::TrustedContactOnlyAllowedInRecoveryRole(
+                trusted_contact
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_role() {
+        let mut sut = MatrixOfFactorSourceIDs::placeholder();
+        sut.confirmation_role.threshold = 5;
+        assert_eq!(
+            sut.validate(),
+            Err(CommonError::InvalidRoleThreshold {
+                threshold: 5,
+                threshold_factor_count: 0
+            })
+        );
+    }
+
+    #[test]
+    fn all_factors_without_duplicates() {
+        assert_eq!(
+            MatrixOfFactorSourceIDs::placeholder().all_factors(),
+            vec![
+                FactorSourceIDFromHash::placeholder_device().into(),
+                FactorSourceIDFromHash::placeholder_ledger().into()
+            ]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&MatrixOfFactorSourceIDs::placeholder());
+    }
+}
@@ -0,0 +1,9 @@
+mod matrix_of_factor_source_ids;
+mod role_of_factor_source_ids;
+mod security_structure_configuration_reference;
+mod security_structure_metadata;
+
+pub use matrix_of_factor_source_ids::*;
+pub use role_of_factor_source_ids::*;
+pub use security_structure_configuration_reference::*;
+pub use security_structure_metadata::*;
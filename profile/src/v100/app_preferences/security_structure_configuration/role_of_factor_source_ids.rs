@@ -0,0 +1,234 @@
+use crate::prelude::*;
+
+/// The factor sources of a role - primary, recovery or confirmation - of a
+/// security structure configuration. The role is fulfilled if `threshold`
+/// many of the `threshold_factors` sign, or if any single of the
+/// `override_factors` sign.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleOfFactorSourceIDs {
+    /// Factor sources of which `threshold` many are required to sign.
+    pub threshold_factors: Vec<FactorSourceID>,
+
+    /// The number of `threshold_factors` required to sign.
+    pub threshold: u16,
+
+    /// Factor sources of which any single one is sufficient to sign.
+    pub override_factors: Vec<FactorSourceID>,
+}
+
+impl RoleOfFactorSourceIDs {
+    /// Returns `Err` if the role is invalid, see `validate`.
+    pub fn new<I, J>(
+        threshold_factors: I,
+        threshold: u16,
+        override_factors: J,
+    ) -> Result<Self>
+    where
+        I: IntoIterator<Item = FactorSourceID>,
+        J: IntoIterator<Item = FactorSourceID>,
+    {
+        let role = Self {
+            threshold_factors: threshold_factors.into_iter().collect(),
+            threshold,
+            override_factors: override_factors.into_iter().collect(),
+        };
+        role.validate()?;
+        Ok(role)
+    }
+
+    /// Returns `Err` if the role has no factors at all, if any factor source
+    /// is used more than once - in either list or across both - if
+    /// `threshold` is zero while there are `threshold_factors` - which would
+    /// fulfill the role without any signature - or if `threshold` is greater
+    /// than the number of `threshold_factors`.
+    pub fn validate(&self) -> Result<()> {
+        if self.threshold_factors.is_empty() && self.override_factors.is_empty()
+        {
+            return Err(CommonError::SecurityStructureRoleWithoutFactors);
+        }
+        if let Some(duplicate) =
+            self.all_factors().into_iter().duplicates().next()
+        {
+            return Err(CommonError::DuplicateFactorSourceInRole(duplicate));
+        }
+        if self.threshold == 0 && !self.threshold_factors.is_empty() {
+            return Err(CommonError::RoleThresholdZeroWithThresholdFactors);
+        }
+        if self.threshold as usize > self.threshold_factors.len() {
+            return Err(CommonError::InvalidRoleThreshold {
+                threshold: self.threshold,
+                threshold_factor_count: self.threshold_factors.len() as u16,
+            });
+        }
+        Ok(())
+    }
+
+    /// All factor sources of this role, threshold factors first.
+    pub fn all_factors(&self) -> Vec<FactorSourceID> {
+        self.threshold_factors
+            .iter()
+            .chain(self.override_factors.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleOfFactorSourceIDs {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RoleOfFactorSourceIDs, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wrapper {
+            threshold_factors: Vec<FactorSourceID>,
+            threshold: u16,
+            override_factors: Vec<FactorSourceID>,
+        }
+        let wrapped = Wrapper::deserialize(deserializer)?;
+        RoleOfFactorSourceIDs::new(
+            wrapped.threshold_factors,
+            wrapped.threshold,
+            wrapped.override_factors,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+impl HasPlaceholder for RoleOfFactorSourceIDs {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new([FactorSourceIDFromHash::placeholder_device().into()], 1, [])
+            .expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new([], 0, [FactorSourceIDFromHash::placeholder_ledger().into()])
+            .expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            RoleOfFactorSourceIDs::placeholder(),
+            RoleOfFactorSourceIDs::placeholder()
+        );
+        assert_eq!(
+            RoleOfFactorSourceIDs::placeholder_other(),
+            RoleOfFactorSourceIDs::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            RoleOfFactorSourceIDs::placeholder(),
+            RoleOfFactorSourceIDs::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn threshold_too_high() {
+        assert_eq!(
+            RoleOfFactorSourceIDs::new(
+                [FactorSourceIDFromHash::placeholder_device().into()],
+                2,
+                []
+            ),
+            Err(CommonError::InvalidRoleThreshold {
+                threshold: 2,
+                threshold_factor_count: 1
+            })
+        );
+    }
+
+    #[test]
+    fn threshold_zero_with_threshold_factors() {
+        assert_eq!(
+            RoleOfFactorSourceIDs::new(
+                [FactorSourceIDFromHash::placeholder_device().into()],
+                0,
+                [FactorSourceIDFromHash::placeholder_ledger().into()]
+            ),
+            Err(CommonError::RoleThresholdZeroWithThresholdFactors)
+        );
+    }
+
+    #[test]
+    fn without_factors() {
+        assert_eq!(
+            RoleOfFactorSourceIDs::new([], 0, []),
+            Err(CommonError::SecurityStructureRoleWithoutFactors)
+        );
+    }
+
+    #[test]
+    fn duplicate_threshold_factors() {
+        let device: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_device().into();
+        assert_eq!(
+            RoleOfFactorSourceIDs::new([device.clone(), device.clone()], 1, []),
+            Err(CommonError::DuplicateFactorSourceInRole(device))
+        );
+    }
+
+    #[test]
+    fn duplicate_across_threshold_and_override_factors() {
+        let device: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_device().into();
+        assert_eq!(
+            RoleOfFactorSourceIDs::new([device.clone()], 1, [device.clone()]),
+            Err(CommonError::DuplicateFactorSourceInRole(device))
+        );
+    }
+
+    #[test]
+    fn all_factors() {
+        let device: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_device().into();
+        let ledger: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_ledger().into();
+        let sut =
+            RoleOfFactorSourceIDs::new([device.clone()], 1, [ledger.clone()])
+                .unwrap();
+        assert_eq!(sut.all_factors(), vec![device, ledger]);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&RoleOfFactorSourceIDs::placeholder());
+        assert_json_roundtrip(&RoleOfFactorSourceIDs::placeholder_other());
+    }
+
+    #[test]
+    fn json_duplicate_factors_fails() {
+        let mut json =
+            serde_json::to_value(RoleOfFactorSourceIDs::placeholder()).unwrap();
+        json["overrideFactors"] = json["thresholdFactors"].clone();
+        assert_json_value_fails::<RoleOfFactorSourceIDs>(json);
+    }
+
+    #[test]
+    fn json_threshold_zero_with_threshold_factors_fails() {
+        let mut json =
+            serde_json::to_value(RoleOfFactorSourceIDs::placeholder()).unwrap();
+        json["threshold"] = json!(0);
+        assert_json_value_fails::<RoleOfFactorSourceIDs>(json);
+    }
+
+    #[test]
+    fn json_without_factors_fails() {
+        assert_json_value_fails::<RoleOfFactorSourceIDs>(json!({
+            "thresholdFactors": [],
+            "threshold": 0,
+            "overrideFactors": []
+        }));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::prelude::*;
+
+/// A security structure configuration saved in Profile, referencing the
+/// factor sources of each role by ID. It can be applied to entities to
+/// securify them.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityStructureConfigurationReference {
+    /// The ID, name and dates of the configuration.
+    pub metadata: SecurityStructureMetadata,
+
+    /// The number of days after which a recovery initiated using the
+    /// recovery role is automatically confirmed, without the confirmation
+    /// role.
+    pub number_of_days_until_auto_confirmation: u16,
+
+    /// The factor sources of each role.
+    pub matrix_of_factors: MatrixOfFactorSourceIDs,
+}
+
+impl Identifiable for SecurityStructureConfigurationReference {
+    type ID = SecurityStructureID;
+
+    fn id(&self) -> Self::ID {
+        self.metadata.id
+    }
+}
+
+impl SecurityStructureConfigurationReference {
+    /// The default number of days until recovery is auto confirmed.
+    pub const DEFAULT_NUMBER_OF_DAYS_UNTIL_AUTO_CONFIRMATION: u16 = 14;
+
+    /// Creates a new configuration with a new random ID, created now.
+    ///
+    /// Returns `Err` if the configuration is invalid, see `validate`.
+    pub fn new(
+        display_name: DisplayName,
+        number_of_days_until_auto_confirmation: u16,
+        matrix_of_factors: MatrixOfFactorSourceIDs,
+    ) -> Result<Self> {
+        let configuration = Self {
+            metadata: SecurityStructureMetadata::new(display_name),
+            number_of_days_until_auto_confirmation,
+            matrix_of_factors,
+        };
+        configuration.validate()?;
+        Ok(configuration)
+    }
+
+    /// Returns `Err` if `number_of_days_until_auto_confirmation` is zero or
+    /// if the matrix of factors is invalid.
+    pub fn validate(&self) -> Result<()> {
+        if self.number_of_days_until_auto_confirmation == 0 {
+            return Err(CommonError::InvalidNumberOfDaysUntilAutoConfirmation(
+                self.number_of_days_until_auto_confirmation,
+            ));
+        }
+        self.matrix_of_factors.validate()
+    }
+}
+
+impl HasPlaceholder for SecurityStructureConfigurationReference {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self {
+            metadata: SecurityStructureMetadata::placeholder(),
+            number_of_days_until_auto_confirmation:
+                Self::DEFAULT_NUMBER_OF_DAYS_UNTIL_AUTO_CONFIRMATION,
+            matrix_of_factors: MatrixOfFactorSourceIDs::placeholder(),
+        }
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self {
+            metadata: SecurityStructureMetadata::placeholder_other(),
+            number_of_days_until_auto_confirmation: 30,
+            matrix_of_factors: MatrixOfFactorSourceIDs::placeholder_other(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SecurityStructureConfigurationReference::placeholder(),
+            SecurityStructureConfigurationReference::placeholder()
+        );
+        assert_eq!(
+            SecurityStructureConfigurationReference::placeholder_other(),
+            SecurityStructureConfigurationReference::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SecurityStructureConfigurationReference::placeholder(),
+            SecurityStructureConfigurationReference::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn zero_days_until_auto_confirmation() {
+        assert_eq!(
+            SecurityStructureConfigurationReference::new(
+                DisplayName::new("Foo").unwrap(),
+                0,
+                MatrixOfFactorSourceIDs::placeholder()
+            ),
+            Err(CommonError::InvalidNumberOfDaysUntilAutoConfirmation(0))
+        );
+    }
+
+    #[test]
+    fn new_is_valid() {
+        let sut = SecurityStructureConfigurationReference::new(
+            DisplayName::new("Foo").unwrap(),
+            SecurityStructureConfigurationReference::DEFAULT_NUMBER_OF_DAYS_UNTIL_AUTO_CONFIRMATION,
+            MatrixOfFactorSourceIDs::placeholder(),
+        )
+        .unwrap();
+        assert_eq!(sut.metadata.created_on, sut.metadata.last_updated_on);
+        assert!(sut.validate().is_ok());
+    }
+
+    #[test]
+    fn id() {
+        let sut = SecurityStructureConfigurationReference::placeholder();
+        assert_eq!(sut.id(), sut.metadata.id);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(
+            &SecurityStructureConfigurationReference::placeholder(),
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::prelude::*;
+
+/// The metadata of a security structure configuration, such as its ID and
+/// the name the user has given it.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityStructureMetadata {
+    /// An immutable and unique identifier of the security structure.
+    pub id: SecurityStructureID,
+
+    /// A name of the security structure chosen by the user.
+    pub display_name: DisplayName,
+
+    /// When the security structure was created.
+    pub created_on: Timestamp,
+
+    /// When the security structure was last updated.
+    pub last_updated_on: Timestamp,
+}
+
+impl SecurityStructureMetadata {
+    /// Instantiates new metadata with a new random ID, created and last
+    /// updated now.
+    pub fn new(display_name: DisplayName) -> Self {
+        let date = now();
+        Self::with_values(id(), display_name, date, date)
+    }
+
+    pub fn with_values(
+        id: SecurityStructureID,
+        display_name: DisplayName,
+        created_on: Timestamp,
+        last_updated_on: Timestamp,
+    ) -> Self {
+        Self {
+            id,
+            display_name,
+            created_on,
+            last_updated_on,
+        }
+    }
+}
+
+impl HasPlaceholder for SecurityStructureMetadata {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        let date = Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap();
+        Self::with_values(
+            Uuid::from_str("ffffffff-ffff-ffff-ffff-ffffffffffff").unwrap(),
+            DisplayName::new("Spending Account").unwrap(),
+            date,
+            date,
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        let date = Timestamp::parse("2023-12-24T17:13:56.123Z").unwrap();
+        Self::with_values(
+            Uuid::from_str("eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee").unwrap(),
+            DisplayName::new("Savings Account").unwrap(),
+            date,
+            date,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SecurityStructureMetadata::placeholder(),
+            SecurityStructureMetadata::placeholder()
+        );
+        assert_eq!(
+            SecurityStructureMetadata::placeholder_other(),
+            SecurityStructureMetadata::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SecurityStructureMetadata::placeholder(),
+            SecurityStructureMetadata::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_has_unique_id() {
+        let name = DisplayName::new("Foo").unwrap();
+        assert_ne!(
+            SecurityStructureMetadata::new(name.clone()).id,
+            SecurityStructureMetadata::new(name).id
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = SecurityStructureMetadata::placeholder();
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "id": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "displayName": "Spending Account",
+                "createdOn": "2023-09-11T16:05:56.000Z",
+                "lastUpdatedOn": "2023-09-11T16:05:56.000Z"
+            }
+            "#,
+        )
+    }
+}
@@ -0,0 +1,245 @@
+use crate::prelude::*;
+
+/// An encrypted backup of a Profile, the JSON snapshot of the Profile is
+/// encrypted using `encryption_scheme` with a key derived from a user
+/// provided password using `key_derivation_scheme`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EncryptedProfileSnapshot {
+    /// The version of this envelope format.
+    pub version: u32,
+
+    /// The encrypted JSON snapshot of the Profile.
+    pub encrypted_snapshot: Vec<u8>,
+
+    /// The scheme used to derive the encryption key from the password.
+    pub key_derivation_scheme: PasswordBasedKeyDerivationScheme,
+
+    /// The scheme used to encrypt the JSON snapshot of the Profile.
+    pub encryption_scheme: EncryptionScheme,
+}
+
+impl EncryptedProfileSnapshot {
+    /// The current version of the envelope format.
+    pub const VERSION: u32 = 1;
+
+    /// Encrypts the JSON snapshot of `profile` with a key derived from
+    /// `password`, using the default key derivation and encryption schemes.
+    pub fn encrypting(profile: &Profile, password: &str) -> Self {
+        let key_derivation_scheme = PasswordBasedKeyDerivationScheme::default();
+        let encryption_scheme = EncryptionScheme::default();
+        let json = serde_json::to_vec(profile)
+            .expect("Should always be able to JSON serialize a Profile.");
+        let encrypted_snapshot = encryption_scheme
+            .encrypt(&json, &key_derivation_scheme.kdf(password));
+        Self {
+            version: Self::VERSION,
+            encrypted_snapshot,
+            key_derivation_scheme,
+            encryption_scheme,
+        }
+    }
+
+    /// Decrypts the Profile using a key derived from `password`.
+    ///
+    /// Returns `Err` if `password` is wrong or if the decrypted JSON is not
+    /// a valid Profile snapshot, older snapshot versions are migrated.
+    pub fn decrypt(&self, password: &str) -> Result<Profile> {
+        let key = self.key_derivation_scheme.kdf(password);
+        let json = self
+            .encryption_scheme
+            .decrypt(&self.encrypted_snapshot, &key)?;
+        Profile::from_json_bytes(&json)
+    }
+
+    /// Serializes this encrypted snapshot as JSON bytes.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect(
+            "Should always be able to JSON serialize an EncryptedProfileSnapshot.",
+        )
+    }
+
+    /// Deserializes an encrypted snapshot from JSON bytes.
+    ///
+    /// Returns `Err` if the JSON is invalid or if the version of the
+    /// envelope, the key derivation scheme or the encryption scheme is not
+    /// supported.
+    pub fn from_json_bytes(json: &[u8]) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Versioned {
+            version: u32,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wrapper {
+            version: u32,
+            encrypted_snapshot: String,
+            key_derivation_scheme: Versioned,
+            encryption_scheme: Versioned,
+        }
+        let wrapper: Wrapper = serde_json::from_slice(json).map_err(|_| {
+            CommonError::FailedToDeserializeJSONToValue {
+                json_byte_count: json.len(),
+                type_name: std::any::type_name::<Self>().to_string(),
+            }
+        })?;
+
+        if wrapper.version != Self::VERSION {
+            return Err(
+                CommonError::UnsupportedEncryptedProfileSnapshotVersion(
+                    wrapper.version,
+                ),
+            );
+        }
+
+        Ok(Self {
+            version: wrapper.version,
+            encrypted_snapshot: hex_decode(&wrapper.encrypted_snapshot)
+                .map_err(|_| {
+                    CommonError::StringNotHex(
+                        wrapper.encrypted_snapshot.clone(),
+                    )
+                })?,
+            key_derivation_scheme: PasswordBasedKeyDerivationScheme::try_from(
+                wrapper.key_derivation_scheme.version,
+            )?,
+            encryption_scheme: EncryptionScheme::try_from(
+                wrapper.encryption_scheme.version,
+            )?,
+        })
+    }
+}
+
+impl Serialize for EncryptedProfileSnapshot {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state =
+            serializer.serialize_struct("EncryptedProfileSnapshot", 4)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field(
+            "encryptedSnapshot",
+            &hex_encode(&self.encrypted_snapshot),
+        )?;
+        state.serialize_field(
+            "keyDerivationScheme",
+            &self.key_derivation_scheme,
+        )?;
+        state.serialize_field("encryptionScheme", &self.encryption_scheme)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let profile = Profile::placeholder();
+        let sut = EncryptedProfileSnapshot::encrypting(&profile, "babylon");
+        assert_eq!(sut.decrypt("babylon"), Ok(profile));
+    }
+
+    #[test]
+    fn decrypt_wrong_password() {
+        let sut = EncryptedProfileSnapshot::encrypting(
+            &Profile::placeholder(),
+            "babylon",
+        );
+        assert_eq!(
+            sut.decrypt("olympia"),
+            Err(CommonError::InvalidPasswordForEncryptedProfileSnapshot)
+        );
+    }
+
+    #[test]
+    fn json_bytes_roundtrip() {
+        let sut = EncryptedProfileSnapshot::encrypting(
+            &Profile::placeholder(),
+            "babylon",
+        );
+        assert_eq!(
+            EncryptedProfileSnapshot::from_json_bytes(&sut.to_json_bytes()),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn json() {
+        let sut = EncryptedProfileSnapshot {
+            version: 1,
+            encrypted_snapshot: vec![0xde, 0xad],
+            key_derivation_scheme: PasswordBasedKeyDerivationScheme::Version1,
+            encryption_scheme: EncryptionScheme::Version1,
+        };
+        assert_eq!(
+            serde_json::to_value(&sut).unwrap(),
+            json!({
+                "version": 1,
+                "encryptedSnapshot": "dead",
+                "keyDerivationScheme": {
+                    "version": 1,
+                    "description": "HKDFSHA256-with-UTF8-encoding-of-password-no-salt-no-info"
+                },
+                "encryptionScheme": {
+                    "version": 1,
+                    "description": "AESGCM-256"
+                }
+            })
+        );
+    }
+
+    fn json_bytes(
+        version: u32,
+        key_derivation_scheme: u32,
+        encryption_scheme: u32,
+    ) -> Vec<u8> {
+        serde_json::to_vec(&json!({
+            "version": version,
+            "encryptedSnapshot": "dead",
+            "keyDerivationScheme": {
+                "version": key_derivation_scheme,
+                "description": "?"
+            },
+            "encryptionScheme": {
+                "version": encryption_scheme,
+                "description": "?"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn from_json_bytes_unsupported_version() {
+        assert_eq!(
+            EncryptedProfileSnapshot::from_json_bytes(&json_bytes(2, 1, 1)),
+            Err(CommonError::UnsupportedEncryptedProfileSnapshotVersion(2))
+        );
+    }
+
+    #[test]
+    fn from_json_bytes_unsupported_key_derivation_scheme() {
+        assert_eq!(
+            EncryptedProfileSnapshot::from_json_bytes(&json_bytes(1, 2, 1)),
+            Err(CommonError::UnsupportedKeyDerivationScheme(2))
+        );
+    }
+
+    #[test]
+    fn from_json_bytes_unsupported_encryption_scheme() {
+        assert_eq!(
+            EncryptedProfileSnapshot::from_json_bytes(&json_bytes(1, 1, 2)),
+            Err(CommonError::UnsupportedEncryptionScheme(2))
+        );
+    }
+
+    #[test]
+    fn from_json_bytes_invalid_json() {
+        assert!(matches!(
+            EncryptedProfileSnapshot::from_json_bytes(&[0xde, 0xad]),
+            Err(CommonError::FailedToDeserializeJSONToValue { .. })
+        ));
+    }
+}
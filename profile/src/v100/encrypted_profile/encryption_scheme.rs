@@ -0,0 +1,204 @@
+use crate::prelude::*;
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Key, Nonce,
+};
+
+/// A versioned authenticated encryption scheme, used to encrypt Profile
+/// backups with a key derived from a user provided password, and mnemonics
+/// sealed by security questions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum EncryptionScheme {
+    /// AES-GCM-256, the output is the combined sealed box: the 12 bytes
+    /// nonce followed by the ciphertext and the 16 bytes authentication tag.
+    #[default]
+    Version1,
+}
+
+const AES_GCM_NONCE_BYTE_COUNT: usize = 12;
+
+impl EncryptionScheme {
+    /// The version number of this scheme, used to tag encrypted Profile
+    /// snapshots with the scheme used to encrypt them.
+    pub fn version(&self) -> u32 {
+        match self {
+            Self::Version1 => 1,
+        }
+    }
+
+    /// A human readable description of this scheme.
+    pub fn description(&self) -> String {
+        match self {
+            Self::Version1 => "AESGCM-256".to_string(),
+        }
+    }
+
+    /// Encrypts `plaintext` with `key` using a random nonce.
+    pub fn encrypt(&self, plaintext: &[u8], key: &Hex32Bytes) -> Vec<u8> {
+        match self {
+            Self::Version1 => {
+                let cipher =
+                    Aes256Gcm::new(&Key::<Aes256Gcm>::from(key.bytes()));
+                let nonce = generate_bytes::<AES_GCM_NONCE_BYTE_COUNT>();
+                let ciphertext = cipher
+                    .encrypt(Nonce::from_slice(&nonce), plaintext)
+                    .expect("AES-GCM encryption should never fail");
+                [nonce, ciphertext].concat()
+            }
+        }
+    }
+
+    /// Decrypts and authenticates `ciphertext` with `key`.
+    ///
+    /// Returns `Err` if `key` is not the key used to encrypt `ciphertext`,
+    /// e.g. because it was derived from the wrong password, or if the
+    /// `ciphertext` has been tampered with.
+    pub fn decrypt(
+        &self,
+        ciphertext: &[u8],
+        key: &Hex32Bytes,
+    ) -> Result<Vec<u8>> {
+        match self {
+            Self::Version1 => {
+                if ciphertext.len() < AES_GCM_NONCE_BYTE_COUNT {
+                    return Err(
+                        CommonError::InvalidPasswordForEncryptedProfileSnapshot,
+                    );
+                }
+                let (nonce, sealed) =
+                    ciphertext.split_at(AES_GCM_NONCE_BYTE_COUNT);
+                Aes256Gcm::new(&Key::<Aes256Gcm>::from(key.bytes()))
+                    .decrypt(Nonce::from_slice(nonce), sealed)
+                    .map_err(|_| {
+                        CommonError::InvalidPasswordForEncryptedProfileSnapshot
+                    })
+            }
+        }
+    }
+}
+
+impl TryFrom<u32> for EncryptionScheme {
+    type Error = CommonError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Version1),
+            _ => Err(CommonError::UnsupportedEncryptionScheme(value)),
+        }
+    }
+}
+
+impl Serialize for EncryptionScheme {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("EncryptionScheme", 2)?;
+        state.serialize_field("version", &self.version())?;
+        state.serialize_field("description", &self.description())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptionScheme {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Versioned {
+            version: u32,
+        }
+        Versioned::deserialize(deserializer)
+            .and_then(|v| Self::try_from(v.version).map_err(de::Error::custom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn key() -> Hex32Bytes {
+        PasswordBasedKeyDerivationScheme::Version1.kdf("babylon")
+    }
+
+    #[test]
+    fn try_from_version() {
+        assert_eq!(
+            EncryptionScheme::try_from(1),
+            Ok(EncryptionScheme::Version1)
+        );
+        assert_eq!(
+            EncryptionScheme::try_from(0),
+            Err(CommonError::UnsupportedEncryptionScheme(0))
+        );
+    }
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let sut = EncryptionScheme::Version1;
+        let encrypted = sut.encrypt("Hello Radix".as_bytes(), &key());
+        assert_eq!(
+            sut.decrypt(&encrypted, &key()),
+            Ok("Hello Radix".as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn encrypt_uses_random_nonce() {
+        let sut = EncryptionScheme::Version1;
+        assert_ne!(
+            sut.encrypt("Hello Radix".as_bytes(), &key()),
+            sut.encrypt("Hello Radix".as_bytes(), &key())
+        );
+    }
+
+    #[test]
+    fn decrypt_known_vector() {
+        let encrypted = hex_decode("000102030405060708090a0bc3373ebbc5fe66d386a0bf8db56a5a4a3d81a9468d3b727950c9be").unwrap();
+        assert_eq!(
+            EncryptionScheme::Version1.decrypt(&encrypted, &key()),
+            Ok("Hello Radix".as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn decrypt_wrong_key() {
+        let sut = EncryptionScheme::Version1;
+        let encrypted = sut.encrypt("Hello Radix".as_bytes(), &key());
+        assert_eq!(
+            sut.decrypt(&encrypted, &Hex32Bytes::placeholder()),
+            Err(CommonError::InvalidPasswordForEncryptedProfileSnapshot)
+        );
+    }
+
+    #[test]
+    fn decrypt_too_short() {
+        assert_eq!(
+            EncryptionScheme::Version1.decrypt(&[0xde, 0xad], &key()),
+            Err(CommonError::InvalidPasswordForEncryptedProfileSnapshot)
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            serde_json::to_value(EncryptionScheme::Version1).unwrap(),
+            json!({
+                "version": 1,
+                "description": "AESGCM-256"
+            })
+        );
+        assert_json_roundtrip(&EncryptionScheme::Version1);
+    }
+
+    #[test]
+    fn json_unsupported_version() {
+        assert_json_value_fails::<EncryptionScheme>(json!({
+            "version": 2,
+            "description": "AESGCM-256"
+        }));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+/// A versioned key derivation function (KDF) used to derive an encryption
+/// key from a user provided password, used to encrypt Profile backups.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PasswordBasedKeyDerivationScheme {
+    /// HKDF-SHA256 over the UTF8 encoding of the password, without any salt
+    /// and without any info.
+    #[default]
+    Version1,
+}
+
+impl PasswordBasedKeyDerivationScheme {
+    /// The version number of this scheme, used to tag encrypted Profile
+    /// snapshots with the scheme used to derive their encryption key.
+    pub fn version(&self) -> u32 {
+        match self {
+            Self::Version1 => 1,
+        }
+    }
+
+    /// A human readable description of this scheme.
+    pub fn description(&self) -> String {
+        match self {
+            Self::Version1 => {
+                "HKDFSHA256-with-UTF8-encoding-of-password-no-salt-no-info"
+                    .to_string()
+            }
+        }
+    }
+
+    /// Derives a 32 bytes long encryption key from `password`.
+    pub fn kdf(&self, password: &str) -> Hex32Bytes {
+        match self {
+            Self::Version1 => {
+                let mut okm = [0u8; 32];
+                Hkdf::<Sha256>::new(None, password.as_bytes())
+                    .expand(&[], &mut okm)
+                    .expect("32 bytes is a valid output length of HKDF-SHA256");
+                Hex32Bytes::from_bytes(&okm)
+            }
+        }
+    }
+}
+
+impl TryFrom<u32> for PasswordBasedKeyDerivationScheme {
+    type Error = CommonError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Version1),
+            _ => Err(CommonError::UnsupportedKeyDerivationScheme(value)),
+        }
+    }
+}
+
+impl Serialize for PasswordBasedKeyDerivationScheme {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer
+            .serialize_struct("PasswordBasedKeyDerivationScheme", 2)?;
+        state.serialize_field("version", &self.version())?;
+        state.serialize_field("description", &self.description())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn default_is_version1() {
+        assert_eq!(
+            PasswordBasedKeyDerivationScheme::default(),
+            PasswordBasedKeyDerivationScheme::Version1
+        );
+    }
+
+    #[test]
+    fn try_from_version() {
+        assert_eq!(
+            PasswordBasedKeyDerivationScheme::try_from(1),
+            Ok(PasswordBasedKeyDerivationScheme::Version1)
+        );
+        assert_eq!(
+            PasswordBasedKeyDerivationScheme::try_from(2),
+            Err(CommonError::UnsupportedKeyDerivationScheme(2))
+        );
+    }
+
+    #[test]
+    fn kdf_version1() {
+        let sut = PasswordBasedKeyDerivationScheme::Version1;
+        assert_eq!(
+            sut.kdf("babylon").to_hex(),
+            "e79a913bc5ecda505c244dc61ede904baed5b80fff77930a29ea60a374a25f38"
+        );
+        assert_eq!(
+            sut.kdf("").to_hex(),
+            "eb70f01dede9afafa449eee1b1286504e1f62388b3f7dd4f956697b0e828fe18"
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            serde_json::to_value(PasswordBasedKeyDerivationScheme::Version1)
+                .unwrap(),
+            json!({
+                "version": 1,
+                "description": "HKDFSHA256-with-UTF8-encoding-of-password-no-salt-no-info"
+            })
+        );
+    }
+}
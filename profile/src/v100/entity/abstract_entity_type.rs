@@ -25,6 +25,8 @@ pub enum AbstractEntityType {
     Identity,
     /// Resource address
     Resource,
+    /// The entity type used by AccessControllers of securified entities.
+    AccessController,
 }
 impl AbstractEntityType {
     /// Conversion of the Radix Engines type for EntityType to Self.
@@ -40,6 +42,9 @@ impl AbstractEntityType {
             EngineEntityType::GlobalFungibleResourceManager => {
                 Ok(Self::Resource)
             }
+            EngineEntityType::GlobalAccessController => {
+                Ok(Self::AccessController)
+            }
             _ => Err(CommonError::UnsupportedEntityType),
         }
     }
@@ -50,6 +55,7 @@ impl AbstractEntityType {
             Self::Account => "account".to_string(),
             Self::Identity => "identity".to_string(),
             Self::Resource => "resource".to_string(),
+            Self::AccessController => "accesscontroller".to_string(),
         }
     }
 }
@@ -99,6 +99,14 @@ impl Account {
     }
 }
 
+impl Account {
+    /// If the user has hidden this account, hidden accounts are kept in
+    /// Profile but not shown in lists of accounts.
+    pub fn is_hidden(&self) -> bool {
+        self.flags.contains(&EntityFlag::DeletedByUser)
+    }
+}
+
 impl Identifiable for Account {
     type ID = AccountAddress;
 
@@ -115,16 +123,7 @@ impl PartialOrd for Account {
 
 impl Ord for Account {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.security_state, &other.security_state) {
-            (
-                EntitySecurityState::Unsecured { value: l },
-                EntitySecurityState::Unsecured { value: r },
-            ) => l
-                .transaction_signing
-                .derivation_path()
-                .last_component()
-                .cmp(r.transaction_signing.derivation_path().last_component()),
-        }
+        self.security_state.cmp(&other.security_state)
     }
 }
 
@@ -299,6 +298,16 @@ mod tests {
         assert_ne!(Account::placeholder(), Account::placeholder_other());
     }
 
+    #[test]
+    fn is_hidden() {
+        let mut account = Account::placeholder();
+        assert!(!account.is_hidden());
+        account.flags.insert_flag(EntityFlag::DeletedByUser);
+        assert!(account.is_hidden());
+        account.flags.remove_flag(&EntityFlag::DeletedByUser);
+        assert!(!account.is_hidden());
+    }
+
     #[test]
     fn new_with_address_only() {
         let address: AccountAddress =
@@ -327,6 +336,13 @@ mod tests {
         assert!(Account::placeholder_alice() < Account::placeholder_bob());
     }
 
+    #[test]
+    fn compare_securified() {
+        let mut securified = Account::placeholder_alice();
+        securified.security_state = SecuredEntityControl::placeholder().into();
+        assert!(Account::placeholder_bob() < securified);
+    }
+
     #[test]
     fn update() {
         let mut account = Account::placeholder();
@@ -20,7 +20,8 @@ use crate::prelude::*;
 )]
 #[serde(rename_all = "camelCase")]
 pub enum EntityFlag {
-    /// The entity is marked as deleted by user. Entity should still be kept in Profile
+    /// The entity is marked as deleted by user. Entity should still be kept in Profile,
+    /// it is hidden from the user and can be unhidden.
     DeletedByUser,
 }
 
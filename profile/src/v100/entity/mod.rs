@@ -10,3 +10,4 @@ pub use account::*;
 pub use display_name::*;
 pub use entity_flag::*;
 pub use entity_flags::*;
+pub use persona::*;
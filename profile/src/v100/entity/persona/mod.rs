@@ -1,2 +1,5 @@
 mod persona;
+mod persona_data;
+
 pub use persona::*;
+pub use persona_data::*;
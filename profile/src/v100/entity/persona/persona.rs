@@ -34,6 +34,8 @@ pub struct Persona {
     #[serde(default)]
     pub flags: EntityFlags,
 
+    /// Personal information a user has associated with this Persona, such as
+    /// name, email addresses and phone numbers, which can be shared with dApps.
     pub persona_data: PersonaData,
 }
 
@@ -62,6 +64,12 @@ impl Persona {
         }
     }
 
+    /// If the user has hidden this persona, hidden personas are kept in
+    /// Profile but not shown in lists of personas.
+    pub fn is_hidden(&self) -> bool {
+        self.flags.contains(&EntityFlag::DeletedByUser)
+    }
+
     fn placeholder_at_index_name_network(
         network_id: NetworkID,
         index: HDPathValue,
@@ -96,6 +104,20 @@ impl Persona {
     pub fn placeholder_batman() -> Self {
         Self::placeholder_at_index_name(1, "Batman")
     }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_stokenet_leia() -> Self {
+        Self::placeholder_at_index_name_network(NetworkID::Stokenet, 0, "Leia")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_stokenet_hermione() -> Self {
+        Self::placeholder_at_index_name_network(
+            NetworkID::Stokenet,
+            1,
+            "Hermione",
+        )
+    }
 }
 
 impl Display for Persona {
@@ -106,16 +128,7 @@ impl Display for Persona {
 
 impl Ord for Persona {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.security_state, &other.security_state) {
-            (
-                EntitySecurityState::Unsecured { value: l },
-                EntitySecurityState::Unsecured { value: r },
-            ) => l
-                .transaction_signing
-                .derivation_path()
-                .last_component()
-                .cmp(r.transaction_signing.derivation_path().last_component()),
-        }
+        self.security_state.cmp(&other.security_state)
     }
 }
 
@@ -145,20 +158,6 @@ impl HasPlaceholder for Persona {
     }
 }
 
-/// Empty struct to act as placeholder for PersonaData, `todo`
-#[derive(
-    Serialize,
-    Deserialize,
-    Clone,
-    Debug,
-    PartialEq,
-    Hash,
-    Eq,
-    Default,
-    uniffi::Record,
-)]
-pub struct PersonaData {}
-
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -229,6 +228,14 @@ mod tests {
         assert_eq!(persona.flags, new_flags);
     }
 
+    #[test]
+    fn is_hidden() {
+        let mut persona = Persona::placeholder_batman();
+        assert!(!persona.is_hidden());
+        persona.flags.insert_flag(EntityFlag::DeletedByUser);
+        assert!(persona.is_hidden());
+    }
+
     #[test]
     fn placerholder_display_name() {
         let placeholder = Persona::placeholder();
@@ -298,7 +305,10 @@ mod tests {
                   }
                 },
                 "flags": [],
-                "personaData": {}
+                "personaData": {
+                  "emailAddresses": [],
+                  "phoneNumbers": []
+                }
               }
             "#,
         );
@@ -345,7 +355,10 @@ mod tests {
                   }
                 },
                 "flags": [],
-                "personaData": {}
+                "personaData": {
+                  "emailAddresses": [],
+                  "phoneNumbers": []
+                }
               }
         "#,
         );
@@ -397,7 +410,7 @@ mod tests {
         let persona = serde_json::from_value::<Persona>(json).unwrap();
         assert_eq!(persona.display_name.value, "Batman".to_string()); // soundness
         assert_eq!(persona.flags.len(), 0); // assert Default value is empty flags.
-        assert_eq!(persona.persona_data, PersonaData {}); // assert Default value is empty flags.
+        assert_eq!(persona.persona_data, PersonaData::default()); // assert Default value is empty persona data.
     }
 
     #[test]
@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// An email address associated with a Persona, e.g. `"satoshi@nakamoto.com"`,
+/// serialized as a plain JSON string.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(transparent)]
+#[display("{email}")]
+pub struct PersonaDataEntryEmailAddress {
+    pub email: String,
+}
+
+impl PersonaDataEntryEmailAddress {
+    /// Instantiates a new `PersonaDataEntryEmailAddress`, returns `Err` if
+    /// `email` is empty.
+    pub fn new(email: impl AsRef<str>) -> Result<Self> {
+        let email = email.as_ref().trim().to_string();
+        if email.is_empty() {
+            return Err(CommonError::PersonaDataInvalidEmailAddressEmpty);
+        }
+        Ok(Self { email })
+    }
+}
+
+impl HasPlaceholder for PersonaDataEntryEmailAddress {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new("alan@turing.hero").expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new("satoshi@nakamoto.btc").expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            PersonaDataEntryEmailAddress::placeholder(),
+            PersonaDataEntryEmailAddress::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            PersonaDataEntryEmailAddress::placeholder(),
+            PersonaDataEntryEmailAddress::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_empty_fails() {
+        assert_eq!(
+            PersonaDataEntryEmailAddress::new(" "),
+            Err(CommonError::PersonaDataInvalidEmailAddressEmpty)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", PersonaDataEntryEmailAddress::placeholder()),
+            "alan@turing.hero"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &PersonaDataEntryEmailAddress::placeholder(),
+            json!("alan@turing.hero"),
+        );
+        assert_json_roundtrip(&PersonaDataEntryEmailAddress::placeholder());
+    }
+}
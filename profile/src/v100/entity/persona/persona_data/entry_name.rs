@@ -0,0 +1,215 @@
+use crate::prelude::*;
+
+/// A persons name they have chosen to associated with a Persona, e.g. `"Bruce 'Batman' Wayne"`
+/// using Western name variant, or `"Lee Jun-fan"` using Eastern name variant (family
+/// name comes before given name(s)).
+///
+/// Nickname is optional in the sense that it can be left blank. Family name and given names
+/// are never empty, at least one of them is non empty.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonaDataEntryName {
+    /// Whether family name comes before or after given name(s).
+    pub variant: PersonaDataNameVariant,
+
+    /// The family name of a person, e.g. `"Wayne"`.
+    pub family_name: String,
+
+    /// The given name(s) of a person, e.g. `"Bruce"`.
+    pub given_names: String,
+
+    /// A nickname of a person, e.g. `"Batman"`, might be empty.
+    pub nickname: String,
+}
+
+/// The order of family name and given name(s) in a `PersonaDataEntryName`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PersonaDataNameVariant {
+    /// Given name(s) comes before family name, e.g. `"Bruce Wayne"`.
+    Western,
+
+    /// Family name comes before given name(s), e.g. `"Lee Jun-fan"`.
+    Eastern,
+}
+
+impl PersonaDataEntryName {
+    /// Instantiates a new `PersonaDataEntryName`, returns `Err` if both `family_name`
+    /// and `given_names` are empty.
+    pub fn new(
+        variant: PersonaDataNameVariant,
+        family_name: impl AsRef<str>,
+        given_names: impl AsRef<str>,
+        nickname: impl AsRef<str>,
+    ) -> Result<Self> {
+        let family_name = family_name.as_ref().trim().to_string();
+        let given_names = given_names.as_ref().trim().to_string();
+        if family_name.is_empty() && given_names.is_empty() {
+            return Err(CommonError::PersonaDataInvalidNameEmpty);
+        }
+        Ok(Self {
+            variant,
+            family_name,
+            given_names,
+            nickname: nickname.as_ref().trim().to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for PersonaDataEntryName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let full_name = match self.variant {
+            PersonaDataNameVariant::Western => {
+                [&self.given_names, &self.family_name]
+            }
+            PersonaDataNameVariant::Eastern => {
+                [&self.family_name, &self.given_names]
+            }
+        }
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .join(" ");
+
+        if self.nickname.is_empty() {
+            write!(f, "{}", full_name)
+        } else {
+            write!(f, "{} ({})", full_name, self.nickname)
+        }
+    }
+}
+
+impl HasPlaceholder for PersonaDataEntryName {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(PersonaDataNameVariant::Western, "Wayne", "Bruce", "Batman")
+            .expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(PersonaDataNameVariant::Eastern, "Lee", "Jun-fan", "Bruce")
+            .expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            PersonaDataEntryName::placeholder(),
+            PersonaDataEntryName::placeholder()
+        );
+        assert_eq!(
+            PersonaDataEntryName::placeholder_other(),
+            PersonaDataEntryName::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            PersonaDataEntryName::placeholder(),
+            PersonaDataEntryName::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_trims() {
+        let sut = PersonaDataEntryName::new(
+            PersonaDataNameVariant::Western,
+            "Alexander ",
+            " Cyon",
+            "Alex",
+        )
+        .unwrap();
+        assert_eq!(sut.family_name, "Alexander");
+        assert_eq!(sut.given_names, "Cyon");
+    }
+
+    #[test]
+    fn new_fails_when_family_and_given_names_empty() {
+        assert_eq!(
+            PersonaDataEntryName::new(
+                PersonaDataNameVariant::Western,
+                "",
+                " ",
+                "Alex"
+            ),
+            Err(CommonError::PersonaDataInvalidNameEmpty)
+        );
+    }
+
+    #[test]
+    fn display_western() {
+        assert_eq!(
+            format!("{}", PersonaDataEntryName::placeholder()),
+            "Bruce Wayne (Batman)"
+        );
+    }
+
+    #[test]
+    fn display_eastern() {
+        assert_eq!(
+            format!("{}", PersonaDataEntryName::placeholder_other()),
+            "Lee Jun-fan (Bruce)"
+        );
+    }
+
+    #[test]
+    fn display_without_nickname() {
+        let sut = PersonaDataEntryName::new(
+            PersonaDataNameVariant::Western,
+            "Wayne",
+            "",
+            "",
+        )
+        .unwrap();
+        assert_eq!(format!("{}", sut), "Wayne");
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &PersonaDataEntryName::placeholder(),
+            r#"
+            {
+                "variant": "western",
+                "familyName": "Wayne",
+                "givenNames": "Bruce",
+                "nickname": "Batman"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_eastern() {
+        assert_eq_after_json_roundtrip(
+            &PersonaDataEntryName::placeholder_other(),
+            r#"
+            {
+                "variant": "eastern",
+                "familyName": "Lee",
+                "givenNames": "Jun-fan",
+                "nickname": "Bruce"
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// A phone number associated with a Persona, e.g. `"+46 123 456 789"`,
+/// serialized as a plain JSON string.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(transparent)]
+#[display("{number}")]
+pub struct PersonaDataEntryPhoneNumber {
+    pub number: String,
+}
+
+impl PersonaDataEntryPhoneNumber {
+    /// Instantiates a new `PersonaDataEntryPhoneNumber`, returns `Err` if
+    /// `number` is empty.
+    pub fn new(number: impl AsRef<str>) -> Result<Self> {
+        let number = number.as_ref().trim().to_string();
+        if number.is_empty() {
+            return Err(CommonError::PersonaDataInvalidPhoneNumberEmpty);
+        }
+        Ok(Self { number })
+    }
+}
+
+impl HasPlaceholder for PersonaDataEntryPhoneNumber {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new("+46123456789").expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new("+44987654321").expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::placeholder(),
+            PersonaDataEntryPhoneNumber::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            PersonaDataEntryPhoneNumber::placeholder(),
+            PersonaDataEntryPhoneNumber::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_empty_fails() {
+        assert_eq!(
+            PersonaDataEntryPhoneNumber::new(""),
+            Err(CommonError::PersonaDataInvalidPhoneNumberEmpty)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", PersonaDataEntryPhoneNumber::placeholder()),
+            "+46123456789"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &PersonaDataEntryPhoneNumber::placeholder(),
+            json!("+46123456789"),
+        );
+        assert_json_roundtrip(&PersonaDataEntryPhoneNumber::placeholder());
+    }
+}
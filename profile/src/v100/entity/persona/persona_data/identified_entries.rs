@@ -0,0 +1,299 @@
+use crate::prelude::*;
+
+/// A stable identifier of an entry in `PersonaData`, allowing a dApp's
+/// shared persona data to reference individual fields.
+pub type PersonaDataEntryID = Uuid;
+
+/// A `PersonaDataEntryName` together with a stable `id`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{value}")]
+pub struct PersonaDataIdentifiedName {
+    pub id: PersonaDataEntryID,
+    pub value: PersonaDataEntryName,
+}
+
+/// A `PersonaDataEntryEmailAddress` together with a stable `id`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{value}")]
+pub struct PersonaDataIdentifiedEmailAddress {
+    pub id: PersonaDataEntryID,
+    pub value: PersonaDataEntryEmailAddress,
+}
+
+/// A `PersonaDataEntryPhoneNumber` together with a stable `id`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{value}")]
+pub struct PersonaDataIdentifiedPhoneNumber {
+    pub id: PersonaDataEntryID,
+    pub value: PersonaDataEntryPhoneNumber,
+}
+
+/// An ordered set of email addresses, identified by their `id`.
+pub type CollectionOfEmailAddresses =
+    IdentifiedVecVia<PersonaDataIdentifiedEmailAddress>;
+
+/// An ordered set of phone numbers, identified by their `id`.
+pub type CollectionOfPhoneNumbers =
+    IdentifiedVecVia<PersonaDataIdentifiedPhoneNumber>;
+
+impl PersonaDataIdentifiedName {
+    /// Instantiates a new identified name with a newly generated `id`.
+    pub fn new(value: PersonaDataEntryName) -> Self {
+        Self::with_id(id(), value)
+    }
+
+    /// Instantiates a new identified name with the provided `id`.
+    pub fn with_id(
+        id: PersonaDataEntryID,
+        value: PersonaDataEntryName,
+    ) -> Self {
+        Self { id, value }
+    }
+}
+
+impl PersonaDataIdentifiedEmailAddress {
+    /// Instantiates a new identified email address with a newly generated `id`.
+    pub fn new(value: PersonaDataEntryEmailAddress) -> Self {
+        Self::with_id(id(), value)
+    }
+
+    /// Instantiates a new identified email address with the provided `id`.
+    pub fn with_id(
+        id: PersonaDataEntryID,
+        value: PersonaDataEntryEmailAddress,
+    ) -> Self {
+        Self { id, value }
+    }
+}
+
+impl PersonaDataIdentifiedPhoneNumber {
+    /// Instantiates a new identified phone number with a newly generated `id`.
+    pub fn new(value: PersonaDataEntryPhoneNumber) -> Self {
+        Self::with_id(id(), value)
+    }
+
+    /// Instantiates a new identified phone number with the provided `id`.
+    pub fn with_id(
+        id: PersonaDataEntryID,
+        value: PersonaDataEntryPhoneNumber,
+    ) -> Self {
+        Self { id, value }
+    }
+}
+
+impl Identifiable for PersonaDataIdentifiedName {
+    type ID = PersonaDataEntryID;
+
+    fn id(&self) -> Self::ID {
+        self.id
+    }
+}
+
+impl Identifiable for PersonaDataIdentifiedEmailAddress {
+    type ID = PersonaDataEntryID;
+
+    fn id(&self) -> Self::ID {
+        self.id
+    }
+}
+
+impl Identifiable for PersonaDataIdentifiedPhoneNumber {
+    type ID = PersonaDataEntryID;
+
+    fn id(&self) -> Self::ID {
+        self.id
+    }
+}
+
+impl HasPlaceholder for PersonaDataIdentifiedName {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::with_id(
+            Uuid::from_str("00000000-0000-0000-0000-000000000000").unwrap(),
+            PersonaDataEntryName::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::with_id(
+            Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap(),
+            PersonaDataEntryName::placeholder_other(),
+        )
+    }
+}
+
+impl HasPlaceholder for PersonaDataIdentifiedEmailAddress {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::with_id(
+            Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap(),
+            PersonaDataEntryEmailAddress::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::with_id(
+            Uuid::from_str("00000000-0000-0000-0000-000000000003").unwrap(),
+            PersonaDataEntryEmailAddress::placeholder_other(),
+        )
+    }
+}
+
+impl HasPlaceholder for PersonaDataIdentifiedPhoneNumber {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::with_id(
+            Uuid::from_str("00000000-0000-0000-0000-000000000004").unwrap(),
+            PersonaDataEntryPhoneNumber::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::with_id(
+            Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap(),
+            PersonaDataEntryPhoneNumber::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            PersonaDataIdentifiedName::placeholder(),
+            PersonaDataIdentifiedName::placeholder_other()
+        );
+        assert_ne!(
+            PersonaDataIdentifiedEmailAddress::placeholder(),
+            PersonaDataIdentifiedEmailAddress::placeholder_other()
+        );
+        assert_ne!(
+            PersonaDataIdentifiedPhoneNumber::placeholder(),
+            PersonaDataIdentifiedPhoneNumber::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_generates_unique_ids() {
+        let value = PersonaDataEntryEmailAddress::placeholder();
+        assert_ne!(
+            PersonaDataIdentifiedEmailAddress::new(value.clone()).id,
+            PersonaDataIdentifiedEmailAddress::new(value).id
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", PersonaDataIdentifiedPhoneNumber::placeholder()),
+            "+46123456789"
+        );
+    }
+
+    #[test]
+    fn collection_with_duplicate_ids_is_deduplicated() {
+        let sut = CollectionOfEmailAddresses::from_iter([
+            PersonaDataIdentifiedEmailAddress::placeholder(),
+            PersonaDataIdentifiedEmailAddress::placeholder(),
+        ]);
+        assert_eq!(sut.len(), 1);
+    }
+
+    #[test]
+    fn json_roundtrip_name() {
+        assert_eq_after_json_roundtrip(
+            &PersonaDataIdentifiedName::placeholder(),
+            r#"
+            {
+                "id": "00000000-0000-0000-0000-000000000000",
+                "value": {
+                    "variant": "western",
+                    "familyName": "Wayne",
+                    "givenNames": "Bruce",
+                    "nickname": "Batman"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_email_address() {
+        assert_eq_after_json_roundtrip(
+            &PersonaDataIdentifiedEmailAddress::placeholder(),
+            r#"
+            {
+                "id": "00000000-0000-0000-0000-000000000002",
+                "value": "alan@turing.hero"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_phone_number() {
+        assert_eq_after_json_roundtrip(
+            &PersonaDataIdentifiedPhoneNumber::placeholder(),
+            r#"
+            {
+                "id": "00000000-0000-0000-0000-000000000004",
+                "value": "+46123456789"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_deserialize_uppercase_id() {
+        let json = r#"
+        {
+            "id": "F30A2A14-E25F-4597-8A49-E74FEDB10F44",
+            "value": "0700838198"
+        }
+        "#;
+        let sut =
+            serde_json::from_str::<PersonaDataIdentifiedPhoneNumber>(json)
+                .unwrap();
+        assert_eq!(
+            sut.id,
+            Uuid::from_str("f30a2a14-e25f-4597-8a49-e74fedb10f44").unwrap()
+        );
+        assert_eq!(sut.value.number, "0700838198");
+    }
+}
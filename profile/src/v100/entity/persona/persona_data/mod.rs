@@ -0,0 +1,13 @@
+mod entry_email_address;
+mod entry_name;
+mod entry_phone_number;
+mod identified_entries;
+mod persona_data;
+mod unmodeled_fields;
+
+pub use entry_email_address::*;
+pub use entry_name::*;
+pub use entry_phone_number::*;
+pub use identified_entries::*;
+pub use persona_data::*;
+pub use unmodeled_fields::*;
@@ -0,0 +1,281 @@
+use crate::prelude::*;
+
+/// Personal information a user has associated with a certain Persona, of different kinds, such as name,
+/// email addresses and phone numbers. Every entry has a stable ID, so that a dApp's shared persona data
+/// can reference individual fields.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonaData {
+    /// A persons name they have chosen to associated with a Persona, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub name: Option<PersonaDataIdentifiedName>,
+
+    /// An ordered set of email addresses associated with a Persona.
+    #[serde(default)]
+    pub email_addresses: CollectionOfEmailAddresses,
+
+    /// An ordered set of phone numbers associated with a Persona.
+    #[serde(default)]
+    pub phone_numbers: CollectionOfPhoneNumbers,
+
+    /// Fields not modeled by Radix Wallet Kit, e.g. `postalAddresses`,
+    /// `creditCards` and `urls`, preserved as is.
+    #[serde(flatten)]
+    pub unmodeled_fields: PersonaDataUnmodeledFields,
+}
+
+impl PersonaData {
+    /// Instantiates a new `PersonaData` from `name`, `email_addresses` and `phone_numbers`.
+    pub fn new(
+        name: Option<PersonaDataIdentifiedName>,
+        email_addresses: CollectionOfEmailAddresses,
+        phone_numbers: CollectionOfPhoneNumbers,
+    ) -> Self {
+        Self {
+            name,
+            email_addresses,
+            phone_numbers,
+            unmodeled_fields: PersonaDataUnmodeledFields::default(),
+        }
+    }
+
+    /// Returns the IDs of **all** entries of this persona data, i.e. the ID
+    /// of the name (if any), all email addresses and all phone numbers.
+    pub fn all_ids(&self) -> Vec<PersonaDataEntryID> {
+        self.name
+            .iter()
+            .map(|n| n.id)
+            .chain(self.email_addresses.iter().map(|e| e.id))
+            .chain(self.phone_numbers.iter().map(|p| p.id))
+            .collect()
+    }
+}
+
+impl HasPlaceholder for PersonaData {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            Some(PersonaDataIdentifiedName::placeholder()),
+            CollectionOfEmailAddresses::from_iter([
+                PersonaDataIdentifiedEmailAddress::placeholder(),
+            ]),
+            CollectionOfPhoneNumbers::from_iter([
+                PersonaDataIdentifiedPhoneNumber::placeholder(),
+            ]),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            Some(PersonaDataIdentifiedName::placeholder_other()),
+            CollectionOfEmailAddresses::from_iter([
+                PersonaDataIdentifiedEmailAddress::placeholder_other(),
+            ]),
+            CollectionOfPhoneNumbers::from_iter([
+                PersonaDataIdentifiedPhoneNumber::placeholder(),
+                PersonaDataIdentifiedPhoneNumber::placeholder_other(),
+            ]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(PersonaData::placeholder(), PersonaData::placeholder());
+        assert_eq!(
+            PersonaData::placeholder_other(),
+            PersonaData::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            PersonaData::placeholder(),
+            PersonaData::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn default_is_empty() {
+        let sut = PersonaData::default();
+        assert_eq!(sut.name, None);
+        assert!(sut.email_addresses.is_empty());
+        assert!(sut.phone_numbers.is_empty());
+        assert!(sut.all_ids().is_empty());
+    }
+
+    #[test]
+    fn all_ids() {
+        assert_eq!(
+            PersonaData::placeholder_other().all_ids(),
+            vec![
+                PersonaDataIdentifiedName::placeholder_other().id,
+                PersonaDataIdentifiedEmailAddress::placeholder_other().id,
+                PersonaDataIdentifiedPhoneNumber::placeholder().id,
+                PersonaDataIdentifiedPhoneNumber::placeholder_other().id,
+            ]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_eq_after_json_roundtrip(
+            &PersonaData::placeholder(),
+            r#"
+            {
+                "name": {
+                    "id": "00000000-0000-0000-0000-000000000000",
+                    "value": {
+                        "variant": "western",
+                        "familyName": "Wayne",
+                        "givenNames": "Bruce",
+                        "nickname": "Batman"
+                    }
+                },
+                "emailAddresses": [
+                    {
+                        "id": "00000000-0000-0000-0000-000000000002",
+                        "value": "alan@turing.hero"
+                    }
+                ],
+                "phoneNumbers": [
+                    {
+                        "id": "00000000-0000-0000-0000-000000000004",
+                        "value": "+46123456789"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_default() {
+        assert_eq_after_json_roundtrip(
+            &PersonaData::default(),
+            r#"
+            {
+                "emailAddresses": [],
+                "phoneNumbers": []
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_deserialize_wallet_app_format() {
+        let json = r#"
+        {
+            "postalAddresses": [],
+            "creditCards": [],
+            "emailAddresses": [
+                {
+                    "id": "8D8AB282-AB20-4D07-8461-06A31553AF1C",
+                    "value": "alex@cyon.com"
+                }
+            ],
+            "name": {
+                "id": "D264960B-1E2B-4E40-AD50-D281B9DBB6D1",
+                "value": {
+                    "nickname": "Alex",
+                    "familyName": "Alexander ",
+                    "variant": "western",
+                    "givenNames": "Cyon"
+                }
+            },
+            "phoneNumbers": [
+                {
+                    "id": "F30A2A14-E25F-4597-8A49-E74FEDB10F44",
+                    "value": "0700838198"
+                }
+            ],
+            "urls": []
+        }
+        "#;
+        let sut = serde_json::from_str::<PersonaData>(json).unwrap();
+        let name = sut.name.unwrap();
+        assert_eq!(
+            name.id,
+            Uuid::from_str("D264960B-1E2B-4E40-AD50-D281B9DBB6D1").unwrap()
+        );
+        assert_eq!(name.value.variant, PersonaDataNameVariant::Western);
+        assert_eq!(name.value.family_name, "Alexander ");
+        assert_eq!(name.value.given_names, "Cyon");
+        assert_eq!(name.value.nickname, "Alex");
+        assert_eq!(sut.email_addresses[0].value.email, "alex@cyon.com");
+        assert_eq!(sut.phone_numbers[0].value.number, "0700838198");
+        assert_eq!(
+            sut.unmodeled_fields
+                .fields
+                .iter()
+                .map(|f| f.key.as_str())
+                .collect_vec(),
+            ["postalAddresses", "creditCards", "urls"]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_preserves_unmodeled_fields() {
+        let json = json!({
+            "name": {
+                "id": "00000000-0000-0000-0000-000000000000",
+                "value": {
+                    "variant": "western",
+                    "familyName": "Wayne",
+                    "givenNames": "Bruce",
+                    "nickname": "Batman"
+                }
+            },
+            "emailAddresses": [],
+            "phoneNumbers": [],
+            "postalAddresses": [
+                {
+                    "id": "00000000-0000-0000-0000-000000000005",
+                    "value": {
+                        "fields": [
+                            {
+                                "discriminator": "countryOrRegion",
+                                "countryOrRegion": "sweden"
+                            }
+                        ]
+                    }
+                }
+            ],
+            "creditCards": [],
+            "urls": [
+                {
+                    "id": "00000000-0000-0000-0000-000000000006",
+                    "value": "https://radixdlt.com"
+                }
+            ]
+        });
+        let sut = serde_json::from_value::<PersonaData>(json.clone()).unwrap();
+        assert_eq!(sut.unmodeled_fields.fields.len(), 3);
+        assert_eq!(serde_json::to_value(&sut).unwrap(), json);
+        assert_json_roundtrip(&sut);
+    }
+
+    #[test]
+    fn json_deserialize_empty() {
+        assert_eq!(
+            serde_json::from_str::<PersonaData>("{}").unwrap(),
+            PersonaData::default()
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+/// A field of `PersonaData` not modeled by Radix Wallet Kit, e.g. the
+/// `postalAddresses` written by the Radix Wallet apps, as JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct PersonaDataUnmodeledField {
+    /// The JSON key of the field, e.g. `"postalAddresses"`.
+    pub key: String,
+
+    /// The JSON value of the field, kept as is.
+    pub json: String,
+}
+
+/// The fields of `PersonaData` not modeled by Radix Wallet Kit, e.g.
+/// `postalAddresses`, `creditCards` and `urls` written by the Radix Wallet
+/// apps, kept in order so that they are not lost when a Profile is decoded
+/// and encoded again.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default, uniffi::Record)]
+pub struct PersonaDataUnmodeledFields {
+    pub fields: Vec<PersonaDataUnmodeledField>,
+}
+
+impl PersonaDataUnmodeledFields {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl Serialize for PersonaDataUnmodeledFields {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let values = self
+            .fields
+            .iter()
+            .map(|f| {
+                serde_json::Value::from_str(&f.json)
+                    .map(|v| (f.key.clone(), v))
+                    .map_err(serde::ser::Error::custom)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        serializer.collect_map(values)
+    }
+}
+
+impl<'de> Deserialize<'de> for PersonaDataUnmodeledFields {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let map = serde_json::Map::deserialize(deserializer)?;
+        Ok(Self {
+            fields: map
+                .into_iter()
+                .map(|(key, value)| PersonaDataUnmodeledField {
+                    key,
+                    json: value.to_string(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn default_is_empty() {
+        assert!(PersonaDataUnmodeledFields::default().is_empty());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let json = json!({
+            "urls": [
+                {
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "value": "https://radixdlt.com"
+                }
+            ],
+            "creditCards": []
+        });
+        let sut =
+            serde_json::from_value::<PersonaDataUnmodeledFields>(json.clone())
+                .unwrap();
+        assert_eq!(
+            sut.fields.iter().map(|f| f.key.as_str()).collect_vec(),
+            ["urls", "creditCards"]
+        );
+        assert_eq!(sut.fields[1].json, "[]");
+        assert_eq!(serde_json::to_value(&sut).unwrap(), json);
+    }
+
+    #[test]
+    fn serialize_invalid_json_fails() {
+        let sut = PersonaDataUnmodeledFields {
+            fields: vec![PersonaDataUnmodeledField {
+                key: "urls".to_owned(),
+                json: "not json".to_owned(),
+            }],
+        };
+        assert!(serde_json::to_value(sut).is_err());
+    }
+}
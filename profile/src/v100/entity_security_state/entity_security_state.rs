@@ -21,6 +21,13 @@ pub enum EntitySecurityState {
         #[serde(rename = "unsecuredEntityControl")]
         value: UnsecuredEntityControl,
     },
+
+    /// The account is controlled by an `AccessController` to which a
+    /// security structure has been applied.
+    Securified {
+        #[serde(rename = "securedEntityControl")]
+        value: SecuredEntityControl,
+    },
 }
 
 impl<'de> Deserialize<'de> for EntitySecurityState {
@@ -31,12 +38,19 @@ impl<'de> Deserialize<'de> for EntitySecurityState {
         // https://github.com/serde-rs/serde/issues/1343#issuecomment-409698470
         #[derive(Deserialize, Serialize)]
         struct Wrapper {
-            #[serde(rename = "discriminator")]
-            _ignore: String,
+            discriminator: String,
             #[serde(flatten, with = "EntitySecurityState")]
             value: EntitySecurityState,
         }
-        Wrapper::deserialize(deserializer).map(|w| w.value)
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        if wrapper.discriminator != wrapper.value.discriminator() {
+            return Err(de::Error::custom(format!(
+                "Invalid EntitySecurityState, discriminator '{}' does not match '{}'",
+                wrapper.discriminator,
+                wrapper.value.discriminator()
+            )));
+        }
+        Ok(wrapper.value)
     }
 }
 
@@ -48,22 +62,88 @@ impl Serialize for EntitySecurityState {
     {
         let mut state =
             serializer.serialize_struct("EntitySecurityState", 2)?;
+        state.serialize_field("discriminator", self.discriminator())?;
         match self {
             EntitySecurityState::Unsecured { value } => {
-                state.serialize_field("discriminator", "unsecured")?;
                 state.serialize_field("unsecuredEntityControl", value)?;
             }
+            EntitySecurityState::Securified { value } => {
+                state.serialize_field("securedEntityControl", value)?;
+            }
         }
         state.end()
     }
 }
 
+impl EntitySecurityState {
+    /// The value of the `"discriminator"` key in JSON.
+    fn discriminator(&self) -> &'static str {
+        match self {
+            Self::Unsecured { .. } => "unsecured",
+            Self::Securified { .. } => "securified",
+        }
+    }
+}
+
 impl From<UnsecuredEntityControl> for EntitySecurityState {
     fn from(value: UnsecuredEntityControl) -> Self {
         Self::Unsecured { value }
     }
 }
 
+impl From<SecuredEntityControl> for EntitySecurityState {
+    fn from(value: SecuredEntityControl) -> Self {
+        Self::Securified { value }
+    }
+}
+
+impl EntitySecurityState {
+    /// If the entity is controlled by any factor instance of the factor
+    /// source with `factor_source_id`.
+    pub fn is_controlled_by_factor_source(
+        &self,
+        factor_source_id: &FactorSourceIDFromHash,
+    ) -> bool {
+        match self {
+            Self::Unsecured { value } => {
+                value.transaction_signing.factor_source_id == *factor_source_id
+            }
+            Self::Securified { value } => value
+                .all_factor_instances()
+                .iter()
+                .any(|fi| fi.factor_source_id == *factor_source_id),
+        }
+    }
+}
+
+impl PartialOrd for EntitySecurityState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Unsecured entities are ordered by the derivation index of their
+/// transaction signing factor instance and before securified entities,
+/// which are ordered by the address of their `AccessController`.
+impl Ord for EntitySecurityState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Unsecured { value: l }, Self::Unsecured { value: r }) => l
+                .transaction_signing
+                .derivation_path()
+                .last_component()
+                .cmp(r.transaction_signing.derivation_path().last_component()),
+            (Self::Unsecured { .. }, Self::Securified { .. }) => Ordering::Less,
+            (Self::Securified { .. }, Self::Unsecured { .. }) => {
+                Ordering::Greater
+            }
+            (Self::Securified { value: l }, Self::Securified { value: r }) => l
+                .access_controller_address
+                .cmp(&r.access_controller_address),
+        }
+    }
+}
+
 impl HasPlaceholder for EntitySecurityState {
     /// A placeholder used to facilitate unit tests.
     fn placeholder() -> Self {
@@ -104,6 +184,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unsecured_before_securified() {
+        assert!(
+            EntitySecurityState::placeholder()
+                < SecuredEntityControl::placeholder().into()
+        );
+    }
+
+    #[test]
+    fn securified_ordered_by_access_controller_address() {
+        let l: EntitySecurityState = SecuredEntityControl::placeholder().into();
+        let r: EntitySecurityState =
+            SecuredEntityControl::placeholder_other().into();
+        assert_eq!(
+            l.cmp(&r),
+            AccessControllerAddress::placeholder()
+                .cmp(&AccessControllerAddress::placeholder_other())
+        );
+    }
+
+    #[test]
+    fn is_controlled_by_factor_source() {
+        let unsecured = EntitySecurityState::placeholder();
+        let securified: EntitySecurityState =
+            SecuredEntityControl::placeholder().into();
+        let id = HierarchicalDeterministicFactorInstance::placeholder()
+            .factor_source_id;
+        assert!(unsecured.is_controlled_by_factor_source(&id));
+        assert!(securified.is_controlled_by_factor_source(&id));
+        assert!(!securified.is_controlled_by_factor_source(
+            &FactorSourceIDFromHash::placeholder_ledger()
+        ));
+    }
+
+    #[test]
+    fn securified_json_roundtrip() {
+        let model: EntitySecurityState =
+            SecuredEntityControl::placeholder().into();
+        assert_json_roundtrip(&model);
+        let json = serde_json::to_value(&model).unwrap();
+        assert_eq!(json["discriminator"], json!("securified"));
+        assert_eq!(
+            json["securedEntityControl"]["accessControllerAddress"],
+            json!("accesscontroller_rdx1c09f0qgjegdmmjh6cgcm8x3rm3x60ph0lq28cnnjhxq80pd00qdy0q")
+        );
+        assert_eq!(
+            json["securedEntityControl"]["securityStructure"]
+                ["securityStructureID"],
+            json!("ffffffff-ffff-ffff-ffff-ffffffffffff")
+        );
+    }
+
+    #[test]
+    fn json_discriminator_mismatch_fails() {
+        let mut json =
+            serde_json::to_value(EntitySecurityState::placeholder()).unwrap();
+        json["discriminator"] = json!("securified");
+        assert_json_value_fails::<EntitySecurityState>(json);
+
+        let securified: EntitySecurityState =
+            SecuredEntityControl::placeholder().into();
+        let mut json = serde_json::to_value(securified).unwrap();
+        json["discriminator"] = json!("unsecured");
+        assert_json_value_fails::<EntitySecurityState>(json);
+    }
+
+    #[test]
+    fn json_unknown_discriminator_fails() {
+        let mut json =
+            serde_json::to_value(EntitySecurityState::placeholder()).unwrap();
+        json["discriminator"] = json!("secret");
+        assert_json_value_fails::<EntitySecurityState>(json);
+    }
+
+    #[test]
+    fn json_missing_discriminator_fails() {
+        let mut json =
+            serde_json::to_value(EntitySecurityState::placeholder()).unwrap();
+        json.as_object_mut().unwrap().remove("discriminator");
+        assert_json_value_fails::<EntitySecurityState>(json);
+    }
+
     #[test]
     fn json_roundtrip() {
         let model = EntitySecurityState::placeholder();
@@ -0,0 +1,108 @@
+use crate::prelude::*;
+
+/// The factor instances of each role of the `AccessController` of a
+/// securified entity.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixOfFactorInstances {
+    /// Used for signing transactions.
+    pub primary_role: RoleOfFactorInstances,
+
+    /// Used to recover the entity, e.g. if the primary role factors are lost.
+    pub recovery_role: RoleOfFactorInstances,
+
+    /// Used to confirm recovery.
+    pub confirmation_role: RoleOfFactorInstances,
+}
+
+impl MatrixOfFactorInstances {
+    pub fn new(
+        primary_role: RoleOfFactorInstances,
+        recovery_role: RoleOfFactorInstances,
+        confirmation_role: RoleOfFactorInstances,
+    ) -> Self {
+        Self {
+            primary_role,
+            recovery_role,
+            confirmation_role,
+        }
+    }
+
+    /// All factor instances of all roles, without duplicates.
+    pub fn all_factor_instances(
+        &self,
+    ) -> Vec<HierarchicalDeterministicFactorInstance> {
+        [
+            &self.primary_role,
+            &self.recovery_role,
+            &self.confirmation_role,
+        ]
+        .into_iter()
+        .flat_map(|r| r.all_factor_instances())
+        .unique()
+        .collect()
+    }
+}
+
+impl HasPlaceholder for MatrixOfFactorInstances {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            RoleOfFactorInstances::placeholder(),
+            RoleOfFactorInstances::placeholder_other(),
+            RoleOfFactorInstances::placeholder_other(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            RoleOfFactorInstances::placeholder_other(),
+            RoleOfFactorInstances::placeholder(),
+            RoleOfFactorInstances::placeholder(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            MatrixOfFactorInstances::placeholder(),
+            MatrixOfFactorInstances::placeholder()
+        );
+        assert_eq!(
+            MatrixOfFactorInstances::placeholder_other(),
+            MatrixOfFactorInstances::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            MatrixOfFactorInstances::placeholder(),
+            MatrixOfFactorInstances::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn all_factor_instances_without_duplicates() {
+        assert_eq!(
+            MatrixOfFactorInstances::placeholder().all_factor_instances(),
+            vec![
+                HierarchicalDeterministicFactorInstance::placeholder(),
+                HierarchicalDeterministicFactorInstance::placeholder_other()
+            ]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&MatrixOfFactorInstances::placeholder());
+    }
+}
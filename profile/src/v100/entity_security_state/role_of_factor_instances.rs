@@ -0,0 +1,196 @@
+use crate::prelude::*;
+
+/// The factor instances of a role - primary, recovery or confirmation - of
+/// the `AccessController` of a securified entity. The role is fulfilled if
+/// `threshold` many of the `threshold_factors` sign, or if any single of the
+/// `override_factors` sign.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleOfFactorInstances {
+    /// Factor instances of which `threshold` many are required to sign.
+    pub threshold_factors: Vec<HierarchicalDeterministicFactorInstance>,
+
+    /// The number of `threshold_factors` required to sign.
+    pub threshold: u16,
+
+    /// Factor instances of which any single one is sufficient to sign.
+    pub override_factors: Vec<HierarchicalDeterministicFactorInstance>,
+}
+
+impl RoleOfFactorInstances {
+    /// Returns `Err` if `threshold` is zero while there are
+    /// `threshold_factors` - which would fulfill the role without any
+    /// signature - or if `threshold` is greater than the number of
+    /// `threshold_factors`.
+    pub fn new<I, J>(
+        threshold_factors: I,
+        threshold: u16,
+        override_factors: J,
+    ) -> Result<Self>
+    where
+        I: IntoIterator<Item = HierarchicalDeterministicFactorInstance>,
+        J: IntoIterator<Item = HierarchicalDeterministicFactorInstance>,
+    {
+        let threshold_factors = threshold_factors.into_iter().collect_vec();
+        if threshold == 0 && !threshold_factors.is_empty() {
+            return Err(CommonError::RoleThresholdZeroWithThresholdFactors);
+        }
+        if threshold as usize > threshold_factors.len() {
+            return Err(CommonError::InvalidRoleThreshold {
+                threshold,
+                threshold_factor_count: threshold_factors.len() as u16,
+            });
+        }
+        Ok(Self {
+            threshold_factors,
+            threshold,
+            override_factors: override_factors.into_iter().collect(),
+        })
+    }
+
+    /// All factor instances of this role, threshold factors first.
+    pub fn all_factor_instances(
+        &self,
+    ) -> Vec<HierarchicalDeterministicFactorInstance> {
+        self.threshold_factors
+            .iter()
+            .chain(self.override_factors.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleOfFactorInstances {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RoleOfFactorInstances, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wrapper {
+            threshold_factors: Vec<HierarchicalDeterministicFactorInstance>,
+            threshold: u16,
+            override_factors: Vec<HierarchicalDeterministicFactorInstance>,
+        }
+        let wrapped = Wrapper::deserialize(deserializer)?;
+        RoleOfFactorInstances::new(
+            wrapped.threshold_factors,
+            wrapped.threshold,
+            wrapped.override_factors,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+impl HasPlaceholder for RoleOfFactorInstances {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            [HierarchicalDeterministicFactorInstance::placeholder()],
+            1,
+            [],
+        )
+        .expect("Valid placeholder")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            [],
+            0,
+            [HierarchicalDeterministicFactorInstance::placeholder_other()],
+        )
+        .expect("Valid placeholder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            RoleOfFactorInstances::placeholder(),
+            RoleOfFactorInstances::placeholder()
+        );
+        assert_eq!(
+            RoleOfFactorInstances::placeholder_other(),
+            RoleOfFactorInstances::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            RoleOfFactorInstances::placeholder(),
+            RoleOfFactorInstances::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn threshold_too_high() {
+        assert_eq!(
+            RoleOfFactorInstances::new(
+                [HierarchicalDeterministicFactorInstance::placeholder()],
+                2,
+                []
+            ),
+            Err(CommonError::InvalidRoleThreshold {
+                threshold: 2,
+                threshold_factor_count: 1
+            })
+        );
+    }
+
+    #[test]
+    fn threshold_zero_with_threshold_factors() {
+        assert_eq!(
+            RoleOfFactorInstances::new(
+                [HierarchicalDeterministicFactorInstance::placeholder()],
+                0,
+                [HierarchicalDeterministicFactorInstance::placeholder_other()]
+            ),
+            Err(CommonError::RoleThresholdZeroWithThresholdFactors)
+        );
+    }
+
+    #[test]
+    fn all_factor_instances() {
+        let sut = RoleOfFactorInstances::new(
+            [HierarchicalDeterministicFactorInstance::placeholder()],
+            1,
+            [HierarchicalDeterministicFactorInstance::placeholder_other()],
+        )
+        .unwrap();
+        assert_eq!(
+            sut.all_factor_instances(),
+            vec![
+                HierarchicalDeterministicFactorInstance::placeholder(),
+                HierarchicalDeterministicFactorInstance::placeholder_other()
+            ]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&RoleOfFactorInstances::placeholder());
+        assert_json_roundtrip(&RoleOfFactorInstances::placeholder_other());
+    }
+
+    #[test]
+    fn json_threshold_zero_with_threshold_factors_fails() {
+        let mut json =
+            serde_json::to_value(RoleOfFactorInstances::placeholder()).unwrap();
+        json["threshold"] = json!(0);
+        assert_json_value_fails::<RoleOfFactorInstances>(json);
+    }
+
+    #[test]
+    fn json_threshold_too_high_fails() {
+        let mut json =
+            serde_json::to_value(RoleOfFactorInstances::placeholder()).unwrap();
+        json["threshold"] = json!(2);
+        assert_json_value_fails::<RoleOfFactorInstances>(json);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::prelude::*;
+
+/// Advanced security control of a securified entity, i.e. an entity to which
+/// a security structure has been applied, meaning it is controlled by an
+/// `AccessController` with a potential Multi-Factor setup.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SecuredEntityControl {
+    /// The address of the `AccessController` controlling the entity.
+    pub access_controller_address: AccessControllerAddress,
+
+    /// The security structure which was applied to the entity.
+    pub security_structure: SecurityStructureOfFactorInstances,
+}
+
+impl SecuredEntityControl {
+    pub fn new(
+        access_controller_address: AccessControllerAddress,
+        security_structure: SecurityStructureOfFactorInstances,
+    ) -> Self {
+        Self {
+            access_controller_address,
+            security_structure,
+        }
+    }
+
+    /// All factor instances of all roles controlling the entity.
+    pub fn all_factor_instances(
+        &self,
+    ) -> Vec<HierarchicalDeterministicFactorInstance> {
+        self.security_structure
+            .matrix_of_factors
+            .all_factor_instances()
+    }
+}
+
+impl HasPlaceholder for SecuredEntityControl {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            AccessControllerAddress::placeholder(),
+            SecurityStructureOfFactorInstances::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            AccessControllerAddress::placeholder_other(),
+            SecurityStructureOfFactorInstances::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SecuredEntityControl::placeholder(),
+            SecuredEntityControl::placeholder()
+        );
+        assert_eq!(
+            SecuredEntityControl::placeholder_other(),
+            SecuredEntityControl::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SecuredEntityControl::placeholder(),
+            SecuredEntityControl::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&SecuredEntityControl::placeholder());
+    }
+}
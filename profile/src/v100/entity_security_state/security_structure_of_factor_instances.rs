@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+/// The ID of a security structure - a configuration of which factors are
+/// used for each role - which can be applied to entities to securify them.
+pub type SecurityStructureID = Uuid;
+
+/// A security structure which has been applied to a securified entity, i.e.
+/// the factor instances derived for the entity for each role, together with
+/// the ID of the security structure configuration which was used.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityStructureOfFactorInstances {
+    /// The ID of the security structure configuration which was applied.
+    #[serde(rename = "securityStructureID")]
+    pub security_structure_id: SecurityStructureID,
+
+    /// The factor instances of each role.
+    pub matrix_of_factors: MatrixOfFactorInstances,
+}
+
+impl SecurityStructureOfFactorInstances {
+    pub fn new(
+        security_structure_id: SecurityStructureID,
+        matrix_of_factors: MatrixOfFactorInstances,
+    ) -> Self {
+        Self {
+            security_structure_id,
+            matrix_of_factors,
+        }
+    }
+}
+
+impl HasPlaceholder for SecurityStructureOfFactorInstances {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            Uuid::from_str("ffffffff-ffff-ffff-ffff-ffffffffffff").unwrap(),
+            MatrixOfFactorInstances::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            Uuid::from_str("eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee").unwrap(),
+            MatrixOfFactorInstances::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SecurityStructureOfFactorInstances::placeholder(),
+            SecurityStructureOfFactorInstances::placeholder()
+        );
+        assert_eq!(
+            SecurityStructureOfFactorInstances::placeholder_other(),
+            SecurityStructureOfFactorInstances::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SecurityStructureOfFactorInstances::placeholder(),
+            SecurityStructureOfFactorInstances::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(
+            &SecurityStructureOfFactorInstances::placeholder(),
+        );
+    }
+}
@@ -25,6 +25,24 @@ pub enum FactorSource {
         #[display("LedgerHWFS({value})")]
         value: LedgerHardwareWalletFactorSource,
     },
+
+    OffDeviceMnemonic {
+        #[serde(rename = "offDeviceMnemonic")]
+        #[display("OffDeviceMnemonicFS({value})")]
+        value: OffDeviceMnemonicFactorSource,
+    },
+
+    SecurityQuestions {
+        #[serde(rename = "securityQuestions")]
+        #[display("SecurityQuestionsFS({value})")]
+        value: SecurityQuestionsFactorSource,
+    },
+
+    TrustedContact {
+        #[serde(rename = "trustedContact")]
+        #[display("TrustedContactFS({value})")]
+        value: TrustedContactFactorSource,
+    },
 }
 
 impl BaseIsFactorSource for FactorSource {
@@ -32,6 +50,15 @@ impl BaseIsFactorSource for FactorSource {
         match self {
             FactorSource::Device { value } => value.factor_source_kind(),
             FactorSource::Ledger { value } => value.factor_source_kind(),
+            FactorSource::OffDeviceMnemonic { value } => {
+                value.factor_source_kind()
+            }
+            FactorSource::SecurityQuestions { value } => {
+                value.factor_source_kind()
+            }
+            FactorSource::TrustedContact { value } => {
+                value.factor_source_kind()
+            }
         }
     }
 
@@ -39,10 +66,37 @@ impl BaseIsFactorSource for FactorSource {
         match self {
             FactorSource::Device { value } => value.factor_source_id(),
             FactorSource::Ledger { value } => value.factor_source_id(),
+            FactorSource::OffDeviceMnemonic { value } => {
+                value.factor_source_id()
+            }
+            FactorSource::SecurityQuestions { value } => {
+                value.factor_source_id()
+            }
+            FactorSource::TrustedContact { value } => value.factor_source_id(),
         }
     }
 }
 
+impl FactorSource {
+    /// The properties shared between factor sources of all kinds.
+    pub fn common(&self) -> &FactorSourceCommon {
+        match self {
+            FactorSource::Device { value } => &value.common,
+            FactorSource::Ledger { value } => &value.common,
+            FactorSource::OffDeviceMnemonic { value } => &value.common,
+            FactorSource::SecurityQuestions { value } => &value.common,
+            FactorSource::TrustedContact { value } => &value.common,
+        }
+    }
+
+    /// If this factor source can derive secp256k1 keys using BIP44 like
+    /// paths, i.e. if accounts created by the Olympia wallet can be imported
+    /// controlled by it.
+    pub fn supports_olympia(&self) -> bool {
+        self.common().supports_olympia()
+    }
+}
+
 impl From<DeviceFactorSource> for FactorSource {
     fn from(value: DeviceFactorSource) -> Self {
         FactorSource::Device { value }
@@ -55,6 +109,24 @@ impl From<LedgerHardwareWalletFactorSource> for FactorSource {
     }
 }
 
+impl From<OffDeviceMnemonicFactorSource> for FactorSource {
+    fn from(value: OffDeviceMnemonicFactorSource) -> Self {
+        FactorSource::OffDeviceMnemonic { value }
+    }
+}
+
+impl From<SecurityQuestionsFactorSource> for FactorSource {
+    fn from(value: SecurityQuestionsFactorSource) -> Self {
+        FactorSource::SecurityQuestions { value }
+    }
+}
+
+impl From<TrustedContactFactorSource> for FactorSource {
+    fn from(value: TrustedContactFactorSource) -> Self {
+        FactorSource::TrustedContact { value }
+    }
+}
+
 impl<'de> Deserialize<'de> for FactorSource {
     #[cfg(not(tarpaulin_include))] // false negative
     fn deserialize<D: Deserializer<'de>>(
@@ -90,6 +162,27 @@ impl Serialize for FactorSource {
                 state.serialize_field(discriminator_key, discriminant)?;
                 state.serialize_field(discriminant, ledger)?;
             }
+            FactorSource::OffDeviceMnemonic {
+                value: off_device_mnemonic,
+            } => {
+                let discriminant = "offDeviceMnemonic";
+                state.serialize_field(discriminator_key, discriminant)?;
+                state.serialize_field(discriminant, off_device_mnemonic)?;
+            }
+            FactorSource::SecurityQuestions {
+                value: security_questions,
+            } => {
+                let discriminant = "securityQuestions";
+                state.serialize_field(discriminator_key, discriminant)?;
+                state.serialize_field(discriminant, security_questions)?;
+            }
+            FactorSource::TrustedContact {
+                value: trusted_contact,
+            } => {
+                let discriminant = "trustedContact";
+                state.serialize_field(discriminator_key, discriminant)?;
+                state.serialize_field(discriminant, trusted_contact)?;
+            }
         }
         state.end()
     }
@@ -127,12 +220,48 @@ impl FactorSource {
             value: LedgerHardwareWalletFactorSource::placeholder(),
         }
     }
+
+    pub fn placeholder_off_device_mnemonic() -> Self {
+        Self::OffDeviceMnemonic {
+            value: OffDeviceMnemonicFactorSource::placeholder(),
+        }
+    }
+
+    pub fn placeholder_security_questions() -> Self {
+        Self::SecurityQuestions {
+            value: SecurityQuestionsFactorSource::placeholder(),
+        }
+    }
+
+    pub fn placeholder_trusted_contact() -> Self {
+        Self::TrustedContact {
+            value: TrustedContactFactorSource::placeholder(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    fn common() {
+        assert_eq!(
+            FactorSource::placeholder_ledger().common(),
+            &LedgerHardwareWalletFactorSource::placeholder().common
+        );
+        assert_eq!(
+            FactorSource::placeholder_trusted_contact().common(),
+            &TrustedContactFactorSource::placeholder().common
+        );
+    }
+
+    #[test]
+    fn supports_olympia() {
+        assert!(FactorSource::placeholder_device_olympia().supports_olympia());
+        assert!(!FactorSource::placeholder_device_babylon().supports_olympia());
+    }
+
     #[test]
     fn equality() {
         assert_eq!(FactorSource::placeholder(), FactorSource::placeholder());
@@ -174,6 +303,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn factor_source_id_off_device_mnemonic() {
+        assert_eq!(
+            FactorSource::placeholder_off_device_mnemonic().factor_source_id(),
+            OffDeviceMnemonicFactorSource::placeholder().factor_source_id()
+        );
+    }
+
+    #[test]
+    fn factor_source_kind_off_device_mnemonic() {
+        assert_eq!(
+            FactorSource::placeholder_off_device_mnemonic()
+                .factor_source_kind(),
+            FactorSourceKind::OffDeviceMnemonic
+        );
+    }
+
+    #[test]
+    fn into_from_off_device_mnemonic() {
+        let factor_source: FactorSource =
+            OffDeviceMnemonicFactorSource::placeholder().into();
+        assert_eq!(
+            factor_source,
+            FactorSource::OffDeviceMnemonic {
+                value: OffDeviceMnemonicFactorSource::placeholder()
+            }
+        );
+    }
+
+    #[test]
+    fn factor_source_kind_security_questions() {
+        assert_eq!(
+            FactorSource::placeholder_security_questions().factor_source_kind(),
+            FactorSourceKind::SecurityQuestions
+        );
+    }
+
+    #[test]
+    fn into_from_security_questions() {
+        let factor_source: FactorSource =
+            SecurityQuestionsFactorSource::placeholder().into();
+        assert_eq!(
+            factor_source,
+            FactorSource::SecurityQuestions {
+                value: SecurityQuestionsFactorSource::placeholder()
+            }
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_security_questions() {
+        let model = FactorSource::placeholder_security_questions();
+        let json = serde_json::to_value(&model).unwrap();
+        assert_eq!(json["discriminator"], json!("securityQuestions"));
+        assert_json_roundtrip(&model);
+    }
+
+    #[test]
+    fn factor_source_id_trusted_contact() {
+        assert_eq!(
+            FactorSource::placeholder_trusted_contact().factor_source_id(),
+            FactorSourceIDFromAddress::placeholder().into()
+        );
+    }
+
+    #[test]
+    fn into_from_trusted_contact() {
+        let factor_source: FactorSource =
+            TrustedContactFactorSource::placeholder().into();
+        assert_eq!(
+            factor_source,
+            FactorSource::TrustedContact {
+                value: TrustedContactFactorSource::placeholder()
+            }
+        );
+    }
+
     #[test]
     fn factor_source_kind_ledger() {
         assert_eq!(
@@ -270,4 +476,68 @@ mod tests {
             "#,
         )
     }
+
+    #[test]
+    fn json_roundtrip_off_device_mnemonic() {
+        let model = FactorSource::placeholder_off_device_mnemonic();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "discriminator": "offDeviceMnemonic",
+                "offDeviceMnemonic": {
+                    "id": {
+                        "kind": "offDeviceMnemonic",
+                        "body": "3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+                    },
+                    "common": {
+                        "addedOn": "2023-09-11T16:05:56.000Z",
+                        "cryptoParameters": {
+                            "supportedCurves": ["curve25519"],
+                            "supportedDerivationPathSchemes": ["cap26"]
+                        },
+                        "flags": [],
+                        "lastUsedOn": "2023-09-11T16:05:56.000Z"
+                    },
+                    "hint": {
+                        "label": "Story about a horse",
+                        "wordCount": 24
+                    }
+                }
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn json_roundtrip_trusted_contact() {
+        let model = FactorSource::placeholder_trusted_contact();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "discriminator": "trustedContact",
+                "trustedContact": {
+                    "id": {
+                        "kind": "trustedContact",
+                        "body": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease"
+                    },
+                    "common": {
+                        "addedOn": "2023-09-11T16:05:56.000Z",
+                        "cryptoParameters": {
+                            "supportedCurves": ["curve25519"],
+                            "supportedDerivationPathSchemes": []
+                        },
+                        "flags": [],
+                        "lastUsedOn": "2023-09-11T16:05:56.000Z"
+                    },
+                    "contact": {
+                        "emailAddress": "spaghetti@dangerous.me",
+                        "name": "Spaghetti Sam"
+                    }
+                }
+            }
+            "#,
+        )
+    }
 }
@@ -88,6 +88,10 @@ impl FactorSourceCommon {
         self.crypto_parameters.supports_babylon()
     }
 
+    pub fn supports_olympia(&self) -> bool {
+        self.crypto_parameters.supports_olympia()
+    }
+
     /// Checks if its Main Babylon Device Factor Source (BDFS).
     pub fn is_main_bdfs(&self) -> bool {
         self.supports_babylon() && self.flags.contains(&FactorSourceFlag::Main)
@@ -70,12 +70,26 @@ impl FactorSourceCryptoParameters {
         .expect("Valid Babylon and Olympia parameters")
     }
 
+    /// Parameters of factor sources which are not hierarchical deterministic,
+    /// e.g. a `TrustedContactFactorSource`.
+    pub fn trusted_entity() -> Self {
+        Self::new([SLIP10Curve::Curve25519], [])
+            .expect("Valid trusted entity parameters")
+    }
+
     pub fn supports_babylon(&self) -> bool {
         self.supported_curves.contains(&SLIP10Curve::Curve25519)
             && self
                 .supported_derivation_path_schemes
                 .contains(&DerivationPathScheme::Cap26)
     }
+
+    pub fn supports_olympia(&self) -> bool {
+        self.supported_curves.contains(&SLIP10Curve::Secp256k1)
+            && self
+                .supported_derivation_path_schemes
+                .contains(&DerivationPathScheme::Bip44Olympia)
+    }
 }
 
 impl Default for FactorSourceCryptoParameters {
@@ -104,6 +118,14 @@ mod tests {
         assert!(FactorSourceCryptoParameters::babylon().supports_babylon());
     }
 
+    #[test]
+    fn trusted_entity_does_not_support_hd_derivation() {
+        let sut = FactorSourceCryptoParameters::trusted_entity();
+        assert!(sut.supported_derivation_path_schemes.is_empty());
+        assert!(!sut.supports_babylon());
+        assert!(!sut.supports_olympia());
+    }
+
     #[test]
     fn babylon_has_cap26_as_first_derivation_path_scheme() {
         assert_eq!(
@@ -151,6 +173,14 @@ mod tests {
             .contains(&DerivationPathScheme::Bip44Olympia));
     }
 
+    #[test]
+    fn supports_olympia() {
+        assert!(FactorSourceCryptoParameters::olympia().supports_olympia());
+        assert!(FactorSourceCryptoParameters::babylon_olympia_compatible()
+            .supports_olympia());
+        assert!(!FactorSourceCryptoParameters::babylon().supports_olympia());
+    }
+
     #[test]
     fn olympia_does_not_support_curve25519() {
         assert!(!FactorSourceCryptoParameters::olympia()
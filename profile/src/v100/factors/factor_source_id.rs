@@ -46,6 +46,16 @@ impl From<FactorSourceIDFromAddress> for FactorSourceID {
     }
 }
 
+impl FactorSourceID {
+    /// The kind of the FactorSource this ID refers to.
+    pub fn kind(&self) -> FactorSourceKind {
+        match self {
+            FactorSourceID::Hash { value } => value.kind,
+            FactorSourceID::Address { value } => value.kind,
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for FactorSourceID {
     #[cfg(not(tarpaulin_include))] // false negative
     fn deserialize<D: Deserializer<'de>>(
@@ -162,6 +172,17 @@ mod tests {
         )
     }
 
+    #[test]
+    fn kind() {
+        assert_eq!(
+            FactorSourceID::placeholder().kind(),
+            FactorSourceKind::Device
+        );
+        let from_address: FactorSourceID =
+            FactorSourceIDFromAddress::placeholder().into();
+        assert_eq!(from_address.kind(), FactorSourceKind::TrustedContact);
+    }
+
     #[test]
     fn hash_into_as_roundtrip() {
         let from_hash = FactorSourceIDFromHash::placeholder();
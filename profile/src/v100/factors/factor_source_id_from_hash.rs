@@ -93,6 +93,38 @@ impl FactorSourceIDFromHash {
             MnemonicWithPassphrase::placeholder_other(),
         )
     }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_off_device() -> Self {
+        Self::from_mnemonic_with_passphrase(
+            FactorSourceKind::OffDeviceMnemonic,
+            MnemonicWithPassphrase::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_off_device_other() -> Self {
+        Self::from_mnemonic_with_passphrase(
+            FactorSourceKind::OffDeviceMnemonic,
+            MnemonicWithPassphrase::placeholder_other(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_security_questions() -> Self {
+        Self::from_mnemonic_with_passphrase(
+            FactorSourceKind::SecurityQuestions,
+            MnemonicWithPassphrase::new(Mnemonic::placeholder()),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_security_questions_other() -> Self {
+        Self::from_mnemonic_with_passphrase(
+            FactorSourceKind::SecurityQuestions,
+            MnemonicWithPassphrase::new(Mnemonic::placeholder_other()),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -82,6 +82,14 @@ impl FactorSourceKind {
     }
 }
 
+impl FactorSourceKind {
+    /// If factor sources of this kind can be used to create new entities,
+    /// i.e. Accounts and Personas.
+    pub fn is_entity_creating(&self) -> bool {
+        matches!(self, Self::Device | Self::LedgerHQHardwareWallet)
+    }
+}
+
 impl std::fmt::Display for FactorSourceKind {
     #[cfg(not(tarpaulin_include))] // false negative
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -126,6 +134,15 @@ mod tests {
         assert!(FactorSourceKind::Device < FactorSourceKind::TrustedContact);
     }
 
+    #[test]
+    fn is_entity_creating() {
+        assert!(FactorSourceKind::Device.is_entity_creating());
+        assert!(FactorSourceKind::LedgerHQHardwareWallet.is_entity_creating());
+        assert!(!FactorSourceKind::OffDeviceMnemonic.is_entity_creating());
+        assert!(!FactorSourceKind::SecurityQuestions.is_entity_creating());
+        assert!(!FactorSourceKind::TrustedContact.is_entity_creating());
+    }
+
     #[test]
     fn discriminant() {
         assert_eq!(FactorSourceKind::Device.discriminant(), "device");
@@ -63,12 +63,9 @@ impl TryFrom<FactorSource> for LedgerHardwareWalletFactorSource {
     type Error = CommonError;
 
     fn try_from(value: FactorSource) -> Result<Self> {
-        match value {
-            FactorSource::Ledger { value: factor } => Ok(factor),
-            FactorSource::Device { value: _ } => {
-                Err(Self::Error::ExpectedLedgerHardwareWalletFactorSourceGotSomethingElse)
-            }
-        }
+        value.into_ledger().map_err(|_| {
+            Self::Error::ExpectedLedgerHardwareWalletFactorSourceGotSomethingElse
+        })
     }
 }
 impl IsFactorSource for LedgerHardwareWalletFactorSource {
@@ -1,9 +1,15 @@
 mod device_factor_source;
 mod factor_sources;
 mod ledger_hardware_wallet_factor_source;
+mod off_device_mnemonic_factor_source;
 mod private_hierarchical_deterministic_factor_source;
+mod security_questions_factor_source;
+mod trusted_contact_factor_source;
 
 pub use device_factor_source::*;
 pub use factor_sources::*;
 pub use ledger_hardware_wallet_factor_source::*;
+pub use off_device_mnemonic_factor_source::*;
 pub use private_hierarchical_deterministic_factor_source::*;
+pub use security_questions_factor_source::*;
+pub use trusted_contact_factor_source::*;
@@ -0,0 +1,5 @@
+mod off_device_mnemonic_factor_source;
+mod off_device_mnemonic_hint;
+
+pub use off_device_mnemonic_factor_source::*;
+pub use off_device_mnemonic_hint::*;
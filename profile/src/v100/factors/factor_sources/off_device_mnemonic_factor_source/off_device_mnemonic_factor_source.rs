@@ -0,0 +1,268 @@
+use crate::prelude::*;
+
+/// A factor source representing a mnemonic (and optional BIP39 passphrase)
+/// which is **not** stored on the device, instead the user has to input it
+/// each time it is used, e.g. during signing.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("{hint} {id}")]
+pub struct OffDeviceMnemonicFactorSource {
+    /// Unique and stable identifier of this factor source, stemming from the
+    /// hash of a special child key of the HD root of the mnemonic.
+    pub id: FactorSourceIDFromHash,
+
+    /// Common properties shared between FactorSources of different kinds,
+    /// describing its state, when added, and supported cryptographic parameters.
+    ///
+    /// Has interior mutability since we must be able to update the
+    /// last used date.
+    pub common: FactorSourceCommon,
+
+    /// Properties describing a OffDeviceMnemonicFactorSource to help user disambiguate between it and another one.
+    pub hint: OffDeviceMnemonicHint,
+}
+
+impl TryFrom<FactorSource> for OffDeviceMnemonicFactorSource {
+    type Error = CommonError;
+
+    fn try_from(value: FactorSource) -> Result<Self> {
+        value.into_off_device_mnemonic().map_err(|_| {
+            Self::Error::ExpectedOffDeviceMnemonicFactorSourceGotSomethingElse
+        })
+    }
+}
+impl IsFactorSource for OffDeviceMnemonicFactorSource {
+    fn kind() -> FactorSourceKind {
+        FactorSourceKind::OffDeviceMnemonic
+    }
+}
+impl BaseIsFactorSource for OffDeviceMnemonicFactorSource {
+    fn factor_source_kind(&self) -> FactorSourceKind {
+        self.id.kind
+    }
+
+    fn factor_source_id(&self) -> FactorSourceID {
+        self.clone().id.into()
+    }
+}
+
+impl OffDeviceMnemonicFactorSource {
+    /// Instantiates a new `OffDeviceMnemonicFactorSource`
+    pub fn new(
+        id: FactorSourceIDFromHash,
+        common: FactorSourceCommon,
+        hint: OffDeviceMnemonicHint,
+    ) -> Self {
+        Self { id, common, hint }
+    }
+
+    /// Instantiates a new `OffDeviceMnemonicFactorSource` supporting Babylon,
+    /// the `mnemonic_with_passphrase` is only used to calculate the ID and
+    /// is not retained.
+    pub fn from_mnemonic_with_passphrase(
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+        label: DisplayName,
+    ) -> Self {
+        let hint = OffDeviceMnemonicHint::new(
+            label,
+            mnemonic_with_passphrase.mnemonic.word_count,
+        );
+        let id = FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+            Self::kind(),
+            mnemonic_with_passphrase,
+        );
+        Self::new(
+            id,
+            FactorSourceCommon::new(
+                FactorSourceCryptoParameters::babylon(),
+                [],
+            ),
+            hint,
+        )
+    }
+
+    /// Validates that `mnemonic_with_passphrase` - input by the user just in
+    /// time, e.g. during signing - is the mnemonic of this factor source, i.e.
+    /// that it hashes to the `id` of this factor source.
+    ///
+    /// Returns `Err` if the mnemonic does not match.
+    pub fn validate_mnemonic_with_passphrase(
+        &self,
+        mnemonic_with_passphrase: &MnemonicWithPassphrase,
+    ) -> Result<()> {
+        let id = FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+            self.factor_source_kind(),
+            mnemonic_with_passphrase.clone(),
+        );
+        if id != self.id {
+            return Err(CommonError::MnemonicDoesNotMatchFactorSource(
+                self.factor_source_id(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl HasPlaceholder for OffDeviceMnemonicFactorSource {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        let date = Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap();
+        Self::new(
+            FactorSourceIDFromHash::placeholder_off_device(),
+            FactorSourceCommon::with_values(
+                FactorSourceCryptoParameters::babylon(),
+                date,
+                date,
+                [],
+            ),
+            OffDeviceMnemonicHint::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        let date = Timestamp::parse("2023-12-24T17:13:56.123Z").unwrap();
+        Self::new(
+            FactorSourceIDFromHash::placeholder_off_device_other(),
+            FactorSourceCommon::with_values(
+                FactorSourceCryptoParameters::babylon(),
+                date,
+                date,
+                [],
+            ),
+            OffDeviceMnemonicHint::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            OffDeviceMnemonicFactorSource::placeholder(),
+            OffDeviceMnemonicFactorSource::placeholder()
+        );
+        assert_eq!(
+            OffDeviceMnemonicFactorSource::placeholder_other(),
+            OffDeviceMnemonicFactorSource::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            OffDeviceMnemonicFactorSource::placeholder(),
+            OffDeviceMnemonicFactorSource::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let model = OffDeviceMnemonicFactorSource::placeholder();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "id": {
+                    "kind": "offDeviceMnemonic",
+                    "body": "3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+                },
+                "common": {
+                    "addedOn": "2023-09-11T16:05:56.000Z",
+                    "cryptoParameters": {
+                        "supportedCurves": ["curve25519"],
+                        "supportedDerivationPathSchemes": ["cap26"]
+                    },
+                    "flags": [],
+                    "lastUsedOn": "2023-09-11T16:05:56.000Z"
+                },
+                "hint": {
+                    "label": "Story about a horse",
+                    "wordCount": 24
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn from_mnemonic_with_passphrase() {
+        let sut = OffDeviceMnemonicFactorSource::from_mnemonic_with_passphrase(
+            MnemonicWithPassphrase::placeholder(),
+            DisplayName::new("Story about a horse").unwrap(),
+        );
+        assert_eq!(sut.id, FactorSourceIDFromHash::placeholder_off_device());
+        assert_eq!(sut.hint, OffDeviceMnemonicHint::placeholder());
+        assert!(sut.common.supports_babylon());
+    }
+
+    #[test]
+    fn validate_mnemonic_with_passphrase() {
+        assert!(OffDeviceMnemonicFactorSource::placeholder()
+            .validate_mnemonic_with_passphrase(
+                &MnemonicWithPassphrase::placeholder()
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_wrong_mnemonic_with_passphrase() {
+        let sut = OffDeviceMnemonicFactorSource::placeholder();
+        assert_eq!(
+            sut.validate_mnemonic_with_passphrase(
+                &MnemonicWithPassphrase::placeholder_other()
+            ),
+            Err(CommonError::MnemonicDoesNotMatchFactorSource(
+                sut.factor_source_id()
+            ))
+        );
+    }
+
+    #[test]
+    fn factor_source_id() {
+        let sut = OffDeviceMnemonicFactorSource::placeholder();
+        let factor_source_id: FactorSourceID = sut.clone().id.into();
+        assert_eq!(factor_source_id, sut.factor_source_id());
+    }
+
+    #[test]
+    fn static_kind() {
+        assert_eq!(
+            OffDeviceMnemonicFactorSource::kind(),
+            FactorSourceKind::OffDeviceMnemonic
+        );
+    }
+
+    #[test]
+    fn from_factor_source() {
+        let sut = OffDeviceMnemonicFactorSource::placeholder();
+        let factor_source: FactorSource = sut.clone().into();
+        assert_eq!(
+            OffDeviceMnemonicFactorSource::try_from(factor_source),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_factor_source_invalid_got_device() {
+        let factor_source: FactorSource =
+            DeviceFactorSource::placeholder().into();
+        assert_eq!(
+            OffDeviceMnemonicFactorSource::try_from(factor_source),
+            Err(CommonError::ExpectedOffDeviceMnemonicFactorSourceGotSomethingElse)
+        );
+    }
+}
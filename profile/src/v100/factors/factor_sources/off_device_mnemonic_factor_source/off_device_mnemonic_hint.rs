@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+/// Properties describing an OffDeviceMnemonicFactorSource to help user
+/// disambiguate between it and another one, and to know how many words to
+/// input when it is used.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("{label} {word_count}")]
+pub struct OffDeviceMnemonicHint {
+    /// "Story about a horse and a battery"
+    pub label: DisplayName,
+
+    /// The number of words in the mnemonic, according to the BIP39
+    /// standard, a multiple of 3, from 12 to 24 words.
+    pub word_count: BIP39WordCount,
+}
+
+impl OffDeviceMnemonicHint {
+    /// Instantiates a new OffDeviceMnemonicHint from the specified label and
+    /// word count.
+    pub fn new(label: DisplayName, word_count: BIP39WordCount) -> Self {
+        Self { label, word_count }
+    }
+}
+
+impl HasPlaceholder for OffDeviceMnemonicHint {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            DisplayName::new("Story about a horse").unwrap(),
+            BIP39WordCount::TwentyFour,
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            DisplayName::new("Thrilled with a shark").unwrap(),
+            BIP39WordCount::Twelve,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            OffDeviceMnemonicHint::placeholder(),
+            OffDeviceMnemonicHint::placeholder()
+        );
+        assert_eq!(
+            OffDeviceMnemonicHint::placeholder_other(),
+            OffDeviceMnemonicHint::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            OffDeviceMnemonicHint::placeholder(),
+            OffDeviceMnemonicHint::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let model = OffDeviceMnemonicHint::placeholder();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "label": "Story about a horse",
+                "wordCount": 24
+            }
+            "#,
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use radix_engine_common::crypto::IsHash;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Record)]
 pub struct PrivateHierarchicalDeterministicFactorSource {
     pub mnemonic_with_passphrase: MnemonicWithPassphrase,
@@ -94,6 +96,48 @@ impl PrivateHierarchicalDeterministicFactorSource {
     }
 }
 
+impl PrivateHierarchicalDeterministicFactorSource {
+    /// Derives the public key of the account at `index` on the network with
+    /// the raw ID `network_id`, which might be a custom network, at the CAP26
+    /// path `m/44H/1022H/{network_id}H/525H/1460H/{index}H`.
+    pub fn derive_account_public_key_on_network(
+        &self,
+        network_id: u8,
+        index: HDPathValue,
+    ) -> PublicKey {
+        let path = HDPath::from_components([
+            HDPathComponent::bip44_purpose(),
+            HDPathComponent::bip44_cointype(),
+            HDPathComponent::harden(network_id as HDPathValue),
+            HDPathComponent::harden(CAP26EntityKind::Account.discriminant()),
+            HDPathComponent::harden(
+                CAP26KeyKind::TransactionSigning.discriminant(),
+            ),
+            HDPathComponent::harden(index),
+        ]);
+        MnemonicWithPassphrase::derive_ed25519_private_key(
+            &self.mnemonic_with_passphrase.to_seed(),
+            &path,
+        )
+        .public_key()
+        .into()
+    }
+}
+
+impl PrivateHierarchicalDeterministicFactorSource {
+    /// Derives the private key at `derivation_path` and signs `msg_hash` with
+    /// it, returning the signature together with the derived public key.
+    pub fn sign(
+        &self,
+        msg_hash: &impl IsHash,
+        derivation_path: &DerivationPath,
+    ) -> SignatureWithPublicKey {
+        self.mnemonic_with_passphrase
+            .derive_private_key(derivation_path.clone())
+            .sign(msg_hash)
+    }
+}
+
 impl HasPlaceholder for PrivateHierarchicalDeterministicFactorSource {
     fn placeholder() -> Self {
         Self::new(
@@ -141,6 +185,44 @@ mod tests {
             .collect::<HashSet<_>>();
         assert_eq!(set.len(), n);
     }
+
+    #[test]
+    fn sign_uses_key_at_derivation_path() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let instance = sut
+            .derive_entity_creation_factor_instance::<AccountPath>(
+                NetworkID::Mainnet,
+                0,
+            );
+        let signature = sut.sign(&hash("Test"), &instance.path.clone().into());
+        assert_eq!(signature.public_key(), instance.public_key);
+    }
+
+    #[test]
+    fn derive_account_public_key_on_known_network_matches_account_path() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let instance = sut
+            .derive_entity_creation_factor_instance::<AccountPath>(
+                NetworkID::Stokenet,
+                3,
+            );
+        assert_eq!(
+            sut.derive_account_public_key_on_network(
+                NetworkID::Stokenet.discriminant(),
+                3
+            ),
+            instance.public_key().public_key
+        );
+    }
+
+    #[test]
+    fn derive_account_public_key_depends_on_network() {
+        let sut = PrivateHierarchicalDeterministicFactorSource::placeholder();
+        assert_ne!(
+            sut.derive_account_public_key_on_network(0x30, 0),
+            sut.derive_account_public_key_on_network(0xa0, 0)
+        );
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,9 @@
+mod security_question;
+mod security_questions_factor_source;
+mod security_questions_key_derivation_scheme;
+mod security_questions_sealed_mnemonic;
+
+pub use security_question::*;
+pub use security_questions_factor_source::*;
+pub use security_questions_key_derivation_scheme::*;
+pub use security_questions_sealed_mnemonic::*;
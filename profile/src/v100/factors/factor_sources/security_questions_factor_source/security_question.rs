@@ -0,0 +1,168 @@
+use crate::prelude::*;
+
+/// A personal question which the answer to should only be known to the user,
+/// used to seal a mnemonic in a `SecurityQuestionsFactorSource`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{question}")]
+pub struct SecurityQuestion {
+    /// A stable identifier of the question, unique within a set of questions.
+    pub id: u16,
+
+    /// "What was the make and model of your first car?"
+    pub question: String,
+}
+
+impl SecurityQuestion {
+    pub fn new(id: u16, question: &str) -> Self {
+        Self {
+            id,
+            question: question.to_string(),
+        }
+    }
+}
+
+impl HasPlaceholder for SecurityQuestion {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(0, "What was the make and model of your first car?")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(1, "What was the name of your first pet?")
+    }
+}
+
+/// A `SecurityQuestion` together with the answer the user has given to it.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct SecurityQuestionAndAnswer {
+    pub question: SecurityQuestion,
+    pub answer: String,
+}
+
+impl SecurityQuestionAndAnswer {
+    pub fn new(question: SecurityQuestion, answer: &str) -> Self {
+        Self {
+            question,
+            answer: answer.to_string(),
+        }
+    }
+}
+
+impl HasPlaceholder for SecurityQuestionAndAnswer {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(SecurityQuestion::placeholder(), "Volvo 240")
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(SecurityQuestion::placeholder_other(), "Fido")
+    }
+}
+
+impl SecurityQuestionAndAnswer {
+    /// Placeholders used to facilitate unit tests, answers to six
+    /// different questions.
+    pub fn placeholders() -> Vec<Self> {
+        vec![
+            Self::placeholder(),
+            Self::placeholder_other(),
+            Self::new(
+                SecurityQuestion::new(
+                    2,
+                    "In which city did your parents meet?",
+                ),
+                "Gothenburg",
+            ),
+            Self::new(
+                SecurityQuestion::new(
+                    3,
+                    "What was the name of your first teacher?",
+                ),
+                "Mrs. Andersson",
+            ),
+            Self::new(
+                SecurityQuestion::new(
+                    4,
+                    "What was the name of the street you grew up on?",
+                ),
+                "Storgatan",
+            ),
+            Self::new(
+                SecurityQuestion::new(5, "What was your childhood nickname?"),
+                "Bubba",
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SecurityQuestion::placeholder(),
+            SecurityQuestion::placeholder()
+        );
+        assert_eq!(
+            SecurityQuestionAndAnswer::placeholder_other(),
+            SecurityQuestionAndAnswer::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SecurityQuestion::placeholder(),
+            SecurityQuestion::placeholder_other()
+        );
+        assert_ne!(
+            SecurityQuestionAndAnswer::placeholder(),
+            SecurityQuestionAndAnswer::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn placeholders_have_unique_questions() {
+        let placeholders = SecurityQuestionAndAnswer::placeholders();
+        assert_eq!(
+            placeholders
+                .iter()
+                .map(|qa| qa.question.id)
+                .unique()
+                .count(),
+            placeholders.len()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &SecurityQuestionAndAnswer::placeholder(),
+            r#"
+            {
+                "question": {
+                    "id": 0,
+                    "question": "What was the make and model of your first car?"
+                },
+                "answer": "Volvo 240"
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use crate::prelude::*;
+
+/// A factor source representing a user owned mnemonic (*never* any BIP39
+/// passphrase) which is encrypted by the answers to personal security
+/// questions, and which can be decrypted as long as enough of the answers
+/// are correct.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("{id}")]
+pub struct SecurityQuestionsFactorSource {
+    /// Unique and stable identifier of this factor source, stemming from the
+    /// hash of a special child key of the HD root of the mnemonic.
+    pub id: FactorSourceIDFromHash,
+
+    /// Common properties shared between FactorSources of different kinds,
+    /// describing its state, when added, and supported cryptographic parameters.
+    ///
+    /// Has interior mutability since we must be able to update the
+    /// last used date.
+    pub common: FactorSourceCommon,
+
+    /// The mnemonic of this factor source, encrypted by the answers to the
+    /// security questions.
+    pub sealed_mnemonic: SecurityQuestionsSealedMnemonic,
+}
+
+/// Creates a new `SecurityQuestionsFactorSource` by sealing `mnemonic` with
+/// the answers in `questions_and_answers`.
+#[uniffi::export]
+pub fn new_security_questions_factor_source_by_encrypting_mnemonic(
+    mnemonic: Mnemonic,
+    questions_and_answers: Vec<SecurityQuestionAndAnswer>,
+) -> Result<SecurityQuestionsFactorSource> {
+    SecurityQuestionsFactorSource::new_by_encrypting(
+        mnemonic,
+        questions_and_answers,
+    )
+}
+
+/// Decrypts the mnemonic of `factor_source` using `answers`.
+#[uniffi::export]
+pub fn security_questions_factor_source_decrypt(
+    factor_source: SecurityQuestionsFactorSource,
+    answers: Vec<SecurityQuestionAndAnswer>,
+) -> Result<Mnemonic> {
+    factor_source.decrypt(answers)
+}
+
+impl TryFrom<FactorSource> for SecurityQuestionsFactorSource {
+    type Error = CommonError;
+
+    fn try_from(value: FactorSource) -> Result<Self> {
+        value.into_security_questions().map_err(|_| {
+            Self::Error::ExpectedSecurityQuestionsFactorSourceGotSomethingElse
+        })
+    }
+}
+impl IsFactorSource for SecurityQuestionsFactorSource {
+    fn kind() -> FactorSourceKind {
+        FactorSourceKind::SecurityQuestions
+    }
+}
+impl BaseIsFactorSource for SecurityQuestionsFactorSource {
+    fn factor_source_kind(&self) -> FactorSourceKind {
+        self.id.kind
+    }
+
+    fn factor_source_id(&self) -> FactorSourceID {
+        self.clone().id.into()
+    }
+}
+
+impl SecurityQuestionsFactorSource {
+    /// Instantiates a new `SecurityQuestionsFactorSource`
+    pub fn new(
+        id: FactorSourceIDFromHash,
+        common: FactorSourceCommon,
+        sealed_mnemonic: SecurityQuestionsSealedMnemonic,
+    ) -> Self {
+        Self {
+            id,
+            common,
+            sealed_mnemonic,
+        }
+    }
+
+    /// Instantiates a new `SecurityQuestionsFactorSource` supporting
+    /// Babylon, by sealing `mnemonic` with the answers in
+    /// `questions_and_answers`.
+    ///
+    /// Returns `Err` if the questions and answers are invalid, see
+    /// `SecurityQuestionsSealedMnemonic::new_by_encrypting`.
+    pub fn new_by_encrypting(
+        mnemonic: Mnemonic,
+        questions_and_answers: Vec<SecurityQuestionAndAnswer>,
+    ) -> Result<Self> {
+        let id = FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+            Self::kind(),
+            MnemonicWithPassphrase::new(mnemonic.clone()),
+        );
+        let sealed_mnemonic =
+            SecurityQuestionsSealedMnemonic::new_by_encrypting(
+                mnemonic,
+                questions_and_answers,
+            )?;
+        Ok(Self::new(
+            id,
+            FactorSourceCommon::new(
+                FactorSourceCryptoParameters::babylon(),
+                [],
+            ),
+            sealed_mnemonic,
+        ))
+    }
+
+    /// Decrypts the mnemonic of this factor source using `answers`.
+    ///
+    /// Returns `Err` if too few answers are correct.
+    pub fn decrypt(
+        &self,
+        answers: Vec<SecurityQuestionAndAnswer>,
+    ) -> Result<Mnemonic> {
+        self.sealed_mnemonic.decrypt(answers)
+    }
+}
+
+impl HasPlaceholder for SecurityQuestionsFactorSource {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        let date = Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap();
+        Self::new(
+            FactorSourceIDFromHash::placeholder_security_questions(),
+            FactorSourceCommon::with_values(
+                FactorSourceCryptoParameters::babylon(),
+                date,
+                date,
+                [],
+            ),
+            SecurityQuestionsSealedMnemonic::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        let date = Timestamp::parse("2023-12-24T17:13:56.123Z").unwrap();
+        Self::new(
+            FactorSourceIDFromHash::placeholder_security_questions_other(),
+            FactorSourceCommon::with_values(
+                FactorSourceCryptoParameters::babylon(),
+                date,
+                date,
+                [],
+            ),
+            SecurityQuestionsSealedMnemonic::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SecurityQuestionsFactorSource::placeholder(),
+            SecurityQuestionsFactorSource::placeholder()
+        );
+        assert_eq!(
+            SecurityQuestionsFactorSource::placeholder_other(),
+            SecurityQuestionsFactorSource::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SecurityQuestionsFactorSource::placeholder(),
+            SecurityQuestionsFactorSource::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn new_by_encrypting() {
+        let sut = SecurityQuestionsFactorSource::new_by_encrypting(
+            Mnemonic::placeholder(),
+            SecurityQuestionAndAnswer::placeholders(),
+        )
+        .unwrap();
+        assert_eq!(
+            sut.id,
+            FactorSourceIDFromHash::placeholder_security_questions()
+        );
+        assert!(sut.common.supports_babylon());
+        assert_eq!(
+            sut.decrypt(SecurityQuestionAndAnswer::placeholders()),
+            Ok(Mnemonic::placeholder())
+        );
+    }
+
+    #[test]
+    fn decrypted_mnemonic_matches_id() {
+        let sut = SecurityQuestionsFactorSource::placeholder();
+        let mnemonic = sut
+            .decrypt(SecurityQuestionAndAnswer::placeholders())
+            .unwrap();
+        assert_eq!(
+            FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+                FactorSourceKind::SecurityQuestions,
+                MnemonicWithPassphrase::new(mnemonic)
+            ),
+            sut.id
+        );
+    }
+
+    #[test]
+    fn decrypt_wrong_answers() {
+        let answers = SecurityQuestionAndAnswer::placeholders()
+            .into_iter()
+            .map(|qa| SecurityQuestionAndAnswer::new(qa.question, "wrong"))
+            .collect_vec();
+        assert_eq!(
+            SecurityQuestionsFactorSource::placeholder().decrypt(answers),
+            Err(CommonError::InvalidSecurityQuestionsAnswers)
+        );
+    }
+
+    #[test]
+    fn factor_source_id() {
+        let sut = SecurityQuestionsFactorSource::placeholder();
+        let factor_source_id: FactorSourceID = sut.clone().id.into();
+        assert_eq!(factor_source_id, sut.factor_source_id());
+    }
+
+    #[test]
+    fn static_kind() {
+        assert_eq!(
+            SecurityQuestionsFactorSource::kind(),
+            FactorSourceKind::SecurityQuestions
+        );
+    }
+
+    #[test]
+    fn from_factor_source() {
+        let sut = SecurityQuestionsFactorSource::placeholder();
+        let factor_source: FactorSource = sut.clone().into();
+        assert_eq!(
+            SecurityQuestionsFactorSource::try_from(factor_source),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_factor_source_invalid_got_device() {
+        let factor_source: FactorSource =
+            DeviceFactorSource::placeholder().into();
+        assert_eq!(
+            SecurityQuestionsFactorSource::try_from(factor_source),
+            Err(CommonError::ExpectedSecurityQuestionsFactorSourceGotSomethingElse)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&SecurityQuestionsFactorSource::placeholder());
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new_and_decrypt() {
+        let sut = new_security_questions_factor_source_by_encrypting_mnemonic(
+            Mnemonic::placeholder_other(),
+            SecurityQuestionAndAnswer::placeholders(),
+        )
+        .unwrap();
+        assert_eq!(
+            security_questions_factor_source_decrypt(
+                sut,
+                SecurityQuestionAndAnswer::placeholders()
+            ),
+            Ok(Mnemonic::placeholder_other())
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use crate::prelude::*;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+
+/// A versioned key derivation function (KDF) used to derive an encryption
+/// key from answers to security questions, used to seal the mnemonic of a
+/// `SecurityQuestionsFactorSource`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum SecurityQuestionsKeyDerivationScheme {
+    /// Argon2id, with 19 MiB of memory, 2 iterations and 1 degree of
+    /// parallelism, over the UTF8 encoding of the normalized answers, ordered
+    /// by question id and separated by `:`, salted with the random salt of
+    /// the sealed mnemonic. Answers are normalized by lowercasing them and
+    /// removing all characters which are not alphanumeric, e.g. whitespace
+    /// and punctuation.
+    #[default]
+    Version1,
+}
+
+impl SecurityQuestionsKeyDerivationScheme {
+    /// The version number of this scheme, used to tag sealed mnemonics with
+    /// the scheme used to derive their encryption keys.
+    pub fn version(&self) -> u32 {
+        match self {
+            Self::Version1 => 1,
+        }
+    }
+
+    /// A human readable description of this scheme.
+    pub fn description(&self) -> String {
+        match self {
+            Self::Version1 => {
+                "Argon2id-m19456-t2-p1-with-UTF8-encoding-of-normalized-answers-salted"
+                    .to_string()
+            }
+        }
+    }
+
+    /// Normalizes `answer` so that small differences in how the user inputs
+    /// the answer, such as casing, whitespace and punctuation, do not matter.
+    pub fn normalize_answer(&self, answer: &str) -> String {
+        match self {
+            Self::Version1 => answer
+                .to_lowercase()
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect(),
+        }
+    }
+
+    /// Derives a 32 bytes long encryption key from the answers in
+    /// `questions_and_answers`, regardless of their order, and `salt`.
+    pub fn kdf(
+        &self,
+        questions_and_answers: &[SecurityQuestionAndAnswer],
+        salt: &Hex32Bytes,
+    ) -> Hex32Bytes {
+        match self {
+            Self::Version1 => {
+                let password = questions_and_answers
+                    .iter()
+                    .sorted_by_key(|qa| qa.question.id)
+                    .map(|qa| self.normalize_answer(&qa.answer))
+                    .join(":");
+                let params = Params::new(19 * 1024, 2, 1, Some(32))
+                    .expect("Valid Argon2 params");
+                let mut okm = [0u8; 32];
+                Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                    .hash_password_into(
+                        password.as_bytes(),
+                        &salt.bytes(),
+                        &mut okm,
+                    )
+                    .expect(
+                        "32 bytes is a valid salt and output length of Argon2",
+                    );
+                Hex32Bytes::from_bytes(&okm)
+            }
+        }
+    }
+}
+
+impl TryFrom<u32> for SecurityQuestionsKeyDerivationScheme {
+    type Error = CommonError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Version1),
+            _ => Err(CommonError::UnsupportedKeyDerivationScheme(value)),
+        }
+    }
+}
+
+impl Serialize for SecurityQuestionsKeyDerivationScheme {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer
+            .serialize_struct("SecurityQuestionsKeyDerivationScheme", 2)?;
+        state.serialize_field("version", &self.version())?;
+        state.serialize_field("description", &self.description())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SecurityQuestionsKeyDerivationScheme {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Versioned {
+            version: u32,
+        }
+        Versioned::deserialize(deserializer)
+            .and_then(|v| Self::try_from(v.version).map_err(de::Error::custom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn try_from_version() {
+        assert_eq!(
+            SecurityQuestionsKeyDerivationScheme::try_from(1),
+            Ok(SecurityQuestionsKeyDerivationScheme::Version1)
+        );
+        assert_eq!(
+            SecurityQuestionsKeyDerivationScheme::try_from(2),
+            Err(CommonError::UnsupportedKeyDerivationScheme(2))
+        );
+    }
+
+    #[test]
+    fn normalize_answer() {
+        let sut = SecurityQuestionsKeyDerivationScheme::Version1;
+        assert_eq!(sut.normalize_answer(" Mrs. Andersson! "), "mrsandersson");
+        assert_eq!(sut.normalize_answer("Volvo 240"), "volvo240");
+    }
+
+    #[test]
+    fn kdf_ignores_order_and_normalizes() {
+        let sut = SecurityQuestionsKeyDerivationScheme::Version1;
+        let a = SecurityQuestionAndAnswer::placeholder();
+        let b = SecurityQuestionAndAnswer::placeholder_other();
+        let b_sloppy =
+            SecurityQuestionAndAnswer::new(b.question.clone(), " FIDO.");
+        let salt = Hex32Bytes::placeholder();
+        assert_eq!(
+            sut.kdf(&[a.clone(), b.clone()], &salt),
+            sut.kdf(&[b_sloppy, a.clone()], &salt)
+        );
+    }
+
+    #[test]
+    fn kdf_wrong_answer() {
+        let sut = SecurityQuestionsKeyDerivationScheme::Version1;
+        let a = SecurityQuestionAndAnswer::placeholder();
+        let wrong = SecurityQuestionAndAnswer::new(a.question.clone(), "Saab");
+        let salt = Hex32Bytes::placeholder();
+        assert_ne!(sut.kdf(&[a], &salt), sut.kdf(&[wrong], &salt));
+    }
+
+    #[test]
+    fn kdf_different_salts() {
+        let sut = SecurityQuestionsKeyDerivationScheme::Version1;
+        let answers = SecurityQuestionAndAnswer::placeholders();
+        assert_ne!(
+            sut.kdf(&answers, &Hex32Bytes::placeholder()),
+            sut.kdf(&answers, &Hex32Bytes::placeholder_other())
+        );
+    }
+
+    #[test]
+    fn kdf_is_deterministic() {
+        let sut = SecurityQuestionsKeyDerivationScheme::Version1;
+        let answers = SecurityQuestionAndAnswer::placeholders();
+        let salt = Hex32Bytes::placeholder();
+        assert_eq!(sut.kdf(&answers, &salt), sut.kdf(&answers, &salt));
+    }
+
+    #[test]
+    fn json() {
+        let sut = SecurityQuestionsKeyDerivationScheme::Version1;
+        assert_eq!(
+            serde_json::to_value(sut).unwrap(),
+            json!({
+                "version": 1,
+                "description": "Argon2id-m19456-t2-p1-with-UTF8-encoding-of-normalized-answers-salted"
+            })
+        );
+        assert_json_roundtrip(&sut);
+    }
+}
@@ -0,0 +1,366 @@
+use crate::prelude::*;
+
+use std::sync::OnceLock;
+
+/// A mnemonic encrypted by answers to security questions, such that it can
+/// be decrypted even if some of the answers are wrong.
+///
+/// The mnemonic is encrypted once for each combination of
+/// `MIN_CORRECT_ANSWER_COUNT` out of the `QUESTION_COUNT` answers, each time
+/// with a key derived from the answers of that combination and a random salt
+/// unique to this sealed mnemonic. Decryption
+/// succeeds if any combination of the given answers derives one of those
+/// keys, i.e. if at least `MIN_CORRECT_ANSWER_COUNT` answers are correct.
+#[serde_as]
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityQuestionsSealedMnemonic {
+    /// The questions whose answers seal the mnemonic, ordered by id.
+    pub security_questions: Vec<SecurityQuestion>,
+
+    /// The scheme used to derive the encryption keys from the answers.
+    pub key_derivation_scheme: SecurityQuestionsKeyDerivationScheme,
+
+    /// The random salt used to derive the encryption keys from the answers.
+    pub kdf_salt: Hex32Bytes,
+
+    /// The scheme used to encrypt the mnemonic.
+    pub encryption_scheme: EncryptionScheme,
+
+    /// The mnemonic encrypted with the key of each combination of answers.
+    #[serde_as(as = "Vec<serde_with::hex::Hex>")]
+    pub encryptions: Vec<Vec<u8>>,
+}
+
+impl SecurityQuestionsSealedMnemonic {
+    /// The number of questions the user must answer to seal a mnemonic.
+    pub const QUESTION_COUNT: usize = 6;
+
+    /// The number of answers which must be correct to decrypt the mnemonic.
+    pub const MIN_CORRECT_ANSWER_COUNT: usize = 4;
+
+    /// Seals `mnemonic` by encrypting it with keys derived from the answers
+    /// in `questions_and_answers`, using the default key derivation and
+    /// encryption schemes.
+    ///
+    /// Returns `Err` if there are not exactly `QUESTION_COUNT` questions,
+    /// if any question is answered more than once or if any answer is empty
+    /// after normalization.
+    pub fn new_by_encrypting(
+        mnemonic: Mnemonic,
+        questions_and_answers: Vec<SecurityQuestionAndAnswer>,
+    ) -> Result<Self> {
+        let key_derivation_scheme =
+            SecurityQuestionsKeyDerivationScheme::default();
+        let encryption_scheme = EncryptionScheme::default();
+        let kdf_salt = Hex32Bytes::generate();
+
+        if questions_and_answers.len() != Self::QUESTION_COUNT {
+            return Err(CommonError::InvalidSecurityQuestionsCount {
+                expected: Self::QUESTION_COUNT as u32,
+                found: questions_and_answers.len() as u32,
+            });
+        }
+        if let Some(duplicate) = questions_and_answers
+            .iter()
+            .map(|qa| qa.question.id)
+            .duplicates()
+            .next()
+        {
+            return Err(CommonError::DuplicateSecurityQuestion(duplicate));
+        }
+        if let Some(empty) = questions_and_answers.iter().find(|qa| {
+            key_derivation_scheme
+                .normalize_answer(&qa.answer)
+                .is_empty()
+        }) {
+            return Err(CommonError::EmptySecurityQuestionAnswer(
+                empty.question.id,
+            ));
+        }
+
+        let questions_and_answers = questions_and_answers
+            .into_iter()
+            .sorted_by_key(|qa| qa.question.id)
+            .collect_vec();
+        let plaintext = mnemonic.phrase();
+        let encryptions = questions_and_answers
+            .iter()
+            .cloned()
+            .combinations(Self::MIN_CORRECT_ANSWER_COUNT)
+            .map(|combination| {
+                encryption_scheme.encrypt(
+                    plaintext.as_bytes(),
+                    &key_derivation_scheme.kdf(&combination, &kdf_salt),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            security_questions: questions_and_answers
+                .into_iter()
+                .map(|qa| qa.question)
+                .collect(),
+            key_derivation_scheme,
+            kdf_salt,
+            encryption_scheme,
+            encryptions,
+        })
+    }
+
+    /// Decrypts the sealed mnemonic using `answers`, answers to questions
+    /// not part of `security_questions` are ignored.
+    ///
+    /// Returns `Err` if fewer than `MIN_CORRECT_ANSWER_COUNT` questions are
+    /// answered or if fewer than `MIN_CORRECT_ANSWER_COUNT` answers are
+    /// correct.
+    pub fn decrypt(
+        &self,
+        answers: Vec<SecurityQuestionAndAnswer>,
+    ) -> Result<Mnemonic> {
+        let answers = answers
+            .into_iter()
+            .filter(|qa| self.security_questions.contains(&qa.question))
+            .unique_by(|qa| qa.question.id)
+            .sorted_by_key(|qa| qa.question.id)
+            .collect_vec();
+        if answers.len() < Self::MIN_CORRECT_ANSWER_COUNT {
+            return Err(CommonError::InvalidSecurityQuestionsCount {
+                expected: Self::MIN_CORRECT_ANSWER_COUNT as u32,
+                found: answers.len() as u32,
+            });
+        }
+
+        answers
+            .into_iter()
+            .combinations(Self::MIN_CORRECT_ANSWER_COUNT)
+            .map(|combination| {
+                self.key_derivation_scheme.kdf(&combination, &self.kdf_salt)
+            })
+            .find_map(|key| {
+                self.encryptions.iter().find_map(|encryption| {
+                    self.encryption_scheme.decrypt(encryption, &key).ok()
+                })
+            })
+            .and_then(|plaintext| String::from_utf8(plaintext).ok())
+            .and_then(|phrase| Mnemonic::from_phrase(&phrase).ok())
+            .ok_or(CommonError::InvalidSecurityQuestionsAnswers)
+    }
+}
+
+impl HasPlaceholder for SecurityQuestionsSealedMnemonic {
+    /// A placeholder used to facilitate unit tests, `Mnemonic::placeholder`
+    /// sealed by `SecurityQuestionAndAnswer::placeholders`.
+    fn placeholder() -> Self {
+        // Sealing uses a random salt and nonce, so we seal the mnemonic once.
+        static PLACEHOLDER: OnceLock<SecurityQuestionsSealedMnemonic> =
+            OnceLock::new();
+        PLACEHOLDER
+            .get_or_init(|| {
+                Self::new_by_encrypting(
+                    Mnemonic::placeholder(),
+                    SecurityQuestionAndAnswer::placeholders(),
+                )
+                .expect("Valid placeholder")
+            })
+            .clone()
+    }
+
+    /// A placeholder used to facilitate unit tests,
+    /// `Mnemonic::placeholder_other` sealed by
+    /// `SecurityQuestionAndAnswer::placeholders`.
+    fn placeholder_other() -> Self {
+        // Sealing uses a random salt and nonce, so we seal the mnemonic once.
+        static PLACEHOLDER: OnceLock<SecurityQuestionsSealedMnemonic> =
+            OnceLock::new();
+        PLACEHOLDER
+            .get_or_init(|| {
+                Self::new_by_encrypting(
+                    Mnemonic::placeholder_other(),
+                    SecurityQuestionAndAnswer::placeholders(),
+                )
+                .expect("Valid placeholder")
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    type SUT = SecurityQuestionsSealedMnemonic;
+
+    fn with_wrong_answers(indices: &[usize]) -> Vec<SecurityQuestionAndAnswer> {
+        SecurityQuestionAndAnswer::placeholders()
+            .into_iter()
+            .enumerate()
+            .map(|(i, qa)| {
+                if indices.contains(&i) {
+                    SecurityQuestionAndAnswer::new(qa.question, "wrong")
+                } else {
+                    qa
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::placeholder(), SUT::placeholder());
+        assert_eq!(SUT::placeholder_other(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::placeholder(), SUT::placeholder_other());
+    }
+
+    #[test]
+    fn kdf_salt_is_random() {
+        let seal = || {
+            SUT::new_by_encrypting(
+                Mnemonic::placeholder(),
+                SecurityQuestionAndAnswer::placeholders(),
+            )
+            .unwrap()
+        };
+        assert_ne!(seal().kdf_salt, seal().kdf_salt);
+    }
+
+    #[test]
+    fn decrypt_with_other_kdf_salt_fails() {
+        let mut sut = SUT::placeholder();
+        sut.kdf_salt = Hex32Bytes::placeholder_other();
+        assert_eq!(
+            sut.decrypt(SecurityQuestionAndAnswer::placeholders()),
+            Err(CommonError::InvalidSecurityQuestionsAnswers)
+        );
+    }
+
+    #[test]
+    fn one_encryption_per_combination() {
+        // 6 choose 4
+        assert_eq!(SUT::placeholder().encryptions.len(), 15);
+    }
+
+    #[test]
+    fn decrypt_all_answers_correct() {
+        assert_eq!(
+            SUT::placeholder()
+                .decrypt(SecurityQuestionAndAnswer::placeholders()),
+            Ok(Mnemonic::placeholder())
+        );
+    }
+
+    #[test]
+    fn decrypt_two_answers_wrong() {
+        assert_eq!(
+            SUT::placeholder().decrypt(with_wrong_answers(&[1, 4])),
+            Ok(Mnemonic::placeholder())
+        );
+    }
+
+    #[test]
+    fn decrypt_only_four_answers_given() {
+        let answers = SecurityQuestionAndAnswer::placeholders()
+            .into_iter()
+            .skip(2)
+            .collect_vec();
+        assert_eq!(
+            SUT::placeholder().decrypt(answers),
+            Ok(Mnemonic::placeholder())
+        );
+    }
+
+    #[test]
+    fn decrypt_answers_are_normalized() {
+        let answers = SecurityQuestionAndAnswer::placeholders()
+            .into_iter()
+            .map(|qa| {
+                let sloppy = format!(" {}. ", qa.answer.to_uppercase());
+                SecurityQuestionAndAnswer::new(qa.question, &sloppy)
+            })
+            .collect_vec();
+        assert_eq!(
+            SUT::placeholder().decrypt(answers),
+            Ok(Mnemonic::placeholder())
+        );
+    }
+
+    #[test]
+    fn decrypt_three_answers_wrong() {
+        assert_eq!(
+            SUT::placeholder().decrypt(with_wrong_answers(&[0, 2, 5])),
+            Err(CommonError::InvalidSecurityQuestionsAnswers)
+        );
+    }
+
+    #[test]
+    fn decrypt_too_few_answers() {
+        let answers = SecurityQuestionAndAnswer::placeholders()
+            .into_iter()
+            .take(3)
+            .collect_vec();
+        assert_eq!(
+            SUT::placeholder().decrypt(answers),
+            Err(CommonError::InvalidSecurityQuestionsCount {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn new_wrong_question_count() {
+        let questions_and_answers = SecurityQuestionAndAnswer::placeholders()
+            .into_iter()
+            .take(5)
+            .collect_vec();
+        assert_eq!(
+            SUT::new_by_encrypting(
+                Mnemonic::placeholder(),
+                questions_and_answers
+            ),
+            Err(CommonError::InvalidSecurityQuestionsCount {
+                expected: 6,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn new_duplicate_question() {
+        let mut questions_and_answers =
+            SecurityQuestionAndAnswer::placeholders();
+        questions_and_answers[5] = SecurityQuestionAndAnswer::placeholder();
+        assert_eq!(
+            SUT::new_by_encrypting(
+                Mnemonic::placeholder(),
+                questions_and_answers
+            ),
+            Err(CommonError::DuplicateSecurityQuestion(0))
+        );
+    }
+
+    #[test]
+    fn new_empty_answer() {
+        let mut questions_and_answers =
+            SecurityQuestionAndAnswer::placeholders();
+        questions_and_answers[1].answer = " ?! ".to_string();
+        assert_eq!(
+            SUT::new_by_encrypting(
+                Mnemonic::placeholder(),
+                questions_and_answers
+            ),
+            Err(CommonError::EmptySecurityQuestionAnswer(1))
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&SUT::placeholder());
+    }
+}
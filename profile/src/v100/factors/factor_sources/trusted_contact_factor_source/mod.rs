@@ -0,0 +1,5 @@
+mod trusted_contact_factor_source;
+mod trusted_contact_factor_source_contact;
+
+pub use trusted_contact_factor_source::*;
+pub use trusted_contact_factor_source_contact::*;
@@ -0,0 +1,226 @@
+use crate::prelude::*;
+
+/// A factor source representing a person, company, organization or otherwise
+/// third party the user trusts enough to help them recover their entities,
+/// identified by an account address of the contact.
+///
+/// Can only be used in the recovery role of a security structure.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("{contact} {id}")]
+pub struct TrustedContactFactorSource {
+    /// Unique and stable identifier of this factor source, stemming from the
+    /// account address of the contact.
+    pub id: FactorSourceIDFromAddress,
+
+    /// Common properties shared between FactorSources of different kinds,
+    /// describing its state, when added, and supported cryptographic parameters.
+    ///
+    /// Has interior mutability since we must be able to update the
+    /// last used date.
+    pub common: FactorSourceCommon,
+
+    /// The contact information about the contact that is 'trusted'.
+    pub contact: TrustedContactFactorSourceContact,
+}
+
+impl TryFrom<FactorSource> for TrustedContactFactorSource {
+    type Error = CommonError;
+
+    fn try_from(value: FactorSource) -> Result<Self> {
+        value.into_trusted_contact().map_err(|_| {
+            Self::Error::ExpectedTrustedContactFactorSourceGotSomethingElse
+        })
+    }
+}
+impl IsFactorSource for TrustedContactFactorSource {
+    fn kind() -> FactorSourceKind {
+        FactorSourceKind::TrustedContact
+    }
+}
+impl BaseIsFactorSource for TrustedContactFactorSource {
+    fn factor_source_kind(&self) -> FactorSourceKind {
+        self.id.kind
+    }
+
+    fn factor_source_id(&self) -> FactorSourceID {
+        self.clone().id.into()
+    }
+}
+
+impl TrustedContactFactorSource {
+    /// Instantiates a new `TrustedContactFactorSource`
+    pub fn new(
+        id: FactorSourceIDFromAddress,
+        common: FactorSourceCommon,
+        contact: TrustedContactFactorSourceContact,
+    ) -> Self {
+        Self {
+            id,
+            common,
+            contact,
+        }
+    }
+
+    /// Instantiates a new `TrustedContactFactorSource` for the contact
+    /// controlling the account with `account_address`.
+    pub fn from_address_and_contact(
+        account_address: AccountAddress,
+        contact: TrustedContactFactorSourceContact,
+    ) -> Self {
+        Self::new(
+            FactorSourceIDFromAddress::new(Self::kind(), account_address),
+            FactorSourceCommon::new(
+                FactorSourceCryptoParameters::trusted_entity(),
+                [],
+            ),
+            contact,
+        )
+    }
+}
+
+impl HasPlaceholder for TrustedContactFactorSource {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        let date = Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap();
+        Self::new(
+            FactorSourceIDFromAddress::placeholder(),
+            FactorSourceCommon::with_values(
+                FactorSourceCryptoParameters::trusted_entity(),
+                date,
+                date,
+                [],
+            ),
+            TrustedContactFactorSourceContact::placeholder(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        let date = Timestamp::parse("2023-12-24T17:13:56.123Z").unwrap();
+        Self::new(
+            FactorSourceIDFromAddress::placeholder_other(),
+            FactorSourceCommon::with_values(
+                FactorSourceCryptoParameters::trusted_entity(),
+                date,
+                date,
+                [],
+            ),
+            TrustedContactFactorSourceContact::placeholder_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            TrustedContactFactorSource::placeholder(),
+            TrustedContactFactorSource::placeholder()
+        );
+        assert_eq!(
+            TrustedContactFactorSource::placeholder_other(),
+            TrustedContactFactorSource::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            TrustedContactFactorSource::placeholder(),
+            TrustedContactFactorSource::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn from_address_and_contact() {
+        let sut = TrustedContactFactorSource::from_address_and_contact(
+            AccountAddress::placeholder(),
+            TrustedContactFactorSourceContact::placeholder(),
+        );
+        assert_eq!(sut.id, FactorSourceIDFromAddress::placeholder());
+        assert_eq!(
+            sut.common.crypto_parameters,
+            FactorSourceCryptoParameters::trusted_entity()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let model = TrustedContactFactorSource::placeholder();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "id": {
+                    "kind": "trustedContact",
+                    "body": "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease"
+                },
+                "common": {
+                    "addedOn": "2023-09-11T16:05:56.000Z",
+                    "cryptoParameters": {
+                        "supportedCurves": ["curve25519"],
+                        "supportedDerivationPathSchemes": []
+                    },
+                    "flags": [],
+                    "lastUsedOn": "2023-09-11T16:05:56.000Z"
+                },
+                "contact": {
+                    "emailAddress": "spaghetti@dangerous.me",
+                    "name": "Spaghetti Sam"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn factor_source_id() {
+        let sut = TrustedContactFactorSource::placeholder();
+        let factor_source_id: FactorSourceID = sut.clone().id.into();
+        assert_eq!(factor_source_id, sut.factor_source_id());
+    }
+
+    #[test]
+    fn static_kind() {
+        assert_eq!(
+            TrustedContactFactorSource::kind(),
+            FactorSourceKind::TrustedContact
+        );
+    }
+
+    #[test]
+    fn from_factor_source() {
+        let sut = TrustedContactFactorSource::placeholder();
+        let factor_source: FactorSource = sut.clone().into();
+        assert_eq!(
+            TrustedContactFactorSource::try_from(factor_source),
+            Ok(sut)
+        );
+    }
+
+    #[test]
+    fn from_factor_source_invalid_got_device() {
+        let factor_source: FactorSource =
+            DeviceFactorSource::placeholder().into();
+        assert_eq!(
+            TrustedContactFactorSource::try_from(factor_source),
+            Err(
+                CommonError::ExpectedTrustedContactFactorSourceGotSomethingElse
+            )
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::prelude::*;
+
+/// Hints about the trusted contact of a `TrustedContactFactorSource`, used
+/// to help the user identify the contact and to reach out to them.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+#[display("{name} {email_address}")]
+pub struct TrustedContactFactorSourceContact {
+    /// The email address of the contact that the user trusts
+    pub email_address: PersonaDataEntryEmailAddress,
+
+    /// The name of the contact that the user trusts
+    pub name: DisplayName,
+}
+
+impl TrustedContactFactorSourceContact {
+    pub fn new(
+        email_address: PersonaDataEntryEmailAddress,
+        name: DisplayName,
+    ) -> Self {
+        Self {
+            email_address,
+            name,
+        }
+    }
+}
+
+impl HasPlaceholder for TrustedContactFactorSourceContact {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            PersonaDataEntryEmailAddress::new("spaghetti@dangerous.me")
+                .unwrap(),
+            DisplayName::new("Spaghetti Sam").unwrap(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            PersonaDataEntryEmailAddress::new("hello@ur.mom").unwrap(),
+            DisplayName::new("Your Mom").unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            TrustedContactFactorSourceContact::placeholder(),
+            TrustedContactFactorSourceContact::placeholder()
+        );
+        assert_eq!(
+            TrustedContactFactorSourceContact::placeholder_other(),
+            TrustedContactFactorSourceContact::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            TrustedContactFactorSourceContact::placeholder(),
+            TrustedContactFactorSourceContact::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &TrustedContactFactorSourceContact::placeholder(),
+            r#"
+            {
+                "emailAddress": "spaghetti@dangerous.me",
+                "name": "Spaghetti Sam"
+            }
+            "#,
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod address;
 mod app_preferences;
+mod encrypted_profile;
 mod entity;
 mod entity_security_state;
 mod factors;
@@ -9,6 +10,7 @@ mod profile;
 
 pub use address::*;
 pub use app_preferences::*;
+pub use encrypted_profile::*;
 pub use entity::*;
 pub use entity_security_state::*;
 pub use factors::*;
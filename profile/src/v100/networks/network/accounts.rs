@@ -42,6 +42,77 @@ impl Accounts {
     pub fn get_all(&self) -> Vec<&Account> {
         self.elements()
     }
+
+    /// Returns references to the accounts not hidden by the user.
+    pub fn get_visible(&self) -> Vec<&Account> {
+        self.iter().filter(|a| !a.is_hidden()).collect()
+    }
+
+    /// Returns references to the accounts hidden by the user.
+    pub fn get_hidden(&self) -> Vec<&Account> {
+        self.iter().filter(|a| a.is_hidden()).collect()
+    }
+}
+
+impl Accounts {
+    /// Reorders the accounts to follow the order of `addresses`, which must
+    /// be a permutation of the addresses of the accounts.
+    ///
+    /// Returns `Err(CommonError::UnknownAccount)` if any address is unknown
+    /// and `Err(CommonError::InvalidAccountsOrder)` if any account is missing
+    /// or listed more than once, in which case the order is left unchanged.
+    pub fn reorder(&mut self, addresses: &[AccountAddress]) -> Result<()> {
+        if addresses.iter().any(|a| !self.contains_id(a)) {
+            return Err(CommonError::UnknownAccount);
+        }
+        if addresses.len() != self.len()
+            || addresses.iter().unique().count() != addresses.len()
+        {
+            return Err(CommonError::InvalidAccountsOrder);
+        }
+        let reordered = Self::with_accounts(
+            addresses.iter().filter_map(|a| self.get(a)).cloned(),
+        );
+        *self = reordered;
+        Ok(())
+    }
+
+    /// Moves the account with `address` one step towards the start, does
+    /// nothing if it already is the first account.
+    pub fn move_up(&mut self, address: &AccountAddress) -> Result<()> {
+        self.move_by_one(address, true)
+    }
+
+    /// Moves the account with `address` one step towards the end, does
+    /// nothing if it already is the last account.
+    pub fn move_down(&mut self, address: &AccountAddress) -> Result<()> {
+        self.move_by_one(address, false)
+    }
+
+    fn move_by_one(
+        &mut self,
+        address: &AccountAddress,
+        up: bool,
+    ) -> Result<()> {
+        let mut addresses =
+            self.iter().map(|a| a.address.clone()).collect_vec();
+        let index = addresses
+            .iter()
+            .position(|a| a == address)
+            .ok_or(CommonError::UnknownAccount)?;
+        let other = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|i| *i < addresses.len())
+        };
+        match other {
+            Some(other) => {
+                addresses.swap(index, other);
+                self.reorder(&addresses)
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 impl HasPlaceholder for Accounts {
@@ -82,6 +153,112 @@ mod tests {
         assert_eq!(Accounts::default().len(), 0);
     }
 
+    #[test]
+    fn visible_and_hidden() {
+        let mut sut = Accounts::placeholder_mainnet();
+        let bob = Account::placeholder_mainnet_bob();
+        sut.update_with(&bob.address, |a| {
+            _ = a.flags.insert_flag(EntityFlag::DeletedByUser)
+        });
+        assert_eq!(sut.get_all().len(), 2);
+        assert_eq!(
+            sut.get_visible(),
+            vec![&Account::placeholder_mainnet_alice()]
+        );
+        assert_eq!(
+            sut.get_hidden()
+                .into_iter()
+                .map(|a| a.address.clone())
+                .collect_vec(),
+            vec![bob.address]
+        );
+    }
+
+    fn addresses(accounts: &Accounts) -> Vec<AccountAddress> {
+        accounts.iter().map(|a| a.address.clone()).collect()
+    }
+
+    #[test]
+    fn reorder() {
+        let mut sut = Accounts::placeholder_mainnet();
+        let alice = Account::placeholder_mainnet_alice();
+        let bob = Account::placeholder_mainnet_bob();
+        sut.reorder(&[bob.address.clone(), alice.address.clone()])
+            .unwrap();
+        assert_eq!(sut, Accounts::with_accounts([bob, alice]));
+    }
+
+    #[test]
+    fn reorder_unknown_address() {
+        let mut sut = Accounts::placeholder_mainnet();
+        assert_eq!(
+            sut.reorder(&[
+                Account::placeholder_mainnet_carol().address,
+                Account::placeholder_mainnet_alice().address,
+            ]),
+            Err(CommonError::UnknownAccount)
+        );
+        assert_eq!(sut, Accounts::placeholder_mainnet());
+    }
+
+    #[test]
+    fn reorder_not_a_permutation() {
+        let mut sut = Accounts::placeholder_mainnet();
+        let alice = Account::placeholder_mainnet_alice().address;
+        assert_eq!(
+            sut.reorder(&[alice.clone()]),
+            Err(CommonError::InvalidAccountsOrder)
+        );
+        assert_eq!(
+            sut.reorder(&[alice.clone(), alice]),
+            Err(CommonError::InvalidAccountsOrder)
+        );
+        assert_eq!(sut, Accounts::placeholder_mainnet());
+    }
+
+    #[test]
+    fn move_up_and_down() {
+        let mut sut = Accounts::placeholder_mainnet();
+        let alice = Account::placeholder_mainnet_alice().address;
+        let bob = Account::placeholder_mainnet_bob().address;
+
+        sut.move_up(&alice).unwrap();
+        assert_eq!(addresses(&sut), vec![alice.clone(), bob.clone()]);
+
+        sut.move_up(&bob).unwrap();
+        assert_eq!(addresses(&sut), vec![bob.clone(), alice.clone()]);
+
+        sut.move_down(&alice).unwrap();
+        assert_eq!(addresses(&sut), vec![bob.clone(), alice.clone()]);
+
+        sut.move_down(&bob).unwrap();
+        assert_eq!(addresses(&sut), vec![alice, bob]);
+    }
+
+    #[test]
+    fn move_unknown_account() {
+        let mut sut = Accounts::placeholder_mainnet();
+        let carol = Account::placeholder_mainnet_carol().address;
+        assert_eq!(sut.move_up(&carol), Err(CommonError::UnknownAccount));
+        assert_eq!(sut.move_down(&carol), Err(CommonError::UnknownAccount));
+    }
+
+    #[test]
+    fn reordered_json_roundtrip_preserves_order() {
+        let mut sut = Accounts::placeholder_mainnet();
+        sut.move_down(&Account::placeholder_mainnet_alice().address)
+            .unwrap();
+        let json = serde_json::to_value(&sut).unwrap();
+        let deserialized: Accounts = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            addresses(&deserialized),
+            vec![
+                Account::placeholder_mainnet_bob().address,
+                Account::placeholder_mainnet_alice().address
+            ]
+        );
+    }
+
     #[test]
     fn inequality() {
         assert_ne!(Accounts::placeholder(), Accounts::placeholder_other());
@@ -0,0 +1,243 @@
+use crate::prelude::*;
+
+/// An ordered set of references to Personas the user has used to login to
+/// a certain dApp with.
+pub type ReferencesToAuthorizedPersonas =
+    IdentifiedVecVia<AuthorizedPersonaSimple>;
+
+/// A connection made between a Radix Dapp and the user, which the user
+/// has authorized, using one or many of their Personas.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedDapp {
+    /// The ID of the network the authorized Dapp is on.
+    #[serde(rename = "networkID")]
+    pub network_id: NetworkID,
+
+    /// The address of the dApp definition, the globally unique identifier of
+    /// the dApp.
+    #[serde(rename = "dAppDefinitionAddress")]
+    pub dapp_definition_address: AccountAddress,
+
+    /// The Display name as sent by the Dapp in any interaction request (CAP21),
+    /// e.g. "Radix Dashboard".
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub display_name: Option<String>,
+
+    /// An order set of `AuthorizedPersonaSimple`s, which is a collection of all
+    /// the Personas the user has used to interact with this Dapp, it is called
+    /// "references to", since the Personas are not stored in full, that would be
+    /// bad duplication of data (which might go stale), instead we refer to the
+    /// necessary data by IDs.
+    pub references_to_authorized_personas: ReferencesToAuthorizedPersonas,
+}
+
+impl Identifiable for AuthorizedDapp {
+    type ID = AccountAddress;
+
+    fn id(&self) -> Self::ID {
+        self.dapp_definition_address.clone()
+    }
+}
+
+impl AuthorizedDapp {
+    /// Instantiates a new `AuthorizedDapp`.
+    ///
+    /// Panics if `dapp_definition_address` is not on `network_id`.
+    pub fn new(
+        network_id: NetworkID,
+        dapp_definition_address: AccountAddress,
+        display_name: Option<String>,
+        references_to_authorized_personas: ReferencesToAuthorizedPersonas,
+    ) -> Self {
+        assert_eq!(
+            dapp_definition_address.network_id, network_id,
+            "Discrepancy, found dApp definition address on other network than {network_id}"
+        );
+        Self {
+            network_id,
+            dapp_definition_address,
+            display_name,
+            references_to_authorized_personas,
+        }
+    }
+
+    /// Removes the persona with `address` from the references to authorized
+    /// personas, returns `true` if it was present.
+    pub fn remove_references_to_persona(
+        &mut self,
+        address: &IdentityAddress,
+    ) -> bool {
+        self.references_to_authorized_personas
+            .remove_by_id(address)
+            .is_some()
+    }
+
+    /// Removes the account with `address` from the shared accounts of **all**
+    /// referenced personas, returns `true` if any reference was removed.
+    ///
+    /// Shared accounts no longer fulfilling the quantity requested by the
+    /// dApp are removed altogether, see `remove_shared_account`.
+    pub fn remove_references_to_account(
+        &mut self,
+        address: &AccountAddress,
+    ) -> bool {
+        let referencing = self
+            .references_to_authorized_personas
+            .iter()
+            .filter(|p| p.is_sharing_account(address))
+            .map(|p| p.id())
+            .collect_vec();
+
+        referencing.iter().for_each(|id| {
+            _ = self
+                .references_to_authorized_personas
+                .update_with(id, |p| _ = p.remove_shared_account(address))
+        });
+
+        !referencing.is_empty()
+    }
+}
+
+impl HasPlaceholder for AuthorizedDapp {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::placeholder_mainnet_dashboard()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::placeholder_mainnet_gumballclub()
+    }
+}
+
+impl AuthorizedDapp {
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_mainnet_dashboard() -> Self {
+        Self::new(
+            NetworkID::Mainnet,
+            AccountAddress::placeholder_alice(),
+            Some("Radix Dashboard".to_string()),
+            ReferencesToAuthorizedPersonas::from_iter([
+                AuthorizedPersonaSimple::placeholder_satoshi(),
+                AuthorizedPersonaSimple::placeholder_batman(),
+            ]),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_mainnet_gumballclub() -> Self {
+        Self::new(
+            NetworkID::Mainnet,
+            AccountAddress::placeholder_bob(),
+            Some("Gumball Club".to_string()),
+            ReferencesToAuthorizedPersonas::from_iter([
+                AuthorizedPersonaSimple::placeholder_batman(),
+            ]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            AuthorizedDapp::placeholder(),
+            AuthorizedDapp::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            AuthorizedDapp::placeholder(),
+            AuthorizedDapp::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn identifiable() {
+        assert_eq!(
+            AuthorizedDapp::placeholder().id(),
+            AccountAddress::placeholder_alice()
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Discrepancy, found dApp definition address on other network than stokenet"
+    )]
+    fn panics_when_network_id_mismatch() {
+        _ = AuthorizedDapp::new(
+            NetworkID::Stokenet,
+            AccountAddress::placeholder_alice(),
+            None,
+            ReferencesToAuthorizedPersonas::new(),
+        );
+    }
+
+    #[test]
+    fn remove_references_to_persona() {
+        let mut sut = AuthorizedDapp::placeholder_mainnet_dashboard();
+        let address = Persona::placeholder_satoshi().address;
+        assert!(sut.remove_references_to_persona(&address));
+        assert!(!sut.remove_references_to_persona(&address));
+        assert_eq!(
+            sut.references_to_authorized_personas.items(),
+            [AuthorizedPersonaSimple::placeholder_batman()]
+        );
+    }
+
+    #[test]
+    fn remove_references_to_account() {
+        let mut sut = AuthorizedDapp::placeholder_mainnet_dashboard();
+        let bob = Account::placeholder_mainnet_bob().address;
+        assert!(sut.remove_references_to_account(&bob));
+        assert!(!sut.remove_references_to_account(&bob));
+        assert!(sut
+            .references_to_authorized_personas
+            .iter()
+            .all(|p| !p.is_sharing_account(&bob)));
+        // Satoshi shared `exactly(2)` and Batman `at_least(1)` accounts,
+        // neither is fulfilled without Bob.
+        assert!(sut
+            .references_to_authorized_personas
+            .iter()
+            .all(|p| p.shared_accounts.is_none()));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &AuthorizedDapp::placeholder_mainnet_gumballclub(),
+            r#"
+            {
+                "networkID": 1,
+                "dAppDefinitionAddress": "account_rdx16yf8jxxpdtcf4afpj5ddeuazp2evep7quuhgtq28vjznee08master",
+                "displayName": "Gumball Club",
+                "referencesToAuthorizedPersonas": [
+                    {
+                        "identityAddress": "identity_rdx12gcd4r799jpvztlffgw483pqcen98pjnay988n8rmscdswd872xy62",
+                        "lastLogin": "2023-12-24T17:13:56.123Z",
+                        "sharedAccounts": {
+                            "request": {
+                                "quantifier": "atLeast",
+                                "quantity": 1
+                            },
+                            "ids": [
+                                "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69"
+                            ]
+                        },
+                        "sharedPersonaData": {}
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::prelude::*;
+
+/// An ordered set of AuthorizedDapps on a specific network, identified by
+/// their dApp definition address.
+pub type AuthorizedDapps = IdentifiedVecVia<AuthorizedDapp>;
+
+impl AuthorizedDapps {
+    /// Instantiates a new collection of authorized dApps from
+    /// and iterator of authorized dApps.
+    pub fn with_authorized_dapps<I>(authorized_dapps: I) -> Self
+    where
+        I: IntoIterator<Item = AuthorizedDapp>,
+    {
+        Self::from_iter(authorized_dapps)
+    }
+
+    /// Instantiates a new collection of authorized dApps from a
+    /// single authorized dApp.
+    pub fn with_authorized_dapp(authorized_dapp: AuthorizedDapp) -> Self {
+        Self::with_authorized_dapps([authorized_dapp])
+    }
+}
+
+// Trait: Default
+impl Default for AuthorizedDapps {
+    /// Instantiates a new empty authorized dApps collection.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthorizedDapps {
+    /// Returns a reference to the authorized dApp identified by
+    /// `dapp_definition_address`, if it exists.
+    pub fn get_authorized_dapp_by_address(
+        &self,
+        dapp_definition_address: &AccountAddress,
+    ) -> Option<&AuthorizedDapp> {
+        self.get(dapp_definition_address)
+    }
+
+    /// Returns references to **all** authorized dApps.
+    pub fn get_all(&self) -> Vec<&AuthorizedDapp> {
+        self.elements()
+    }
+}
+
+impl HasPlaceholder for AuthorizedDapps {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::placeholder_mainnet()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::with_authorized_dapp(
+            AuthorizedDapp::placeholder_mainnet_gumballclub(),
+        )
+    }
+}
+
+impl AuthorizedDapps {
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_mainnet() -> Self {
+        Self::with_authorized_dapps([
+            AuthorizedDapp::placeholder_mainnet_dashboard(),
+            AuthorizedDapp::placeholder_mainnet_gumballclub(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn default_is_empty() {
+        assert_eq!(AuthorizedDapps::default().len(), 0);
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            AuthorizedDapps::placeholder(),
+            AuthorizedDapps::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn duplicates_are_prevented() {
+        assert_eq!(
+            AuthorizedDapps::with_authorized_dapps([
+                AuthorizedDapp::placeholder(),
+                AuthorizedDapp::placeholder()
+            ])
+            .len(),
+            1
+        )
+    }
+
+    #[test]
+    fn get_by_address() {
+        let sut = AuthorizedDapps::placeholder();
+        let dapp = AuthorizedDapp::placeholder_mainnet_gumballclub();
+        assert_eq!(
+            sut.get_authorized_dapp_by_address(&dapp.dapp_definition_address),
+            Some(&dapp)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&AuthorizedDapps::placeholder());
+    }
+}
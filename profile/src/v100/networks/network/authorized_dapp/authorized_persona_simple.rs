@@ -0,0 +1,235 @@
+use crate::prelude::*;
+
+/// Simple data representation of a Persona the user has shared with a dApp.
+/// Simple meaning "the bare minimum amount of data" that enabled `Wallet` to reconstruct
+/// what accounts and persona data was shared, by looking it up in the Profile.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedPersonaSimple {
+    /// The globally unique identifier of a Persona is its address, used
+    /// to lookup persona
+    pub identity_address: IdentityAddress,
+
+    /// Date of last login for this persona.
+    pub last_login: Timestamp,
+
+    /// List of "ongoing accountAddresses" that user given the dApp access to.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub shared_accounts: Option<SharedAccounts>,
+
+    /// ID to PersonaData entries to user has shared with a dApp.
+    #[serde(default)]
+    pub shared_persona_data: SharedPersonaData,
+}
+
+impl Identifiable for AuthorizedPersonaSimple {
+    type ID = IdentityAddress;
+
+    fn id(&self) -> Self::ID {
+        self.identity_address.clone()
+    }
+}
+
+impl AuthorizedPersonaSimple {
+    /// Instantiates a new `AuthorizedPersonaSimple`.
+    pub fn new(
+        identity_address: IdentityAddress,
+        last_login: Timestamp,
+        shared_accounts: Option<SharedAccounts>,
+        shared_persona_data: SharedPersonaData,
+    ) -> Self {
+        Self {
+            identity_address,
+            last_login,
+            shared_accounts,
+            shared_persona_data,
+        }
+    }
+
+    /// Returns `true` if the account with `address` is shared with the dApp
+    /// using this persona.
+    pub fn is_sharing_account(&self, address: &AccountAddress) -> bool {
+        self.shared_accounts
+            .as_ref()
+            .map(|s| s.ids.contains(address))
+            .unwrap_or(false)
+    }
+
+    /// Removes the account with `address` from the shared accounts, returns
+    /// `true` if it was shared. If the remaining accounts no longer fulfill
+    /// the quantity requested by the dApp, e.g. one account left of
+    /// `exactly(2)`, the shared accounts are removed altogether, so that the
+    /// dApp has to request accounts again.
+    pub fn remove_shared_account(&mut self, address: &AccountAddress) -> bool {
+        let Some(shared_accounts) = self.shared_accounts.as_mut() else {
+            return false;
+        };
+        if !shared_accounts.remove(address) {
+            return false;
+        }
+        if !shared_accounts
+            .request
+            .is_fulfilled_by_ids(shared_accounts.ids.len())
+        {
+            self.shared_accounts = None;
+        }
+        true
+    }
+}
+
+impl HasPlaceholder for AuthorizedPersonaSimple {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::placeholder_satoshi()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::placeholder_batman()
+    }
+}
+
+impl AuthorizedPersonaSimple {
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_satoshi() -> Self {
+        Self::new(
+            Persona::placeholder_satoshi().address,
+            Timestamp::parse("2023-09-11T16:05:56.000Z").unwrap(),
+            Some(SharedAccounts::new(
+                RequestedQuantity::exactly(2),
+                [
+                    Account::placeholder_mainnet_alice().address,
+                    Account::placeholder_mainnet_bob().address,
+                ],
+            )),
+            SharedPersonaData::default(),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_batman() -> Self {
+        Self::new(
+            Persona::placeholder_batman().address,
+            Timestamp::parse("2023-12-24T17:13:56.123Z").unwrap(),
+            Some(SharedAccounts::new(
+                RequestedQuantity::at_least(1),
+                [Account::placeholder_mainnet_bob().address],
+            )),
+            SharedPersonaData::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            AuthorizedPersonaSimple::placeholder(),
+            AuthorizedPersonaSimple::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            AuthorizedPersonaSimple::placeholder(),
+            AuthorizedPersonaSimple::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn remove_shared_account_exactly_no_longer_fulfilled() {
+        let mut sut = AuthorizedPersonaSimple::placeholder_satoshi();
+        assert_eq!(
+            sut.shared_accounts.as_ref().unwrap().request,
+            RequestedQuantity::exactly(2)
+        );
+        assert!(sut.remove_shared_account(
+            &Account::placeholder_mainnet_bob().address
+        ));
+        assert_eq!(sut.shared_accounts, None);
+    }
+
+    #[test]
+    fn remove_shared_account_at_least_still_fulfilled() {
+        let alice = Account::placeholder_mainnet_alice().address;
+        let bob = Account::placeholder_mainnet_bob().address;
+        let mut sut = AuthorizedPersonaSimple::placeholder_satoshi();
+        sut.shared_accounts = Some(SharedAccounts::new(
+            RequestedQuantity::at_least(1),
+            [alice.clone(), bob.clone()],
+        ));
+        assert!(sut.remove_shared_account(&bob));
+        assert_eq!(sut.shared_accounts.unwrap().ids, vec![alice]);
+    }
+
+    #[test]
+    fn remove_shared_account_not_shared() {
+        let mut sut = AuthorizedPersonaSimple::placeholder_batman();
+        assert!(!sut.remove_shared_account(
+            &Account::placeholder_mainnet_alice().address
+        ));
+        assert_eq!(sut, AuthorizedPersonaSimple::placeholder_batman());
+    }
+
+    #[test]
+    fn identifiable() {
+        assert_eq!(
+            AuthorizedPersonaSimple::placeholder().id(),
+            Persona::placeholder_satoshi().address
+        );
+    }
+
+    #[test]
+    fn is_sharing_account() {
+        let sut = AuthorizedPersonaSimple::placeholder_batman();
+        assert!(
+            sut.is_sharing_account(&Account::placeholder_mainnet_bob().address)
+        );
+        assert!(!sut
+            .is_sharing_account(&Account::placeholder_mainnet_alice().address));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &AuthorizedPersonaSimple::placeholder_batman(),
+            r#"
+            {
+                "identityAddress": "identity_rdx12gcd4r799jpvztlffgw483pqcen98pjnay988n8rmscdswd872xy62",
+                "lastLogin": "2023-12-24T17:13:56.123Z",
+                "sharedAccounts": {
+                    "request": {
+                        "quantifier": "atLeast",
+                        "quantity": 1
+                    },
+                    "ids": [
+                        "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69"
+                    ]
+                },
+                "sharedPersonaData": {}
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_deserialize_without_shared_accounts() {
+        let sut = serde_json::from_str::<AuthorizedPersonaSimple>(
+            r#"
+            {
+                "identityAddress": "identity_rdx12gcd4r799jpvztlffgw483pqcen98pjnay988n8rmscdswd872xy62",
+                "lastLogin": "2023-09-13T07:24:41Z",
+                "sharedPersonaData": {}
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(sut.shared_accounts, None);
+    }
+}
@@ -0,0 +1,13 @@
+mod authorized_dapp;
+mod authorized_dapps;
+mod authorized_persona_simple;
+mod requested_quantity;
+mod shared_accounts;
+mod shared_persona_data;
+
+pub use authorized_dapp::*;
+pub use authorized_dapps::*;
+pub use authorized_persona_simple::*;
+pub use requested_quantity::*;
+pub use shared_accounts::*;
+pub use shared_persona_data::*;
@@ -0,0 +1,175 @@
+use crate::prelude::*;
+
+/// A quantifier of a quantity, either `exactly` or `atLeast`, used by dApps
+/// when requesting e.g. accounts or email addresses of a Persona.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum RequestedNumberQuantifier {
+    /// (Request access to) *exactly* N many of something, where quantity `N` is
+    /// not part of this enum, e.g. "I want EXACTLY 2 accounts"
+    Exactly,
+
+    /// (Request access to) *at least* N many of something, where quantity `N` is
+    /// not part of this enum, e.g. "I want AT LEAST 3 accounts"
+    AtLeast,
+}
+
+/// A requested (by dApp) quantity, e.g. "I want AT LEAST 3 account addresses" or
+/// "I want EXACTLY 2 email addresses".
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    uniffi::Record,
+)]
+#[display("{quantifier}: {quantity}")]
+pub struct RequestedQuantity {
+    pub quantifier: RequestedNumberQuantifier,
+    pub quantity: u16,
+}
+
+impl RequestedQuantity {
+    /// Instantiates a new `RequestedQuantity` with `quantifier` and `quantity`.
+    ///
+    /// Panics if `quantifier` is `Exactly` and `quantity` is zero.
+    pub fn new(quantifier: RequestedNumberQuantifier, quantity: u16) -> Self {
+        assert!(
+            !(quantifier == RequestedNumberQuantifier::Exactly
+                && quantity == 0),
+            "Invalid RequestedQuantity, requested exactly 0, which is invalid."
+        );
+        Self {
+            quantifier,
+            quantity,
+        }
+    }
+
+    /// Requesting *exactly* `quantity` many of something.
+    pub fn exactly(quantity: u16) -> Self {
+        Self::new(RequestedNumberQuantifier::Exactly, quantity)
+    }
+
+    /// Requesting *at least* `quantity` many of something.
+    pub fn at_least(quantity: u16) -> Self {
+        Self::new(RequestedNumberQuantifier::AtLeast, quantity)
+    }
+
+    /// Checks if `len` many items fulfills this requested quantity.
+    pub fn is_fulfilled_by_ids(&self, len: usize) -> bool {
+        let quantity = self.quantity as usize;
+        match self.quantifier {
+            RequestedNumberQuantifier::Exactly => len == quantity,
+            RequestedNumberQuantifier::AtLeast => len >= quantity,
+        }
+    }
+}
+
+impl HasPlaceholder for RequestedQuantity {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::exactly(1)
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::at_least(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            RequestedQuantity::placeholder(),
+            RequestedQuantity::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            RequestedQuantity::placeholder(),
+            RequestedQuantity::placeholder_other()
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid RequestedQuantity, requested exactly 0, which is invalid."
+    )]
+    fn exactly_zero_panics() {
+        _ = RequestedQuantity::exactly(0);
+    }
+
+    #[test]
+    fn at_least_zero_is_valid() {
+        assert!(RequestedQuantity::at_least(0).is_fulfilled_by_ids(0));
+    }
+
+    #[test]
+    fn is_fulfilled_by_ids_exactly() {
+        let sut = RequestedQuantity::exactly(2);
+        assert!(!sut.is_fulfilled_by_ids(1));
+        assert!(sut.is_fulfilled_by_ids(2));
+        assert!(!sut.is_fulfilled_by_ids(3));
+    }
+
+    #[test]
+    fn is_fulfilled_by_ids_at_least() {
+        let sut = RequestedQuantity::at_least(2);
+        assert!(!sut.is_fulfilled_by_ids(1));
+        assert!(sut.is_fulfilled_by_ids(2));
+        assert!(sut.is_fulfilled_by_ids(3));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", RequestedQuantity::at_least(3)), "AtLeast: 3");
+    }
+
+    #[test]
+    fn json_roundtrip_exactly() {
+        assert_eq_after_json_roundtrip(
+            &RequestedQuantity::exactly(5),
+            r#"
+            {
+                "quantifier": "exactly",
+                "quantity": 5
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_at_least() {
+        assert_eq_after_json_roundtrip(
+            &RequestedQuantity::at_least(1),
+            r#"
+            {
+                "quantifier": "atLeast",
+                "quantity": 1
+            }
+            "#,
+        );
+    }
+}
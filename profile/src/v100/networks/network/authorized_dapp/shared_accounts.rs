@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// The addresses of the accounts a user has shared with a dApp, using a
+/// certain Persona, together with the quantity originally requested by the dApp.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct SharedAccounts {
+    /// The quantity of accounts the dApp requested.
+    pub request: RequestedQuantity,
+
+    /// The addresses of the accounts the user has shared with the dApp.
+    pub ids: Vec<AccountAddress>,
+}
+
+impl SharedAccounts {
+    /// Instantiates a new `SharedAccounts`, any duplicates in `ids` are removed.
+    ///
+    /// Panics if the number of `ids` does not fulfill `request`.
+    pub fn new<I>(request: RequestedQuantity, ids: I) -> Self
+    where
+        I: IntoIterator<Item = AccountAddress>,
+    {
+        let ids = ids.into_iter().unique().collect_vec();
+        assert!(
+            request.is_fulfilled_by_ids(ids.len()),
+            "ids does not match request"
+        );
+        Self { request, ids }
+    }
+
+    /// Removes `address` from the shared ids, if present, returns `true` if
+    /// it was removed.
+    ///
+    /// The remaining ids might not fulfill `request`, prefer
+    /// `AuthorizedPersonaSimple::remove_shared_account`.
+    pub fn remove(&mut self, address: &AccountAddress) -> bool {
+        let len = self.ids.len();
+        self.ids.retain(|a| a != address);
+        self.ids.len() != len
+    }
+}
+
+impl HasPlaceholder for SharedAccounts {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            RequestedQuantity::exactly(2),
+            [
+                AccountAddress::placeholder_alice(),
+                AccountAddress::placeholder_bob(),
+            ],
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            RequestedQuantity::at_least(1),
+            [AccountAddress::placeholder_bob()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SharedAccounts::placeholder(),
+            SharedAccounts::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SharedAccounts::placeholder(),
+            SharedAccounts::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn duplicates_are_removed() {
+        let sut = SharedAccounts::new(
+            RequestedQuantity::at_least(1),
+            [
+                AccountAddress::placeholder_alice(),
+                AccountAddress::placeholder_alice(),
+            ],
+        );
+        assert_eq!(sut.ids, vec![AccountAddress::placeholder_alice()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ids does not match request")]
+    fn panics_when_request_not_fulfilled() {
+        _ = SharedAccounts::new(
+            RequestedQuantity::exactly(2),
+            [AccountAddress::placeholder_alice()],
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let mut sut = SharedAccounts::placeholder();
+        assert!(sut.remove(&AccountAddress::placeholder_alice()));
+        assert!(!sut.remove(&AccountAddress::placeholder_alice()));
+        assert_eq!(sut.ids, vec![AccountAddress::placeholder_bob()]);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &SharedAccounts::placeholder(),
+            r#"
+            {
+                "request": {
+                    "quantifier": "exactly",
+                    "quantity": 2
+                },
+                "ids": [
+                    "account_rdx16xlfcpp0vf7e3gqnswv8j9k58n6rjccu58vvspmdva22kf3aplease",
+                    "account_rdx16yf8jxxpdtcf4afpj5ddeuazp2evep7quuhgtq28vjznee08master"
+                ]
+            }
+            "#,
+        );
+    }
+}
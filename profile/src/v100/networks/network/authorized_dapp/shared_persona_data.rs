@@ -0,0 +1,190 @@
+use crate::prelude::*;
+
+/// The IDs of entries of a collection in `PersonaData` - e.g. email addresses - that a user
+/// has shared with a dApp, together with the quantity originally requested by the dApp.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+pub struct SharedPersonaDataEntries {
+    /// The quantity of entries the dApp requested.
+    pub request: RequestedQuantity,
+
+    /// The IDs of the entries the user has shared with the dApp.
+    pub ids: Vec<PersonaDataEntryID>,
+}
+
+impl SharedPersonaDataEntries {
+    /// Instantiates a new `SharedPersonaDataEntries`, any duplicates in `ids` are removed.
+    ///
+    /// Panics if the number of `ids` does not fulfill `request`.
+    pub fn new<I>(request: RequestedQuantity, ids: I) -> Self
+    where
+        I: IntoIterator<Item = PersonaDataEntryID>,
+    {
+        let ids = ids.into_iter().unique().collect_vec();
+        assert!(
+            request.is_fulfilled_by_ids(ids.len()),
+            "ids does not match request"
+        );
+        Self { request, ids }
+    }
+}
+
+/// References to the entries of a Persona's `PersonaData` that a user has
+/// shared with a dApp, referenced by their `PersonaDataEntryID`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedPersonaData {
+    /// The ID of the name shared with the dApp, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub name: Option<PersonaDataEntryID>,
+
+    /// The IDs of the email addresses shared with the dApp, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub email_addresses: Option<SharedPersonaDataEntries>,
+
+    /// The IDs of the phone numbers shared with the dApp, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub phone_numbers: Option<SharedPersonaDataEntries>,
+}
+
+impl SharedPersonaData {
+    /// Instantiates a new `SharedPersonaData` from `name`, `email_addresses` and `phone_numbers`.
+    pub fn new(
+        name: Option<PersonaDataEntryID>,
+        email_addresses: Option<SharedPersonaDataEntries>,
+        phone_numbers: Option<SharedPersonaDataEntries>,
+    ) -> Self {
+        Self {
+            name,
+            email_addresses,
+            phone_numbers,
+        }
+    }
+
+    /// Returns the IDs of **all** shared persona data entries.
+    pub fn all_ids(&self) -> Vec<PersonaDataEntryID> {
+        self.name
+            .iter()
+            .cloned()
+            .chain(self.email_addresses.iter().flat_map(|e| e.ids.clone()))
+            .chain(self.phone_numbers.iter().flat_map(|p| p.ids.clone()))
+            .collect()
+    }
+}
+
+impl HasPlaceholder for SharedPersonaData {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::new(
+            Some(PersonaDataIdentifiedName::placeholder().id),
+            Some(SharedPersonaDataEntries::new(
+                RequestedQuantity::exactly(1),
+                [PersonaDataIdentifiedEmailAddress::placeholder().id],
+            )),
+            Some(SharedPersonaDataEntries::new(
+                RequestedQuantity::at_least(1),
+                [PersonaDataIdentifiedPhoneNumber::placeholder().id],
+            )),
+        )
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::new(
+            Some(PersonaDataIdentifiedName::placeholder_other().id),
+            None,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SharedPersonaData::placeholder(),
+            SharedPersonaData::placeholder()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SharedPersonaData::placeholder(),
+            SharedPersonaData::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn all_ids() {
+        assert_eq!(
+            SharedPersonaData::placeholder().all_ids(),
+            PersonaData::placeholder().all_ids()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ids does not match request")]
+    fn entries_panics_when_request_not_fulfilled() {
+        _ = SharedPersonaDataEntries::new(RequestedQuantity::exactly(2), []);
+    }
+
+    #[test]
+    fn json_roundtrip_placeholder() {
+        assert_eq_after_json_roundtrip(
+            &SharedPersonaData::placeholder(),
+            r#"
+            {
+                "name": "00000000-0000-0000-0000-000000000000",
+                "emailAddresses": {
+                    "request": {
+                        "quantifier": "exactly",
+                        "quantity": 1
+                    },
+                    "ids": ["00000000-0000-0000-0000-000000000002"]
+                },
+                "phoneNumbers": {
+                    "request": {
+                        "quantifier": "atLeast",
+                        "quantity": 1
+                    },
+                    "ids": ["00000000-0000-0000-0000-000000000004"]
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_empty() {
+        assert_eq_after_json_roundtrip(&SharedPersonaData::default(), "{}");
+    }
+
+    #[test]
+    fn json_deserialize_uppercase_name_id() {
+        let sut = serde_json::from_str::<SharedPersonaData>(
+            r#"{ "name": "D264960B-1E2B-4E40-AD50-D281B9DBB6D1" }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            sut.name,
+            Some(
+                Uuid::from_str("d264960b-1e2b-4e40-ad50-d281b9dbb6d1").unwrap()
+            )
+        );
+    }
+}
@@ -1,7 +1,11 @@
 mod accounts;
+mod authorized_dapp;
 mod network;
 mod network_id;
+mod personas;
 
 pub use accounts::*;
+pub use authorized_dapp::*;
 pub use network::*;
 pub use network_id::*;
+pub use personas::*;
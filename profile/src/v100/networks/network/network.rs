@@ -13,6 +13,13 @@ pub struct Network {
 
     /// An ordered set of Accounts on this network.
     pub accounts: Accounts,
+
+    /// An ordered set of Personas on this network.
+    pub personas: Personas,
+
+    /// An ordered set of Authorized Dapps on this network.
+    #[serde(rename = "authorizedDapps")]
+    pub authorized_dapps: AuthorizedDapps,
 }
 
 impl Identifiable for Network {
@@ -25,11 +32,17 @@ impl Identifiable for Network {
 }
 
 impl Network {
-    /// Instantiates a new `Network` from `network_id` and `accounts`.
+    /// Instantiates a new `Network` from `network_id`, `accounts`, `personas`
+    /// and `authorized_dapps`.
     ///
-    /// Panics if not any account in `accounts` is on another
-    /// network than `network_id`
-    pub fn new(network_id: NetworkID, accounts: Accounts) -> Self {
+    /// Panics if not any account in `accounts`, any persona in `personas` or
+    /// any dApp in `authorized_dapps` is on another network than `network_id`
+    pub fn new(
+        network_id: NetworkID,
+        accounts: Accounts,
+        personas: Personas,
+        authorized_dapps: AuthorizedDapps,
+    ) -> Self {
         assert!(
             accounts
                 .get_all()
@@ -37,9 +50,25 @@ impl Network {
                 .all(|a| a.network_id == network_id),
             "Discrepancy, found accounts on other network than {network_id}"
         );
+        assert!(
+            personas
+                .get_all()
+                .into_iter()
+                .all(|p| p.network_id == network_id),
+            "Discrepancy, found personas on other network than {network_id}"
+        );
+        assert!(
+            authorized_dapps
+                .get_all()
+                .into_iter()
+                .all(|d| d.network_id == network_id),
+            "Discrepancy, found authorized dapps on other network than {network_id}"
+        );
         Self {
             id: network_id,
             accounts,
+            personas,
+            authorized_dapps,
         }
     }
 }
@@ -60,6 +89,99 @@ impl Network {
             None
         }
     }
+
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        if self.personas.update_with(address, mutate) {
+            self.personas.get(address).cloned()
+        } else {
+            None
+        }
+    }
+}
+
+impl Network {
+    /// Validates that all personas, shared accounts and shared persona data
+    /// entries referenced by `authorized_dapp` are known to this network.
+    pub fn validate_authorized_dapp(
+        &self,
+        authorized_dapp: &AuthorizedDapp,
+    ) -> Result<()> {
+        if authorized_dapp.network_id != self.id {
+            return Err(CommonError::AuthorizedDappOnWrongNetwork {
+                expected: self.id,
+                found: authorized_dapp.network_id,
+            });
+        }
+        authorized_dapp
+            .references_to_authorized_personas
+            .iter()
+            .try_for_each(|reference| {
+                let persona = self
+                    .personas
+                    .get(&reference.identity_address)
+                    .ok_or(CommonError::UnknownPersona)?;
+
+                if let Some(shared_accounts) = &reference.shared_accounts {
+                    if !shared_accounts
+                        .ids
+                        .iter()
+                        .all(|a| self.accounts.contains_id(a))
+                    {
+                        return Err(CommonError::UnknownAccount);
+                    }
+                }
+
+                let persona_data_ids = persona.persona_data.all_ids();
+                reference
+                    .shared_persona_data
+                    .all_ids()
+                    .into_iter()
+                    .find(|id| !persona_data_ids.contains(id))
+                    .map_or(Ok(()), |id| {
+                        Err(CommonError::UnknownPersonaDataEntry(id))
+                    })
+            })
+    }
+
+    /// Removes all references to the persona with `address` from the authorized
+    /// dApps, any dApp left without any referenced persona is removed.
+    pub fn remove_references_to_persona(&mut self, address: &IdentityAddress) {
+        self.authorized_dapps = self
+            .authorized_dapps
+            .clone()
+            .into_iter()
+            .filter_map(|mut dapp| {
+                dapp.remove_references_to_persona(address);
+                if dapp.references_to_authorized_personas.is_empty() {
+                    None
+                } else {
+                    Some(dapp)
+                }
+            })
+            .collect();
+    }
+
+    /// Removes all references to the account with `address` from the shared
+    /// accounts of all authorized dApps.
+    pub fn remove_references_to_account(&mut self, address: &AccountAddress) {
+        self.authorized_dapps = self
+            .authorized_dapps
+            .clone()
+            .into_iter()
+            .map(|mut dapp| {
+                dapp.remove_references_to_account(address);
+                dapp
+            })
+            .collect();
+    }
 }
 
 impl HasPlaceholder for Network {
@@ -77,12 +199,22 @@ impl HasPlaceholder for Network {
 impl Network {
     /// A placeholder used to facilitate unit tests.
     pub fn placeholder_mainnet() -> Self {
-        Self::new(NetworkID::Mainnet, Accounts::placeholder_mainnet())
+        Self::new(
+            NetworkID::Mainnet,
+            Accounts::placeholder_mainnet(),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        )
     }
 
     /// A placeholder used to facilitate unit tests.
     pub fn placeholder_stokenet() -> Self {
-        Self::new(NetworkID::Stokenet, Accounts::placeholder_stokenet())
+        Self::new(
+            NetworkID::Stokenet,
+            Accounts::placeholder_stokenet(),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        )
     }
 }
 
@@ -114,7 +246,9 @@ mod tests {
                 Accounts::with_accounts(
                     [Account::placeholder(), Account::placeholder()]
                         .into_iter()
-                )
+                ),
+                Personas::default(),
+                AuthorizedDapps::default(),
             )
             .accounts
             .len(),
@@ -133,7 +267,188 @@ mod tests {
                 Account::placeholder_mainnet(),
                 Account::placeholder_stokenet(),
             ]),
+            Personas::default(),
+            AuthorizedDapps::default(),
+        );
+    }
+
+    #[test]
+    fn duplicate_personas_are_filtered_out() {
+        assert_eq!(
+            Network::new(
+                NetworkID::Mainnet,
+                Accounts::default(),
+                Personas::with_personas([
+                    Persona::placeholder(),
+                    Persona::placeholder()
+                ]),
+                AuthorizedDapps::default(),
+            )
+            .personas
+            .len(),
+            1
+        )
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Discrepancy, found personas on other network than mainnet"
+    )]
+    fn panic_when_network_id_mismatch_between_personas_and_value() {
+        Network::new(
+            NetworkID::Mainnet,
+            Accounts::default(),
+            Personas::with_personas([
+                Persona::placeholder_satoshi(),
+                Persona::placeholder_stokenet_leia(),
+            ]),
+            AuthorizedDapps::default(),
+        );
+    }
+
+    #[test]
+    fn update_persona() {
+        let mut sut = Network::new(
+            NetworkID::Mainnet,
+            Accounts::default(),
+            Personas::placeholder_mainnet(),
+            AuthorizedDapps::default(),
         );
+        let address = Persona::placeholder_batman().address;
+        let updated = sut
+            .update_persona(&address, |p| {
+                p.display_name = DisplayName::new("Bruce").unwrap()
+            })
+            .unwrap();
+        assert_eq!(updated.display_name.value, "Bruce");
+        assert_eq!(sut.personas.get(&address).unwrap(), &updated);
+    }
+
+    #[test]
+    fn update_persona_unknown() {
+        let mut sut = Network::placeholder();
+        assert_eq!(
+            sut.update_persona(&Persona::placeholder().address, |p| {
+                p.display_name = DisplayName::new("Bruce").unwrap()
+            }),
+            None
+        );
+    }
+
+    fn network_with_authorized_dapps() -> Network {
+        Network::new(
+            NetworkID::Mainnet,
+            Accounts::placeholder_mainnet(),
+            Personas::placeholder_mainnet(),
+            AuthorizedDapps::placeholder_mainnet(),
+        )
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Discrepancy, found authorized dapps on other network than stokenet"
+    )]
+    fn panic_when_network_id_mismatch_between_authorized_dapps_and_value() {
+        Network::new(
+            NetworkID::Stokenet,
+            Accounts::default(),
+            Personas::default(),
+            AuthorizedDapps::placeholder_mainnet(),
+        );
+    }
+
+    #[test]
+    fn validate_authorized_dapp() {
+        let sut = network_with_authorized_dapps();
+        assert_eq!(
+            sut.validate_authorized_dapp(&AuthorizedDapp::placeholder()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_authorized_dapp_unknown_persona() {
+        let sut = Network::placeholder();
+        assert_eq!(
+            sut.validate_authorized_dapp(&AuthorizedDapp::placeholder()),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn validate_authorized_dapp_unknown_account() {
+        let sut = Network::new(
+            NetworkID::Mainnet,
+            Accounts::with_account(Account::placeholder_mainnet_alice()),
+            Personas::placeholder_mainnet(),
+            AuthorizedDapps::default(),
+        );
+        assert_eq!(
+            sut.validate_authorized_dapp(&AuthorizedDapp::placeholder()),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn validate_authorized_dapp_unknown_persona_data_entry() {
+        let sut = network_with_authorized_dapps();
+        let mut dapp = AuthorizedDapp::placeholder_mainnet_gumballclub();
+        let address = Persona::placeholder_batman().address;
+        _ = dapp
+            .references_to_authorized_personas
+            .update_with(&address, |p| {
+                p.shared_persona_data = SharedPersonaData::placeholder_other()
+            });
+        assert_eq!(
+            sut.validate_authorized_dapp(&dapp),
+            Err(CommonError::UnknownPersonaDataEntry(
+                PersonaDataIdentifiedName::placeholder_other().id
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_authorized_dapp_wrong_network() {
+        let sut = Network::placeholder_stokenet();
+        assert_eq!(
+            sut.validate_authorized_dapp(&AuthorizedDapp::placeholder()),
+            Err(CommonError::AuthorizedDappOnWrongNetwork {
+                expected: NetworkID::Stokenet,
+                found: NetworkID::Mainnet
+            })
+        );
+    }
+
+    #[test]
+    fn remove_references_to_persona_removes_dapps_without_personas() {
+        let mut sut = network_with_authorized_dapps();
+        sut.remove_references_to_persona(
+            &Persona::placeholder_batman().address,
+        );
+        assert_eq!(
+            sut.authorized_dapps.items(),
+            [AuthorizedDapp::new(
+                NetworkID::Mainnet,
+                AccountAddress::placeholder_alice(),
+                Some("Radix Dashboard".to_string()),
+                ReferencesToAuthorizedPersonas::from_iter([
+                    AuthorizedPersonaSimple::placeholder_satoshi()
+                ]),
+            )]
+        );
+    }
+
+    #[test]
+    fn remove_references_to_account() {
+        let mut sut = network_with_authorized_dapps();
+        let bob = Account::placeholder_mainnet_bob().address;
+        sut.remove_references_to_account(&bob);
+        assert_eq!(sut.authorized_dapps.len(), 2);
+        assert!(sut
+            .authorized_dapps
+            .iter()
+            .flat_map(|d| d.references_to_authorized_personas.items())
+            .all(|p| !p.is_sharing_account(&bob)));
     }
 
     #[test]
@@ -235,7 +550,9 @@ mod tests {
 						"flags": [],
 						"address": "account_tdx_2_129663ef7fj8azge3y6sl73lf9vyqt53ewzlf7ul2l76mg5wyqlqlpr"
 					}
-				]
+				],
+				"personas": [],
+				"authorizedDapps": []
 			}
             "#,
         )
@@ -340,7 +657,9 @@ mod tests {
 						"flags": [],
 						"address": "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69"
 					}
-				]
+				],
+				"personas": [],
+				"authorizedDapps": []
 			}
             "#,
         );
@@ -0,0 +1,169 @@
+use crate::prelude::*;
+
+/// An ordered set of Personas on a specific network.
+pub type Personas = IdentifiedVecVia<Persona>;
+
+impl Personas {
+    /// Instantiates a new collection of personas from
+    /// and iterator of personas.
+    pub fn with_personas<I>(personas: I) -> Self
+    where
+        I: IntoIterator<Item = Persona>,
+    {
+        Self::from_iter(personas)
+    }
+
+    /// Instantiates a new collection of personas from a
+    /// single persona.
+    pub fn with_persona(persona: Persona) -> Self {
+        Self::with_personas([persona])
+    }
+}
+
+// Trait: Default
+impl Default for Personas {
+    /// Instantiates a new empty personas collection.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Personas {
+    /// Returns a reference to the persona identified by `address`, if it exists.
+    pub fn get_persona_by_address(
+        &self,
+        address: &IdentityAddress,
+    ) -> Option<&Persona> {
+        self.get(address)
+    }
+
+    /// Returns references to **all** personas, including hidden ones.
+    pub fn get_all(&self) -> Vec<&Persona> {
+        self.elements()
+    }
+
+    /// Returns references to the personas not hidden by the user.
+    pub fn get_visible(&self) -> Vec<&Persona> {
+        self.iter().filter(|p| !p.is_hidden()).collect()
+    }
+
+    /// Returns references to the personas hidden by the user.
+    pub fn get_hidden(&self) -> Vec<&Persona> {
+        self.iter().filter(|p| p.is_hidden()).collect()
+    }
+}
+
+impl HasPlaceholder for Personas {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::placeholder_mainnet()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Self::placeholder_stokenet()
+    }
+}
+
+impl Personas {
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_mainnet() -> Self {
+        Self::with_personas([
+            Persona::placeholder_satoshi(),
+            Persona::placeholder_batman(),
+        ])
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    pub fn placeholder_stokenet() -> Self {
+        Self::with_personas([
+            Persona::placeholder_stokenet_leia(),
+            Persona::placeholder_stokenet_hermione(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn default_is_empty() {
+        assert_eq!(Personas::default().len(), 0);
+    }
+
+    #[test]
+    fn visible_and_hidden() {
+        let mut sut = Personas::placeholder_mainnet();
+        let batman = Persona::placeholder_batman();
+        sut.update_with(&batman.address, |p| {
+            _ = p.flags.insert_flag(EntityFlag::DeletedByUser)
+        });
+        assert_eq!(sut.get_all().len(), 2);
+        assert_eq!(sut.get_visible(), vec![&Persona::placeholder_satoshi()]);
+        assert_eq!(
+            sut.get_hidden()
+                .into_iter()
+                .map(|p| p.address.clone())
+                .collect_vec(),
+            vec![batman.address]
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Personas::placeholder(), Personas::placeholder_other());
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Personas::placeholder(), Personas::placeholder());
+        assert_eq!(
+            Personas::placeholder_other(),
+            Personas::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn duplicates_are_prevented() {
+        assert_eq!(
+            Personas::with_personas(
+                [Persona::placeholder(), Persona::placeholder()].into_iter()
+            )
+            .len(),
+            1
+        )
+    }
+
+    #[test]
+    fn with_one() {
+        assert_eq!(Personas::with_persona(Persona::placeholder()).len(), 1)
+    }
+
+    #[test]
+    fn get_by_address() {
+        let persona = Persona::placeholder_batman();
+        let address = persona.address.clone();
+        let personas = Personas::with_persona(persona.clone());
+        assert_eq!(personas.get_persona_by_address(&address), Some(&persona));
+    }
+
+    #[test]
+    fn get_all() {
+        assert_eq!(Personas::placeholder().get_all().len(), 2);
+    }
+
+    #[test]
+    fn placeholder_stokenet_is_on_stokenet() {
+        assert!(Personas::placeholder_stokenet()
+            .get_all()
+            .into_iter()
+            .all(|p| p.network_id == NetworkID::Stokenet));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_roundtrip(&Personas::placeholder());
+        assert_json_roundtrip(&Personas::placeholder_other());
+    }
+}
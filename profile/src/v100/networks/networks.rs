@@ -44,13 +44,40 @@ impl Networks {
         });
         self.get_account(address)
     }
+
+    pub fn get_persona(&self, address: &IdentityAddress) -> Option<Persona> {
+        self.get(&address.network_id)
+            .and_then(|n| n.personas.get_persona_by_address(address))
+            .cloned()
+    }
+
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mut mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        self.update_with(&address.network_id, |n| {
+            _ = n.update_persona(address, |p| mutate(p))
+        });
+        self.get_persona(address)
+    }
 }
 
 impl Networks {
     pub fn content_hint(&self) -> ContentHint {
         let number_of_accounts =
             self.iter().fold(0, |acc, x| acc + x.accounts.len());
-        ContentHint::with_counters(number_of_accounts, 0, self.len())
+        let number_of_personas =
+            self.iter().fold(0, |acc, x| acc + x.personas.len());
+        ContentHint::with_counters(
+            number_of_accounts,
+            number_of_personas,
+            self.len(),
+        )
     }
 }
 
@@ -119,11 +146,15 @@ mod tests {
                 Account::placeholder_mainnet_alice(),
                 Account::placeholder_mainnet_bob(),
             ]),
+            Personas::default(),
+            AuthorizedDapps::default(),
         )]);
         assert!(
             !sut.append(Network::new(
                 NetworkID::Mainnet,
                 Accounts::from_iter([Account::placeholder_mainnet_carol()]),
+                Personas::default(),
+                AuthorizedDapps::default(),
             ))
             .0
         );
@@ -208,6 +239,8 @@ mod tests {
         let network = Network::new(
             NetworkID::Mainnet,
             Accounts::with_account(Account::placeholder_mainnet()),
+            Personas::default(),
+            AuthorizedDapps::default(),
         );
         assert_eq!(Networks::with_network(network).len(), 1);
     }
@@ -220,6 +253,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn content_hint_counts_personas() {
+        let sut = Networks::with_networks([
+            Network::new(
+                NetworkID::Mainnet,
+                Accounts::placeholder_mainnet(),
+                Personas::placeholder_mainnet(),
+                AuthorizedDapps::default(),
+            ),
+            Network::new(
+                NetworkID::Stokenet,
+                Accounts::default(),
+                Personas::with_persona(Persona::placeholder_stokenet_leia()),
+                AuthorizedDapps::default(),
+            ),
+        ]);
+        assert_eq!(sut.content_hint(), ContentHint::with_counters(2, 3, 2));
+    }
+
+    #[test]
+    fn update_persona() {
+        let mut sut = Networks::with_network(Network::new(
+            NetworkID::Mainnet,
+            Accounts::default(),
+            Personas::placeholder_mainnet(),
+            AuthorizedDapps::default(),
+        ));
+        let address = Persona::placeholder_satoshi().address;
+
+        assert_eq!(
+            sut.update_persona(&address, |p| {
+                p.display_name = DisplayName::new("Nakamoto").unwrap()
+            })
+            .unwrap()
+            .display_name
+            .value,
+            "Nakamoto"
+        );
+        assert_eq!(
+            sut.get_persona(&address).unwrap().display_name.value,
+            "Nakamoto"
+        );
+    }
+
+    #[test]
+    fn update_persona_unknown_network() {
+        let mut sut = Networks::placeholder();
+        assert!(sut
+            .update_persona(
+                &Persona::placeholder_stokenet_leia().address,
+                |p| { p.display_name = DisplayName::new("will fail").unwrap() }
+            )
+            .is_none());
+
+        // Assert unchanged
+        assert_eq!(sut, Networks::placeholder());
+    }
+
     #[test]
     fn json_roundtrip() {
         let sut = Networks::placeholder();
@@ -320,7 +411,9 @@ mod tests {
                             "flags": [],
                             "address": "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69"
                         }
-                    ]
+                    ],
+                    "personas": [],
+                    "authorizedDapps": []
                 },
                 {
                     "networkID": 2,
@@ -415,7 +508,9 @@ mod tests {
                             "flags": [],
                             "address": "account_tdx_2_129663ef7fj8azge3y6sl73lf9vyqt53ewzlf7ul2l76mg5wyqlqlpr"
                         }
-                    ]
+                    ],
+                    "personas": [],
+                    "authorizedDapps": []
                 }
             ]
             "#,
@@ -1,4 +1,6 @@
 use crate::prelude::*;
+use radix_engine_common::network::NetworkDefinition;
+
 /// Representation of the Radix Wallet, contains a list of
 /// users Accounts, Personas, Authorized Dapps per network
 /// the user has used. It also contains all FactorSources,
@@ -89,6 +91,108 @@ impl Profile {
         self.header.id.clone()
     }
 
+    /// Returns the accounts on `network_id` not hidden by the user.
+    pub fn visible_accounts_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Account> {
+        self.networks
+            .get(&network_id)
+            .map(|n| n.accounts.get_visible().into_iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the accounts on `network_id` hidden by the user.
+    pub fn hidden_accounts_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Account> {
+        self.networks
+            .get(&network_id)
+            .map(|n| n.accounts.get_hidden().into_iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the personas on `network_id` not hidden by the user.
+    pub fn visible_personas_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Persona> {
+        self.networks
+            .get(&network_id)
+            .map(|n| n.personas.get_visible().into_iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the personas on `network_id` hidden by the user.
+    pub fn hidden_personas_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Persona> {
+        self.networks
+            .get(&network_id)
+            .map(|n| n.personas.get_hidden().into_iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the `NetworkDefinition` of the network with the raw ID
+    /// `network_id`, using the custom network definitions of this Profile for
+    /// networks unknown to this library.
+    ///
+    /// Returns `Err` if the network is neither known nor defined in Profile.
+    pub fn network_definition(
+        &self,
+        network_id: u8,
+    ) -> Result<NetworkDefinition> {
+        if let Ok(known) = NetworkID::try_from(network_id) {
+            return Ok(known.network_definition());
+        }
+        self.app_preferences
+            .custom_networks
+            .get(&network_id)
+            .map(|c| c.network_definition())
+            .ok_or(CommonError::UnknownNetworkID(network_id))
+    }
+
+    /// Returns the `RadixNetwork` of the network with the raw ID
+    /// `network_id`, using the custom network definitions of this Profile for
+    /// networks unknown to this library.
+    ///
+    /// Returns `Err` if the network is neither known nor defined in Profile.
+    pub fn radix_network(&self, network_id: u8) -> Result<RadixNetwork> {
+        if let Ok(known) = NetworkID::try_from(network_id) {
+            return RadixNetwork::lookup_by_id(known);
+        }
+        self.app_preferences
+            .custom_networks
+            .get(&network_id)
+            .map(|c| c.radix_network())
+            .ok_or(CommonError::UnknownNetworkID(network_id))
+    }
+
+    /// Creates a Gateway with `url` to the network with the raw ID
+    /// `network_id`, which might be a custom network defined in this Profile.
+    pub fn new_gateway(&self, url: String, network_id: u8) -> Result<Gateway> {
+        self.radix_network(network_id)
+            .and_then(|network| Gateway::with_network(url, network))
+    }
+
+    /// Returns the bech32 address of the account controlled by `public_key`
+    /// on the network with the raw ID `network_id`, which might be a custom
+    /// network defined in this Profile.
+    ///
+    /// Returns `Err` if the network is neither known nor defined in Profile.
+    pub fn account_address_on_network(
+        &self,
+        public_key: PublicKey,
+        network_id: u8,
+    ) -> Result<String> {
+        let network = self.network_definition(network_id)?;
+        Ok(AccountAddress::bech32_from_public_key(
+            public_key, network.id,
+        ))
+    }
+
     /// Returns a clone of the updated account if found, else None.
     pub fn update_account<F>(
         &mut self,
@@ -101,6 +205,18 @@ impl Profile {
         self.networks.update_account(address, mutate)
     }
 
+    /// Returns a clone of the updated persona if found, else None.
+    pub fn update_persona<F>(
+        &mut self,
+        address: &IdentityAddress,
+        mutate: F,
+    ) -> Option<Persona>
+    where
+        F: FnMut(&mut Persona),
+    {
+        self.networks.update_persona(address, mutate)
+    }
+
     pub fn update_factor_source<S, M>(
         &mut self,
         factor_source_id: &FactorSourceID,
@@ -164,6 +280,161 @@ mod tests {
         assert_eq!(Profile::placeholder_other(), Profile::placeholder_other());
     }
 
+    fn profile_with_custom_network() -> Profile {
+        let mut sut = Profile::placeholder();
+        sut.app_preferences
+            .custom_networks
+            .append(CustomNetworkDefinition::placeholder());
+        sut
+    }
+
+    #[test]
+    fn network_definition() {
+        let sut = profile_with_custom_network();
+        assert_eq!(
+            sut.network_definition(NetworkID::Stokenet.discriminant())
+                .unwrap()
+                .logical_name,
+            "stokenet"
+        );
+        assert_eq!(
+            sut.network_definition(0x30).unwrap().logical_name,
+            "ziggurat"
+        );
+        assert_eq!(
+            sut.network_definition(0x31).err(),
+            Some(CommonError::UnknownNetworkID(0x31))
+        );
+    }
+
+    #[test]
+    fn radix_network() {
+        let sut = profile_with_custom_network();
+        assert_eq!(
+            sut.radix_network(NetworkID::Mainnet.discriminant()),
+            Ok(RadixNetwork::mainnet())
+        );
+        assert_eq!(
+            sut.radix_network(0x30),
+            Ok(CustomNetworkDefinition::placeholder().radix_network())
+        );
+        assert_eq!(
+            sut.radix_network(0x31),
+            Err(CommonError::UnknownNetworkID(0x31))
+        );
+    }
+
+    #[test]
+    fn new_gateway() {
+        let sut = profile_with_custom_network();
+        let url = "https://gateway.ziggurat.example.com/";
+        let gateway = sut.new_gateway(url.to_owned(), 0x30).unwrap();
+        assert_eq!(gateway.url.as_str(), url);
+        assert_eq!(
+            gateway.network,
+            CustomNetworkDefinition::placeholder().radix_network()
+        );
+        assert_eq!(
+            sut.new_gateway(url.to_owned(), 0x31),
+            Err(CommonError::UnknownNetworkID(0x31))
+        );
+    }
+
+    #[test]
+    fn account_address_on_network() {
+        use radix_engine_toolkit::functions::address::decode;
+        let sut = profile_with_custom_network();
+        let alice = Account::placeholder_mainnet_alice();
+        let public_key = alice
+            .security_state
+            .as_unsecured()
+            .unwrap()
+            .transaction_signing
+            .public_key
+            .public_key
+            .clone();
+
+        assert_eq!(
+            sut.account_address_on_network(
+                public_key.clone(),
+                NetworkID::Mainnet.discriminant()
+            ),
+            Ok(alice.address.address.clone())
+        );
+
+        let custom = sut
+            .account_address_on_network(public_key.clone(), 0x30)
+            .unwrap();
+        assert!(custom.starts_with("account_tdx_30_1"));
+        let (network_id, _, hrp, data) = decode(&custom).unwrap();
+        assert_eq!(network_id, 0x30);
+        assert_eq!(hrp, "account_tdx_30_");
+        assert_eq!(data, decode(&alice.address.address).unwrap().3);
+
+        assert_eq!(
+            sut.account_address_on_network(public_key, 0x31),
+            Err(CommonError::UnknownNetworkID(0x31))
+        );
+    }
+
+    #[test]
+    fn visible_and_hidden_accounts_on_network() {
+        let mut sut = Profile::placeholder();
+        let bob = Account::placeholder_mainnet_bob();
+        sut.update_account(&bob.address, |a| {
+            _ = a.flags.insert_flag(EntityFlag::DeletedByUser)
+        });
+        assert_eq!(
+            sut.visible_accounts_on_network(NetworkID::Mainnet),
+            vec![Account::placeholder_mainnet_alice()]
+        );
+        assert_eq!(
+            sut.hidden_accounts_on_network(NetworkID::Mainnet)
+                .into_iter()
+                .map(|a| a.address)
+                .collect_vec(),
+            vec![bob.address]
+        );
+        assert!(sut
+            .hidden_accounts_on_network(NetworkID::Stokenet)
+            .is_empty());
+        assert!(sut
+            .visible_accounts_on_network(NetworkID::Nebunet)
+            .is_empty());
+
+        // Hidden accounts are still counted by the content hint.
+        assert_eq!(
+            sut.networks.content_hint(),
+            Profile::placeholder().networks.content_hint()
+        );
+    }
+
+    #[test]
+    fn visible_and_hidden_personas_on_network() {
+        let mut sut = Profile::placeholder();
+        _ = sut.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.personas = Personas::placeholder_mainnet()
+        });
+        let batman = Persona::placeholder_batman();
+        sut.update_persona(&batman.address, |p| {
+            _ = p.flags.insert_flag(EntityFlag::DeletedByUser)
+        });
+        assert_eq!(
+            sut.visible_personas_on_network(NetworkID::Mainnet),
+            vec![Persona::placeholder_satoshi()]
+        );
+        assert_eq!(
+            sut.hidden_personas_on_network(NetworkID::Mainnet)
+                .into_iter()
+                .map(|p| p.address)
+                .collect_vec(),
+            vec![batman.address]
+        );
+        assert!(sut
+            .visible_personas_on_network(NetworkID::Nebunet)
+            .is_empty());
+    }
+
     #[test]
     fn update_factor_source_not_update_when_factor_source_not_found() {
         let mut sut = Profile::placeholder();
@@ -565,7 +836,9 @@ mod tests {
 								"flags": [],
 								"address": "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69"
 							}
-						]
+						],
+						"personas": [],
+						"authorizedDapps": []
 					},
 					{
 						"networkID": 2,
@@ -660,7 +933,9 @@ mod tests {
 								"flags": [],
 								"address": "account_tdx_2_129663ef7fj8azge3y6sl73lf9vyqt53ewzlf7ul2l76mg5wyqlqlpr"
 							}
-						]
+						],
+						"personas": [],
+						"authorizedDapps": []
 					}
 				]
 			}
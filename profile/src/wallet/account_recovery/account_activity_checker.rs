@@ -0,0 +1,9 @@
+use crate::prelude::*;
+
+/// Used by account recovery scans to find out which of the derived accounts
+/// are in use, i.e. if they have any activity on ledger. Typically
+/// implemented by the Wallet Client using the Gateway API.
+#[uniffi::export]
+pub trait AccountActivityChecker: Send + Sync + std::fmt::Debug {
+    fn is_active_on_ledger(&self, address: AccountAddress) -> Result<bool>;
+}
@@ -0,0 +1,254 @@
+use crate::prelude::*;
+
+/// The number of account indices derived and checked for activity on ledger
+/// at a time during an account recovery scan.
+pub const ACCOUNT_RECOVERY_SCAN_BATCH_SIZE: HDPathValue = 30;
+
+/// The maximum number of batches of `ACCOUNT_RECOVERY_SCAN_BATCH_SIZE`
+/// indices an account recovery scan checks, after which it fails rather than
+/// deriving keys forever for an activity checker reporting every account as
+/// active.
+pub const ACCOUNT_RECOVERY_SCAN_MAX_BATCH_COUNT: HDPathValue = 10;
+
+/// The accounts found by an account recovery scan of a mnemonic, ready to be
+/// added to Profile together with the `factor_source` controlling them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AccountRecoveryScanOutcome {
+    /// The "Babylon" `DeviceFactorSource` of the scanned mnemonic, which
+    /// controls all `accounts`.
+    pub factor_source: DeviceFactorSource,
+
+    /// The accounts found to be active on ledger, ordered by derivation index.
+    pub accounts: Vec<Account>,
+}
+
+impl AccountRecoveryScanOutcome {
+    /// Creates the `accounts` from `factor_instances`, with appearance ids
+    /// following the `number_of_accounts_on_network` already in Profile.
+    pub fn new(
+        factor_source: DeviceFactorSource,
+        factor_instances: Vec<HDFactorInstanceAccountCreation>,
+        number_of_accounts_on_network: usize,
+    ) -> Self {
+        let accounts = factor_instances
+            .into_iter()
+            .enumerate()
+            .map(|(offset, fi)| {
+                Account::new(
+                    fi,
+                    DisplayName::default(),
+                    AppearanceID::from_number_of_accounts_on_network(
+                        number_of_accounts_on_network + offset,
+                    ),
+                )
+            })
+            .collect();
+        Self {
+            factor_source,
+            accounts,
+        }
+    }
+}
+
+impl PrivateHierarchicalDeterministicFactorSource {
+    /// Derives account creating factor instances on `network_id` in batches
+    /// of `ACCOUNT_RECOVERY_SCAN_BATCH_SIZE` indices, starting at index `0`,
+    /// returning those for which the account address is active on ledger
+    /// according to `activity_checker`, ordered by index.
+    ///
+    /// The scan stops after the first batch without any active account.
+    ///
+    /// Returns `Err` if `activity_checker` fails or if all of the
+    /// `ACCOUNT_RECOVERY_SCAN_MAX_BATCH_COUNT` batches contain an active
+    /// account.
+    pub fn scan_for_active_accounts(
+        &self,
+        network_id: NetworkID,
+        activity_checker: &dyn AccountActivityChecker,
+    ) -> Result<Vec<HDFactorInstanceAccountCreation>> {
+        let mut active = Vec::new();
+        for batch_index in 0..ACCOUNT_RECOVERY_SCAN_MAX_BATCH_COUNT {
+            let start = batch_index * ACCOUNT_RECOVERY_SCAN_BATCH_SIZE;
+            let end = start + ACCOUNT_RECOVERY_SCAN_BATCH_SIZE;
+            debug!("Scanning account indices {}..{}", start, end);
+            let batch = (start..end)
+                .map(|index| {
+                    self.derive_entity_creation_factor_instance::<AccountPath>(
+                        network_id, index,
+                    )
+                })
+                .map(|fi| {
+                    let address = AccountAddress::from_hd_factor_instance_virtual_entity_creation(fi.clone());
+                    activity_checker
+                        .is_active_on_ledger(address)
+                        .map(|is_active| is_active.then_some(fi))
+                })
+                .filter_map_ok(|fi| fi)
+                .collect::<Result<Vec<_>>>()?;
+
+            if batch.is_empty() {
+                return Ok(active);
+            }
+            active.extend(batch);
+        }
+        Err(CommonError::AccountRecoveryScanIndexLimitReached(
+            ACCOUNT_RECOVERY_SCAN_MAX_BATCH_COUNT
+                * ACCOUNT_RECOVERY_SCAN_BATCH_SIZE,
+        ))
+    }
+}
+
+/// Scans `network_id` for accounts controlled by `mnemonic_with_passphrase`
+/// which are active on ledger according to `activity_checker`, used when
+/// restoring a wallet from a mnemonic without any Profile.
+///
+/// The returned accounts are controlled by the main "Babylon"
+/// `DeviceFactorSource` of the mnemonic.
+#[uniffi::export]
+pub fn scan_accounts_for_recovery(
+    mnemonic_with_passphrase: MnemonicWithPassphrase,
+    network_id: NetworkID,
+    wallet_client_model: WalletClientModel,
+    activity_checker: Arc<dyn AccountActivityChecker>,
+) -> Result<AccountRecoveryScanOutcome> {
+    let bdfs = DeviceFactorSource::babylon(
+        true,
+        mnemonic_with_passphrase.clone(),
+        wallet_client_model,
+    );
+    let private = PrivateHierarchicalDeterministicFactorSource::new(
+        mnemonic_with_passphrase,
+        bdfs.clone(),
+    );
+    let factor_instances = private
+        .scan_for_active_accounts(network_id, activity_checker.as_ref())?;
+    Ok(AccountRecoveryScanOutcome::new(bdfs, factor_instances, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Debug)]
+    struct FailingActivityChecker;
+
+    impl AccountActivityChecker for FailingActivityChecker {
+        fn is_active_on_ledger(&self, _: AccountAddress) -> Result<bool> {
+            Err(CommonError::Unknown)
+        }
+    }
+
+    #[derive(Debug)]
+    struct AlwaysActiveActivityChecker;
+
+    impl AccountActivityChecker for AlwaysActiveActivityChecker {
+        fn is_active_on_ledger(&self, _: AccountAddress) -> Result<bool> {
+            Ok(true)
+        }
+    }
+
+    fn scanned_indices(indices: &[HDPathValue]) -> Vec<HDPathValue> {
+        PrivateHierarchicalDeterministicFactorSource::placeholder()
+            .scan_for_active_accounts(
+                NetworkID::Mainnet,
+                &StubActivityChecker::new(NetworkID::Mainnet, indices),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|fi| fi.path.index)
+            .collect()
+    }
+
+    #[test]
+    fn no_active_accounts() {
+        assert!(scanned_indices(&[]).is_empty());
+    }
+
+    #[test]
+    fn active_accounts_in_first_batch() {
+        assert_eq!(scanned_indices(&[0, 2, 5]), vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn scan_continues_while_batches_have_active_accounts() {
+        assert_eq!(scanned_indices(&[1, 35, 61]), vec![1, 35, 61]);
+    }
+
+    #[test]
+    fn scan_stops_after_batch_without_active_accounts() {
+        assert_eq!(scanned_indices(&[1, 65]), vec![1]);
+    }
+
+    #[test]
+    fn activity_checker_failure() {
+        assert_eq!(
+            PrivateHierarchicalDeterministicFactorSource::placeholder()
+                .scan_for_active_accounts(
+                    NetworkID::Mainnet,
+                    &FailingActivityChecker
+                ),
+            Err(CommonError::Unknown)
+        );
+    }
+
+    #[test]
+    fn scan_fails_when_index_limit_is_reached() {
+        assert_eq!(
+            PrivateHierarchicalDeterministicFactorSource::placeholder()
+                .scan_for_active_accounts(
+                    NetworkID::Mainnet,
+                    &AlwaysActiveActivityChecker
+                ),
+            Err(CommonError::AccountRecoveryScanIndexLimitReached(300))
+        );
+    }
+
+    #[test]
+    fn outcome_accounts() {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let fi = private
+            .derive_entity_creation_factor_instance(NetworkID::Mainnet, 0);
+        let sut = AccountRecoveryScanOutcome::new(
+            private.factor_source.clone(),
+            vec![fi.clone()],
+            3,
+        );
+        assert_eq!(
+            sut.accounts,
+            vec![Account::new(
+                fi,
+                DisplayName::default(),
+                AppearanceID::from_number_of_accounts_on_network(3)
+            )]
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn scan_accounts_for_recovery() {
+        let sut = super::scan_accounts_for_recovery(
+            MnemonicWithPassphrase::placeholder(),
+            NetworkID::Mainnet,
+            WalletClientModel::Iphone,
+            Arc::new(StubActivityChecker::new(NetworkID::Mainnet, &[0, 1])),
+        )
+        .unwrap();
+        assert!(sut.factor_source.is_main_bdfs());
+        assert_eq!(
+            sut.factor_source.id,
+            FactorSourceIDFromHash::placeholder_device()
+        );
+        assert_eq!(
+            sut.accounts.iter().map(|a| a.appearance_id).collect_vec(),
+            vec![
+                AppearanceID::from_number_of_accounts_on_network(0),
+                AppearanceID::from_number_of_accounts_on_network(1)
+            ]
+        );
+    }
+}
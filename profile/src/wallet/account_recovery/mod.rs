@@ -0,0 +1,11 @@
+mod account_activity_checker;
+mod account_recovery_scan;
+
+pub use account_activity_checker::*;
+pub use account_recovery_scan::*;
+
+#[cfg(test)]
+mod stub_account_activity_checker;
+
+#[cfg(test)]
+pub use stub_account_activity_checker::*;
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// Used for testing - stands in for the Gateway, treating the accounts
+/// derived at `indices` by `MnemonicWithPassphrase::placeholder()` as active.
+#[derive(Debug)]
+pub struct StubActivityChecker {
+    active: HashSet<AccountAddress>,
+}
+
+impl StubActivityChecker {
+    pub fn new(network_id: NetworkID, indices: &[HDPathValue]) -> Self {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let active = indices
+            .iter()
+            .map(|i| {
+                AccountAddress::from_hd_factor_instance_virtual_entity_creation(
+                    private
+                        .derive_entity_creation_factor_instance(network_id, *i),
+                )
+            })
+            .collect();
+        Self { active }
+    }
+}
+
+impl AccountActivityChecker for StubActivityChecker {
+    fn is_active_on_ledger(&self, address: AccountAddress) -> Result<bool> {
+        Ok(self.active.contains(&address))
+    }
+}
@@ -0,0 +1,150 @@
+use crate::prelude::*;
+
+/// Information about a connected Ledger device, as read from the Radix
+/// Ledger app.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDeviceInfo {
+    /// The model of the Ledger device, e.g. `nanoS+`.
+    pub model: LedgerHardwareWalletModel,
+
+    /// The body of the `FactorSourceIDFromHash` of the Ledger device, which
+    /// is the hash of the special key derived at `CAP26::GetID`.
+    pub id: Hex32Bytes,
+}
+
+impl LedgerDeviceInfo {
+    pub fn new(model: LedgerHardwareWalletModel, id: Hex32Bytes) -> Self {
+        Self { model, id }
+    }
+}
+
+/// Identifies the Ledger device a request is sent to, allowing the host to
+/// verify that the correct Ledger is connected before forwarding it.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDevice {
+    /// The name the user gave the Ledger device, if known.
+    pub name: Option<String>,
+
+    /// The model of the Ledger device, e.g. `nanoS+`.
+    pub model: LedgerHardwareWalletModel,
+
+    /// The body of the `FactorSourceIDFromHash` of the Ledger device.
+    pub id: Hex32Bytes,
+}
+
+impl LedgerDevice {
+    pub fn new(
+        name: Option<String>,
+        model: LedgerHardwareWalletModel,
+        id: Hex32Bytes,
+    ) -> Self {
+        Self { name, model, id }
+    }
+}
+
+impl From<&LedgerHardwareWalletFactorSource> for LedgerDevice {
+    fn from(value: &LedgerHardwareWalletFactorSource) -> Self {
+        Self::new(
+            Some(value.hint.name.clone()),
+            value.hint.model,
+            value.id.body.clone(),
+        )
+    }
+}
+
+impl LedgerHardwareWalletFactorSource {
+    /// Instantiates a new `LedgerHardwareWalletFactorSource` for the Ledger
+    /// device described by `device_info`, named `name` by the user.
+    pub fn from_device_info(device_info: LedgerDeviceInfo, name: &str) -> Self {
+        Self::new(
+            FactorSourceIDFromHash::new(
+                FactorSourceKind::LedgerHQHardwareWallet,
+                device_info.id,
+            ),
+            FactorSourceCommon::new(
+                FactorSourceCryptoParameters::babylon(),
+                [],
+            ),
+            LedgerHardwareWalletHint::new(name, device_info.model),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn device_from_factor_source() {
+        let factor_source = LedgerHardwareWalletFactorSource::placeholder();
+        assert_eq!(
+            LedgerDevice::from(&factor_source),
+            LedgerDevice::new(
+                Some("Orange, scratched".to_string()),
+                LedgerHardwareWalletModel::NanoSPlus,
+                FactorSourceIDFromHash::placeholder_ledger().body
+            )
+        );
+    }
+
+    #[test]
+    fn factor_source_from_device_info() {
+        let sut = LedgerHardwareWalletFactorSource::from_device_info(
+            LedgerDeviceInfo::new(
+                LedgerHardwareWalletModel::NanoX,
+                FactorSourceIDFromHash::placeholder_ledger().body,
+            ),
+            "Black",
+        );
+        assert_eq!(sut.id, FactorSourceIDFromHash::placeholder_ledger());
+        assert_eq!(
+            sut.hint,
+            LedgerHardwareWalletHint::new(
+                "Black",
+                LedgerHardwareWalletModel::NanoX
+            )
+        );
+        assert!(sut.common.supports_babylon());
+        assert!(sut.common.flags.is_empty());
+    }
+
+    #[test]
+    fn device_info_json_roundtrip() {
+        let sut = LedgerDeviceInfo::new(
+            LedgerHardwareWalletModel::NanoSPlus,
+            FactorSourceIDFromHash::placeholder_ledger().body,
+        );
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "model": "nanoS+",
+                "id": "3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn device_json_roundtrip() {
+        let sut = LedgerDevice::from(
+            &LedgerHardwareWalletFactorSource::placeholder(),
+        );
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "name": "Orange, scratched",
+                "model": "nanoS+",
+                "id": "3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+            }
+            "#,
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+
+/// Used to interact with Ledger hardware wallets running the Radix Ledger
+/// app, the transport - e.g. USB, Bluetooth or the Radix Connector
+/// Extension - is provided by the Wallet Client.
+///
+/// Each request identifies the Ledger device it is intended for, implementers
+/// SHOULD fail with `LedgerDeviceMismatch` if another device is connected.
+#[uniffi::export]
+pub trait LedgerHardwareWalletClient: Send + Sync + std::fmt::Debug {
+    /// Reads the model and ID of the connected Ledger device.
+    fn get_device_info(&self) -> Result<LedgerDeviceInfo>;
+
+    /// Derives the public keys of the requested keys, in order.
+    fn derive_public_keys(
+        &self,
+        request: LedgerDerivePublicKeysRequest,
+    ) -> Result<Vec<HierarchicalDeterministicPublicKey>>;
+
+    /// Signs the transaction with the keys of the requested signers, in order.
+    fn sign_transaction(
+        &self,
+        request: LedgerSignTransactionRequest,
+    ) -> Result<Vec<LedgerSignature>>;
+
+    /// Signs the auth challenge with the keys of the requested signers, in
+    /// order.
+    fn sign_auth(
+        &self,
+        request: LedgerSignAuthRequest,
+    ) -> Result<Vec<LedgerSignature>>;
+
+    /// Derives the requested key and displays its address on the device.
+    fn derive_and_display_address(
+        &self,
+        request: LedgerDeriveAndDisplayAddressRequest,
+    ) -> Result<LedgerDerivedAddress>;
+}
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+/// The curve and derivation path of a key the Ledger device should derive
+/// or sign with.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerKeyParameters {
+    /// The curve of the key, always the curve of the scheme of
+    /// `derivation_path`.
+    pub curve: SLIP10Curve,
+
+    /// The HD derivation path of the key.
+    pub derivation_path: DerivationPath,
+}
+
+impl From<DerivationPath> for LedgerKeyParameters {
+    fn from(value: DerivationPath) -> Self {
+        Self {
+            curve: value.scheme().curve(),
+            derivation_path: value,
+        }
+    }
+}
+
+impl HasPlaceholder for LedgerKeyParameters {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        DerivationPath::placeholder().into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        DerivationPath::placeholder_other().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            LedgerKeyParameters::placeholder(),
+            LedgerKeyParameters::placeholder()
+        );
+        assert_eq!(
+            LedgerKeyParameters::placeholder_other(),
+            LedgerKeyParameters::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            LedgerKeyParameters::placeholder(),
+            LedgerKeyParameters::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn curve_from_cap26_path() {
+        assert_eq!(
+            LedgerKeyParameters::placeholder().curve,
+            SLIP10Curve::Curve25519
+        );
+    }
+
+    #[test]
+    fn curve_from_bip44_path() {
+        let path: DerivationPath = BIP44LikePath::placeholder().into();
+        assert_eq!(
+            LedgerKeyParameters::from(path).curve,
+            SLIP10Curve::Secp256k1
+        );
+    }
+}
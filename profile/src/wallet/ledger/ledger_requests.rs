@@ -0,0 +1,164 @@
+use crate::prelude::*;
+
+/// Request to derive the public keys at the derivation paths in
+/// `keys_parameters` using the Ledger device `ledger_device`.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDerivePublicKeysRequest {
+    pub ledger_device: LedgerDevice,
+    pub keys_parameters: Vec<LedgerKeyParameters>,
+}
+
+impl LedgerDerivePublicKeysRequest {
+    pub fn new<I>(ledger_device: LedgerDevice, derivation_paths: I) -> Self
+    where
+        I: IntoIterator<Item = DerivationPath>,
+    {
+        Self {
+            ledger_device,
+            keys_parameters: derivation_paths
+                .into_iter()
+                .map(LedgerKeyParameters::from)
+                .collect(),
+        }
+    }
+}
+
+/// How much of a transaction the Radix Ledger app displays to the user
+/// before signing it.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    uniffi::Enum,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum LedgerTransactionSigningMode {
+    /// Every instruction of the transaction manifest is displayed.
+    Verbose,
+
+    /// Only a summary of the transaction is displayed.
+    SummaryOnly,
+}
+
+/// Request to sign the compiled transaction intent with the keys of
+/// `signers` using the Ledger device `ledger_device`.
+#[serde_as]
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerSignTransactionRequest {
+    pub ledger_device: LedgerDevice,
+    pub signers: Vec<LedgerKeyParameters>,
+
+    /// The compiled transaction intent, which the Ledger device decodes in
+    /// order to display it to the user.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub compiled_transaction_intent: Vec<u8>,
+
+    /// If the Ledger device should display the hash of the transaction.
+    pub display_hash: bool,
+    pub mode: LedgerTransactionSigningMode,
+}
+
+/// Request to sign the authentication `challenge` of the dApp with
+/// `dapp_definition_address` at `origin`, with the keys of `signers` using
+/// the Ledger device `ledger_device`.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerSignAuthRequest {
+    pub ledger_device: LedgerDevice,
+    pub signers: Vec<LedgerKeyParameters>,
+    pub challenge: Hex32Bytes,
+    pub origin: Url,
+    pub dapp_definition_address: AccountAddress,
+}
+
+/// Request to derive the key at `key_parameters` using the Ledger device
+/// `ledger_device` and display the address of it on the device, allowing
+/// the user to verify the address shown by the wallet.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDeriveAndDisplayAddressRequest {
+    pub ledger_device: LedgerDevice,
+    pub key_parameters: LedgerKeyParameters,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn derive_public_keys_request_key_parameters() {
+        let sut = LedgerDerivePublicKeysRequest::new(
+            LedgerDevice::from(&LedgerHardwareWalletFactorSource::placeholder()),
+            [
+                DerivationPath::placeholder(),
+                DerivationPath::placeholder_other(),
+            ],
+        );
+        assert_eq!(
+            sut.keys_parameters,
+            vec![
+                LedgerKeyParameters::placeholder(),
+                LedgerKeyParameters::placeholder_other()
+            ]
+        );
+    }
+
+    #[test]
+    fn signing_mode_json() {
+        assert_json_value_eq_after_roundtrip(
+            &LedgerTransactionSigningMode::Verbose,
+            json!("verbose"),
+        );
+        assert_json_value_eq_after_roundtrip(
+            &LedgerTransactionSigningMode::SummaryOnly,
+            json!("summaryOnly"),
+        );
+    }
+
+    #[test]
+    fn sign_transaction_request_json_roundtrip() {
+        let sut = LedgerSignTransactionRequest {
+            ledger_device: LedgerDevice::from(
+                &LedgerHardwareWalletFactorSource::placeholder(),
+            ),
+            signers: vec![LedgerKeyParameters::placeholder()],
+            compiled_transaction_intent: vec![0xde, 0xad],
+            display_hash: true,
+            mode: LedgerTransactionSigningMode::Verbose,
+        };
+        assert_json_roundtrip(&sut);
+        assert_eq!(
+            serde_json::to_value(&sut).unwrap()["compiledTransactionIntent"],
+            json!("dead")
+        );
+    }
+
+    #[test]
+    fn sign_auth_request_json_roundtrip() {
+        let sut = LedgerSignAuthRequest {
+            ledger_device: LedgerDevice::from(
+                &LedgerHardwareWalletFactorSource::placeholder(),
+            ),
+            signers: vec![LedgerKeyParameters::placeholder()],
+            challenge: Hex32Bytes::placeholder(),
+            origin: Url::parse("https://dashboard.radixdlt.com").unwrap(),
+            dapp_definition_address: AccountAddress::placeholder(),
+        };
+        assert_json_roundtrip(&sut);
+    }
+}
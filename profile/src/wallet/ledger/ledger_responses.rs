@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+/// A signature produced by the Ledger device, together with the public key
+/// and derivation path of the key which produced it.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerSignature {
+    pub derived_public_key: HierarchicalDeterministicPublicKey,
+    pub signature: Signature,
+}
+
+impl LedgerSignature {
+    pub fn new(
+        derived_public_key: HierarchicalDeterministicPublicKey,
+        signature: Signature,
+    ) -> Self {
+        Self {
+            derived_public_key,
+            signature,
+        }
+    }
+
+    /// Returns the signature together with the public key, failing if they
+    /// are not on the same curve.
+    pub fn signature_with_public_key(&self) -> Result<SignatureWithPublicKey> {
+        SignatureWithPublicKey::new(
+            self.derived_public_key.public_key.clone(),
+            self.signature.clone(),
+        )
+    }
+}
+
+/// The key derived by the Ledger device and the address of it, as displayed
+/// on the device.
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, uniffi::Record,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDerivedAddress {
+    pub derived_key: HierarchicalDeterministicPublicKey,
+    pub address: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn signature_with_public_key() {
+        let private_key = MnemonicWithPassphrase::placeholder()
+            .derive_private_key(DerivationPath::placeholder());
+        let signature = private_key.sign(&hash("Test"));
+        let sut = LedgerSignature::new(
+            private_key.public_key(),
+            signature.signature(),
+        );
+        assert_eq!(sut.signature_with_public_key(), Ok(signature));
+    }
+
+    #[test]
+    fn signature_with_public_key_curve_mismatch() {
+        let sut = LedgerSignature::new(
+            HierarchicalDeterministicPublicKey::placeholder(),
+            Signature::placeholder_other(),
+        );
+        assert_eq!(
+            sut.signature_with_public_key(),
+            Err(CommonError::SignatureWithPublicKeyCurveMismatch)
+        );
+    }
+}
@@ -0,0 +1,17 @@
+mod ledger_device;
+mod ledger_hardware_wallet_client;
+mod ledger_key_parameters;
+mod ledger_requests;
+mod ledger_responses;
+
+pub use ledger_device::*;
+pub use ledger_hardware_wallet_client::*;
+pub use ledger_key_parameters::*;
+pub use ledger_requests::*;
+pub use ledger_responses::*;
+
+#[cfg(test)]
+mod simulated_ledger_hardware_wallet;
+
+#[cfg(test)]
+pub use simulated_ledger_hardware_wallet::*;
@@ -0,0 +1,146 @@
+#![cfg(test)]
+use crate::prelude::*;
+
+use radix_engine_common::crypto::IsHash;
+
+/// Used for testing - an in-memory Ledger device deriving keys from and
+/// signing with `mnemonic_with_passphrase`.
+///
+/// Transactions are signed by signing the hash of the compiled transaction
+/// intent and auth challenges by signing the hash of the challenge.
+#[derive(Debug)]
+pub struct SimulatedLedgerHardwareWallet {
+    model: LedgerHardwareWalletModel,
+    mnemonic_with_passphrase: MnemonicWithPassphrase,
+}
+
+impl SimulatedLedgerHardwareWallet {
+    pub fn new(
+        model: LedgerHardwareWalletModel,
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+    ) -> Self {
+        Self {
+            model,
+            mnemonic_with_passphrase,
+        }
+    }
+
+    /// The body of the `FactorSourceIDFromHash` of this Ledger device.
+    pub fn id(&self) -> Hex32Bytes {
+        FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+            FactorSourceKind::LedgerHQHardwareWallet,
+            self.mnemonic_with_passphrase.clone(),
+        )
+        .body
+    }
+
+    fn validate_device(&self, ledger_device: &LedgerDevice) -> Result<()> {
+        if ledger_device.id != self.id() {
+            return Err(CommonError::LedgerDeviceMismatch);
+        }
+        Ok(())
+    }
+
+    fn derive(
+        &self,
+        key_parameters: &LedgerKeyParameters,
+    ) -> HierarchicalDeterministicPrivateKey {
+        self.mnemonic_with_passphrase
+            .derive_private_key(key_parameters.derivation_path.clone())
+    }
+
+    fn sign(
+        &self,
+        signers: &[LedgerKeyParameters],
+        msg_hash: &impl IsHash,
+    ) -> Vec<LedgerSignature> {
+        signers
+            .iter()
+            .map(|k| {
+                let private_key = self.derive(k);
+                LedgerSignature::new(
+                    private_key.public_key(),
+                    private_key.sign(msg_hash).signature(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl HasPlaceholder for SimulatedLedgerHardwareWallet {
+    /// The Ledger device of `LedgerHardwareWalletFactorSource::placeholder()`.
+    fn placeholder() -> Self {
+        Self::new(
+            LedgerHardwareWalletModel::NanoSPlus,
+            MnemonicWithPassphrase::placeholder(),
+        )
+    }
+
+    /// A Ledger device not present in `Profile::placeholder()`.
+    fn placeholder_other() -> Self {
+        Self::new(
+            LedgerHardwareWalletModel::NanoX,
+            MnemonicWithPassphrase::placeholder_other(),
+        )
+    }
+}
+
+impl LedgerHardwareWalletClient for SimulatedLedgerHardwareWallet {
+    fn get_device_info(&self) -> Result<LedgerDeviceInfo> {
+        Ok(LedgerDeviceInfo::new(self.model, self.id()))
+    }
+
+    fn derive_public_keys(
+        &self,
+        request: LedgerDerivePublicKeysRequest,
+    ) -> Result<Vec<HierarchicalDeterministicPublicKey>> {
+        self.validate_device(&request.ledger_device)?;
+        Ok(request
+            .keys_parameters
+            .iter()
+            .map(|k| self.derive(k).public_key())
+            .collect())
+    }
+
+    fn sign_transaction(
+        &self,
+        request: LedgerSignTransactionRequest,
+    ) -> Result<Vec<LedgerSignature>> {
+        self.validate_device(&request.ledger_device)?;
+        Ok(self.sign(
+            &request.signers,
+            &hash(&request.compiled_transaction_intent),
+        ))
+    }
+
+    fn sign_auth(
+        &self,
+        request: LedgerSignAuthRequest,
+    ) -> Result<Vec<LedgerSignature>> {
+        self.validate_device(&request.ledger_device)?;
+        Ok(self.sign(&request.signers, &hash(request.challenge.bytes())))
+    }
+
+    fn derive_and_display_address(
+        &self,
+        request: LedgerDeriveAndDisplayAddressRequest,
+    ) -> Result<LedgerDerivedAddress> {
+        self.validate_device(&request.ledger_device)?;
+        let account_path = request
+            .key_parameters
+            .derivation_path
+            .as_cap26()
+            .and_then(|p| p.as_account_path())
+            .cloned()
+            .ok_or(CommonError::Unknown)?;
+        let derived_key = self.derive(&request.key_parameters).public_key();
+        let address = AccountAddress::new(
+            derived_key.public_key.clone(),
+            account_path.network_id(),
+        );
+        Ok(LedgerDerivedAddress {
+            derived_key,
+            address: address.to_string(),
+        })
+    }
+}
@@ -1,9 +1,33 @@
+mod account_recovery;
+mod ledger;
 mod secure_storage;
 mod wallet;
+mod wallet_account_recovery;
 mod wallet_accounts;
+mod wallet_authorized_dapps;
+mod wallet_gateways;
+mod wallet_ledger;
+mod wallet_olympia;
+mod wallet_p2p_links;
+mod wallet_personas;
 mod wallet_profile_io;
+mod wallet_profiles;
+mod wallet_security_structures;
+mod wallet_signing;
 
+pub use account_recovery::*;
+pub use ledger::*;
 pub use secure_storage::*;
 pub use wallet::*;
+pub use wallet_account_recovery::*;
 pub use wallet_accounts::*;
+pub use wallet_authorized_dapps::*;
+pub use wallet_gateways::*;
+pub use wallet_ledger::*;
+pub use wallet_olympia::*;
+pub use wallet_p2p_links::*;
+pub use wallet_personas::*;
 pub use wallet_profile_io::*;
+pub use wallet_profiles::*;
+pub use wallet_security_structures::*;
+pub use wallet_signing::*;
@@ -97,6 +97,38 @@ impl WalletClientStorage {
     }
 }
 
+//======
+// Load Profile
+//======
+impl WalletClientStorage {
+    /// Loads the Profile snapshot with `profile_id`, migrating it from an
+    /// older snapshot version if needed.
+    ///
+    /// Returns Err if no snapshot was found, if failed to load bytes or if
+    /// failed to decode the snapshot into a `Profile`.
+    pub fn load_profile_with_id(
+        &self,
+        profile_id: &ProfileID,
+    ) -> Result<Profile> {
+        self.interface
+            .load_data(SecureStorageKey::ProfileSnapshot {
+                profile_id: profile_id.clone(),
+            })?
+            .ok_or(CommonError::ProfileSnapshotNotFound(profile_id.clone()))
+            .and_then(|j| Profile::from_json_bytes(&j))
+    }
+}
+
+//======
+// Delete
+//======
+impl WalletClientStorage {
+    /// Deletes the bytes saved under `key` in SecureStorage, if any.
+    pub fn delete(&self, key: SecureStorageKey) -> Result<()> {
+        self.interface.delete_data_for_key(key)
+    }
+}
+
 //======
 // Mnemonic CR(U)D
 //======
@@ -133,11 +165,9 @@ impl WalletClientStorage {
 
     /// Deletes a MnemonicWithPassphrase with a `FactorSourceIDFromHash`
     pub fn delete_mnemonic(&self, id: &FactorSourceIDFromHash) -> Result<()> {
-        self.interface.delete_data_for_key(
-            SecureStorageKey::DeviceFactorSourceMnemonic {
-                factor_source_id: id.clone(),
-            },
-        )
+        self.delete(SecureStorageKey::DeviceFactorSourceMnemonic {
+            factor_source_id: id.clone(),
+        })
     }
 }
 
@@ -45,16 +45,9 @@ impl Wallet {
         profile_id: ProfileID,
         wallet_client_storage: WalletClientStorage,
     ) -> Result<Self> {
-        // Form storage key
-        let profile_key = SecureStorageKey::ProfileSnapshot {
-            profile_id: profile_id.clone(),
-        };
-
-        // Load Profile from storage with key
-        let profile: Profile = wallet_client_storage.load_or(
-            profile_key,
-            CommonError::ProfileSnapshotNotFound(profile_id.clone()),
-        )?;
+        // Load Profile from storage, migrating it if needed
+        let profile =
+            wallet_client_storage.load_profile_with_id(&profile_id)?;
 
         // Create wallet
         let wallet = Self {
@@ -128,6 +121,31 @@ impl Wallet {
         Self::with_imported_profile(profile, secure_storage)
     }
 
+    /// Creates wallet by *importing* a password encrypted Profile backup,
+    /// as exported by `export_encrypted_backup`.
+    ///
+    /// Returns `Err` if `password` is wrong or if the version or any scheme
+    /// of the encrypted backup is not supported.
+    #[uniffi::constructor]
+    pub fn by_importing_encrypted_profile(
+        encrypted_profile_bytes: Vec<u8>,
+        password: String,
+        secure_storage: Arc<dyn SecureStorage>,
+    ) -> Result<Self> {
+        Wallet::init_logging();
+
+        let profile =
+            EncryptedProfileSnapshot::from_json_bytes(&encrypted_profile_bytes)
+                .and_then(|e| e.decrypt(&password))?;
+
+        log::info!(
+            "Instantiating Wallet by importing an encrypted Profile with ID: {}",
+            profile.id()
+        );
+
+        Ok(Self::with_imported_profile(profile, secure_storage))
+    }
+
     #[uniffi::constructor]
     pub fn by_loading_profile(
         secure_storage: Arc<dyn SecureStorage>,
@@ -180,6 +198,30 @@ impl Wallet {
             storage,
         )
     }
+
+    /// An ephemeral wallet with the mnemonic of the BDFS of `profile` saved
+    /// in secure storage, `profile`'s BDFS MUST equal
+    /// `PrivateHierarchicalDeterministicFactorSource::placeholder()`.
+    pub(crate) fn ephemeral_with_bdfs_mnemonic_saved(
+        profile: Profile,
+    ) -> (Self, Arc<EphemeralSecureStorage>) {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        assert_eq!(
+            profile.bdfs().factor_source_id(),
+            private.factor_source.factor_source_id()
+        );
+
+        let (wallet, storage) = Self::ephemeral(profile);
+
+        let data =
+            serde_json::to_vec(&private.mnemonic_with_passphrase).unwrap();
+        let key = SecureStorageKey::DeviceFactorSourceMnemonic {
+            factor_source_id: private.factor_source.id.clone(),
+        };
+        assert!(storage.save_data(key, data).is_ok());
+        (wallet, storage)
+    }
 }
 #[cfg(test)]
 impl HasPlaceholder for Wallet {
@@ -203,6 +245,13 @@ impl Wallet {
             .expect("Should always be able to JSON serialize a Profile.")
     }
 
+    /// Encrypts a snapshot of the profile with a key derived from `password`
+    /// and returns the encrypted backup serialized as JSON bytes.
+    pub fn export_encrypted_backup(&self, password: String) -> Vec<u8> {
+        EncryptedProfileSnapshot::encrypting(&self.profile(), &password)
+            .to_json_bytes()
+    }
+
     /// Clone the profile and return it.
     pub fn profile(&self) -> Profile {
         self.read(|p| p.clone())
@@ -339,6 +388,35 @@ mod uniffi_tests {
         assert_eq!(wallet.json_snapshot(), expected_json);
     }
 
+    #[test]
+    fn by_importing_encrypted_profile() {
+        let profile = Profile::placeholder();
+        let bytes = Wallet::placeholder()
+            .export_encrypted_backup("babylon".to_string());
+        let wallet = Wallet::by_importing_encrypted_profile(
+            bytes,
+            "babylon".to_string(),
+            EphemeralSecureStorage::new(),
+        )
+        .unwrap();
+        assert_eq!(wallet.profile(), profile);
+    }
+
+    #[test]
+    fn by_importing_encrypted_profile_wrong_password() {
+        let bytes = Wallet::placeholder()
+            .export_encrypted_backup("babylon".to_string());
+        assert_eq!(
+            Wallet::by_importing_encrypted_profile(
+                bytes,
+                "olympia".to_string(),
+                EphemeralSecureStorage::new(),
+            )
+            .map(|w| w.profile()),
+            Err(CommonError::InvalidPasswordForEncryptedProfileSnapshot)
+        );
+    }
+
     #[test]
     fn by_creating_new_profile_and_secrets_with_entropy() {
         let secure_storage = EphemeralSecureStorage::new();
@@ -0,0 +1,128 @@
+use crate::prelude::*;
+
+//========
+// RECOVER - Accounts
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Scans `network_id` for accounts controlled by `mnemonic_with_passphrase`
+    /// which are active on ledger according to `activity_checker`, skipping
+    /// accounts already in Profile.
+    ///
+    /// If Profile already contains the `DeviceFactorSource` of the mnemonic
+    /// it is used, otherwise a new non main "Babylon" `DeviceFactorSource` is
+    /// returned, which should be added to Profile together with the accounts.
+    ///
+    /// Returns `Err` if `activity_checker` fails.
+    pub fn scan_accounts_for_recovery(
+        &self,
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+        network_id: NetworkID,
+        activity_checker: Arc<dyn AccountActivityChecker>,
+    ) -> Result<AccountRecoveryScanOutcome> {
+        let profile = self.profile();
+        let id = FactorSourceIDFromHash::new_for_device(
+            mnemonic_with_passphrase.clone(),
+        );
+        let factor_source =
+            profile.device_factor_source_by_id(&id).unwrap_or_else(|_| {
+                DeviceFactorSource::babylon(
+                    false,
+                    mnemonic_with_passphrase.clone(),
+                    WalletClientModel::Unknown,
+                )
+            });
+        let number_of_accounts_on_network = profile
+            .networks
+            .get(&network_id)
+            .map(|n| n.accounts.len())
+            .unwrap_or(0);
+
+        let factor_instances =
+            PrivateHierarchicalDeterministicFactorSource::new(
+                mnemonic_with_passphrase,
+                factor_source.clone(),
+            )
+            .scan_for_active_accounts(network_id, activity_checker.as_ref())?
+            .into_iter()
+            .filter(|fi| {
+                let address =
+                AccountAddress::from_hd_factor_instance_virtual_entity_creation(
+                    fi.clone(),
+                );
+                profile.networks.get_account(&address).is_none()
+            })
+            .collect_vec();
+
+        Ok(AccountRecoveryScanOutcome::new(
+            factor_source,
+            factor_instances,
+            number_of_accounts_on_network,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn checker(indices: &[HDPathValue]) -> Arc<dyn AccountActivityChecker> {
+        Arc::new(StubActivityChecker::new(NetworkID::Nebunet, indices))
+    }
+
+    #[test]
+    fn uses_factor_source_in_profile() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let sut = wallet
+            .scan_accounts_for_recovery(
+                MnemonicWithPassphrase::placeholder(),
+                NetworkID::Nebunet,
+                checker(&[0]),
+            )
+            .unwrap();
+        assert_eq!(
+            sut.factor_source,
+            DeviceFactorSource::placeholder_babylon()
+        );
+        assert_eq!(sut.accounts.len(), 1);
+    }
+
+    #[test]
+    fn new_factor_source_if_not_in_profile() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let mwp = MnemonicWithPassphrase::placeholder_other();
+        let sut = wallet
+            .scan_accounts_for_recovery(
+                mwp.clone(),
+                NetworkID::Nebunet,
+                checker(&[]),
+            )
+            .unwrap();
+        assert_eq!(
+            sut.factor_source.id,
+            FactorSourceIDFromHash::new_for_device(mwp)
+        );
+        assert!(!sut.factor_source.is_main_bdfs());
+        assert!(sut.accounts.is_empty());
+    }
+
+    #[test]
+    fn accounts_already_in_profile_are_skipped() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let scan = || {
+            wallet
+                .scan_accounts_for_recovery(
+                    MnemonicWithPassphrase::placeholder(),
+                    NetworkID::Nebunet,
+                    checker(&[0, 1]),
+                )
+                .unwrap()
+        };
+        let first = scan();
+        assert_eq!(first.accounts.len(), 2);
+        wallet.add_account(first.accounts[0].clone()).unwrap();
+
+        let second = scan();
+        assert_eq!(second.accounts, vec![first.accounts[1].clone()]);
+    }
+}
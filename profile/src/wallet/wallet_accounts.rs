@@ -132,60 +132,178 @@ impl Wallet {
         &self,
         network_id: NetworkID,
         name: DisplayName,
+    ) -> Result<Account> {
+        let bdfs = self.profile().bdfs();
+        self.create_new_account_with_factor_source(
+            bdfs.factor_source_id(),
+            network_id,
+            name,
+        )
+    }
+
+    /// Creates a new non securified account **WITHOUT** add it to Profile, using the
+    /// `DeviceFactorSource` with `factor_source_id` and the "next" index for this
+    /// FactorSource as derivation path.
+    ///
+    /// If you want to add it to Profile, call `wallet.add_account(account)`
+    ///
+    /// Returns `Err` if Profile does not contain a factor source with
+    /// `factor_source_id`, if it cannot create entities, if it does not
+    /// support the curve of account keys, if it is a Ledger - use
+    /// `create_new_account_with_ledger` for those - or if loading of the
+    /// mnemonic from SecureStorage fails.
+    pub fn create_new_account_with_factor_source(
+        &self,
+        factor_source_id: FactorSourceID,
+        network_id: NetworkID,
+        name: DisplayName,
     ) -> Result<Account> {
         let profile = &self.profile();
-        let bdfs = profile.bdfs();
-        let index = profile
-            .next_derivation_index_for_entity(EntityKind::Accounts, network_id);
-        let number_of_accounts_on_network = profile
-            .networks
-            .get(&network_id)
-            .map(|n| n.accounts.len())
-            .unwrap_or(0);
+        let device_factor_source =
+            validate_entity_creating_factor_source(profile, &factor_source_id)?
+                .into_device()
+                .map_err(|_| {
+                    CommonError::LedgerHardwareWalletClientRequired(
+                        factor_source_id.clone(),
+                    )
+                })?;
 
-        let appearance_id = AppearanceID::from_number_of_accounts_on_network(
-            number_of_accounts_on_network,
+        let index = profile.next_derivation_index_for_entity_for_factor_source(
+            EntityKind::Accounts,
+            network_id,
+            device_factor_source.id.clone(),
         );
 
-        let factor_instance =
-            self.load_private_device_factor_source(&bdfs).map(|p| {
+        let factor_instance = self
+            .load_private_device_factor_source(&device_factor_source)
+            .map(|p| {
                 p.derive_entity_creation_factor_instance(network_id, index)
             })?;
 
-        let account = Account::new(factor_instance, name, appearance_id);
+        let account = Account::new(
+            factor_instance,
+            name,
+            AppearanceID::from_number_of_accounts_on_network(
+                number_of_accounts_on_network(profile, network_id),
+            ),
+        );
 
         Ok(account)
     }
 
+    /// Creates a new non securified account **WITHOUT** add it to Profile, using the
+    /// `LedgerHardwareWalletFactorSource` with `factor_source_id`, connected through
+    /// `ledger_client`, and the "next" index for this FactorSource as derivation path.
+    ///
+    /// If you want to add it to Profile, call `wallet.add_account(account)`
+    ///
+    /// Returns `Err` if Profile does not contain a Ledger with
+    /// `factor_source_id`, if it does not support the curve of account keys
+    /// or if deriving the key using `ledger_client` fails.
+    pub fn create_new_account_with_ledger(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        network_id: NetworkID,
+        name: DisplayName,
+        ledger_client: Arc<dyn LedgerHardwareWalletClient>,
+    ) -> Result<Account> {
+        validate_entity_creating_factor_source(
+            &self.profile(),
+            &factor_source_id.clone().into(),
+        )?;
+
+        let factor_instance = self
+            .derive_account_creation_factor_instances_with_ledger(
+                factor_source_id,
+                network_id,
+                1,
+                ledger_client,
+            )?
+            .into_iter()
+            .next()
+            .ok_or(CommonError::UnexpectedLedgerResponse)
+            .and_then(HDFactorInstanceAccountCreation::new)?;
+
+        Ok(Account::new(
+            factor_instance,
+            name,
+            AppearanceID::from_number_of_accounts_on_network(
+                number_of_accounts_on_network(&self.profile(), network_id),
+            ),
+        ))
+    }
+
+    /// Creates the bech32 address of the account at `index` on the network
+    /// with the raw ID `network_id`, which might be a custom network added
+    /// with `add_custom_network`, controlled by the *main* "Babylon"
+    /// `DeviceFactorSource`.
+    ///
+    /// Profile only contains accounts on networks known to this library, so
+    /// for custom networks only the address is created, use
+    /// `create_new_account` for known networks.
+    ///
+    /// Returns `Err` if the network is neither known nor added with
+    /// `add_custom_network`, or if loading of the mnemonic from SecureStorage
+    /// fails.
+    pub fn new_account_address_on_network(
+        &self,
+        network_id: u8,
+        index: HDPathValue,
+    ) -> Result<String> {
+        let profile = self.profile();
+        _ = profile.network_definition(network_id)?;
+        let private =
+            self.load_private_device_factor_source(&profile.bdfs())?;
+        profile.account_address_on_network(
+            private.derive_account_public_key_on_network(network_id, index),
+            network_id,
+        )
+    }
+
     /// Returns `Ok(())` if the `account` was new and successfully added. If saving failed or if the account was already present in Profile, an
     /// error is returned.
     pub fn add_account(&self, account: Account) -> Result<()> {
-        // TODO: clean this up, BAD code. messy, mostly because of (my) bad IdentifiedVec API.
-        let network_id = account.network_id;
-        let err_exists =
-            CommonError::AccountAlreadyPresent(account.id().clone());
+        self.add_accounts(vec![account])
+    }
+
+    /// Adds all `accounts` to Profile, which is saved to SecureStorage once.
+    ///
+    /// Returns `Err` without adding any of the accounts if any of them is
+    /// already present in Profile or present more than once in `accounts`,
+    /// or if saving failed.
+    pub fn add_accounts(&self, accounts: Vec<Account>) -> Result<()> {
         self.try_write(|mut p| {
-            let networks = &mut p.networks;
-            if networks.contains_id(&network_id) {
-                networks
-                    .try_update_with(&network_id, |network| {
-                        if network.accounts.append(account.clone()).0 {
-                            Ok(network.clone())
-                        } else {
-                            Err(err_exists.clone())
-                        }
-                    })
-                    .and_then(
-                        |r| if r { Ok(()) } else { Err(err_exists.clone()) },
-                    )
-            } else {
-                let network = Network::new(
-                    network_id,
-                    Accounts::from_iter([account.to_owned()]),
-                );
-                networks.append(network);
-                Ok(())
+            if let Some(address) = accounts
+                .iter()
+                .map(|a| a.address.clone())
+                .duplicates()
+                .next()
+                .or_else(|| {
+                    accounts
+                        .iter()
+                        .map(|a| a.address.clone())
+                        .find(|a| p.networks.get_account(a).is_some())
+                })
+            {
+                return Err(CommonError::AccountAlreadyPresent(address));
             }
+
+            accounts.iter().for_each(|account| {
+                let network_id = account.network_id;
+                if p.networks.contains_id(&network_id) {
+                    _ = p.networks.update_with(&network_id, |n| {
+                        _ = n.accounts.append(account.clone())
+                    });
+                } else {
+                    p.networks.append(Network::new(
+                        network_id,
+                        Accounts::from_iter([account.clone()]),
+                        Personas::default(),
+                        AuthorizedDapps::default(),
+                    ));
+                }
+            });
+            Ok(())
         })
     }
 
@@ -200,6 +318,63 @@ impl Wallet {
         Ok(account)
     }
 
+    /// Creates `count` new non securified accounts on `network_id` using the *main*
+    /// "Babylon" `DeviceFactorSource`, named `"{name_prefix} {n}"` for `n` in
+    /// `1...count`, and adds them to Profile.
+    ///
+    /// The mnemonic is loaded from SecureStorage once and Profile is saved once. Holes
+    /// among the derivation indices already used by the `DeviceFactorSource` on
+    /// `network_id`, e.g. from accounts imported out of order, are either filled or
+    /// skipped according to `gap_strategy`.
+    ///
+    /// Returns `Err` if a name is invalid, if loading of the mnemonic from
+    /// SecureStorage fails or if saving Profile fails.
+    pub fn create_and_save_new_accounts(
+        &self,
+        network_id: NetworkID,
+        count: u16,
+        name_prefix: String,
+        gap_strategy: DerivationIndexGapStrategy,
+    ) -> Result<Vec<Account>> {
+        let profile = &self.profile();
+        let bdfs = profile.bdfs();
+        let indices = profile
+            .next_derivation_indices_for_entity_for_factor_source(
+                EntityKind::Accounts,
+                network_id,
+                &bdfs.id,
+                count as usize,
+                gap_strategy,
+            );
+        let number_of_accounts_on_network =
+            number_of_accounts_on_network(profile, network_id);
+
+        let private = self.load_private_device_factor_source(&bdfs)?;
+        let accounts = indices
+            .into_iter()
+            .enumerate()
+            .map(|(offset, index)| {
+                DisplayName::new(
+                    format!("{} {}", name_prefix, offset + 1).as_str(),
+                )
+                .map(|name| {
+                    Account::new(
+                        private.derive_entity_creation_factor_instance(
+                            network_id, index,
+                        ),
+                        name,
+                        AppearanceID::from_number_of_accounts_on_network(
+                            number_of_accounts_on_network + offset,
+                        ),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.add_accounts(accounts.clone())?;
+        Ok(accounts)
+    }
+
     /// Updates the display name of account with the provided address, throws an error if the account is unknown to the wallet.
     pub fn change_name_of_account(
         &self,
@@ -211,6 +386,178 @@ impl Wallet {
         })
         .ok_or(CommonError::UnknownAccount)
     }
+
+    /// Marks the account with the provided address as hidden by the user, the
+    /// account is still kept in Profile. Throws an error if the account is unknown
+    /// to the wallet.
+    ///
+    /// Any reference to the account in the shared accounts of the authorized
+    /// dApps on its network is removed.
+    pub fn hide_account(&self, address: AccountAddress) -> Result<Account> {
+        self.write(|mut p| {
+            let account = p.update_account(&address, |a| {
+                _ = a.flags.insert_flag(EntityFlag::DeletedByUser)
+            })?;
+            _ = p.networks.update_with(&address.network_id, |n| {
+                n.remove_references_to_account(&address)
+            });
+            Some(account)
+        })
+        .ok_or(CommonError::UnknownAccount)
+    }
+
+    /// Makes the account with the provided address visible again, throws an
+    /// error if the account is unknown to the wallet.
+    ///
+    /// References to the account in authorized dApps removed when it was
+    /// hidden are not restored.
+    pub fn unhide_account(&self, address: AccountAddress) -> Result<Account> {
+        self.write(|mut p| {
+            p.update_account(&address, |a| {
+                _ = a.flags.remove_flag(&EntityFlag::DeletedByUser)
+            })
+        })
+        .ok_or(CommonError::UnknownAccount)
+    }
+
+    /// Reorders the accounts on `network_id` to follow the order of
+    /// `addresses`, which must contain the address of every account on the
+    /// network, including hidden ones, exactly once.
+    ///
+    /// Returns `Err` if any address is unknown, if the list is not a
+    /// permutation of the accounts on the network or if the network is unknown.
+    pub fn reorder_accounts(
+        &self,
+        network_id: NetworkID,
+        addresses: Vec<AccountAddress>,
+    ) -> Result<()> {
+        self.try_write(|mut p| {
+            update_accounts_on_network(&mut p, network_id, |accounts| {
+                accounts.reorder(&addresses)
+            })
+            .and_then(|r| {
+                if r {
+                    Ok(())
+                } else {
+                    Err(CommonError::UnknownNetworkForID(
+                        network_id.discriminant(),
+                    ))
+                }
+            })
+        })
+    }
+
+    /// Moves the account with the provided address one step up in the list
+    /// of accounts on its network, does nothing if it already is the first.
+    pub fn move_account_up(&self, address: AccountAddress) -> Result<()> {
+        self.try_write(|mut p| {
+            update_accounts_on_network(&mut p, address.network_id, |accounts| {
+                accounts.move_up(&address)
+            })
+            .and_then(|r| r.then_some(()).ok_or(CommonError::UnknownAccount))
+        })
+    }
+
+    /// Moves the account with the provided address one step down in the list
+    /// of accounts on its network, does nothing if it already is the last.
+    pub fn move_account_down(&self, address: AccountAddress) -> Result<()> {
+        self.try_write(|mut p| {
+            update_accounts_on_network(&mut p, address.network_id, |accounts| {
+                accounts.move_down(&address)
+            })
+            .and_then(|r| r.then_some(()).ok_or(CommonError::UnknownAccount))
+        })
+    }
+}
+
+//========
+// GET - Accounts
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Returns the accounts on `network_id` which are not hidden by the user.
+    pub fn visible_accounts_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Account> {
+        self.read(|p| p.visible_accounts_on_network(network_id))
+    }
+
+    /// Returns the accounts on `network_id` which are hidden by the user.
+    pub fn hidden_accounts_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Account> {
+        self.read(|p| p.hidden_accounts_on_network(network_id))
+    }
+}
+
+/// Applies `update` to the accounts on `network_id`, returning `Ok(false)` if
+/// Profile does not contain the network.
+fn update_accounts_on_network<F>(
+    profile: &mut Profile,
+    network_id: NetworkID,
+    update: F,
+) -> Result<bool>
+where
+    F: Fn(&mut Accounts) -> Result<()>,
+{
+    profile.networks.try_update_with(&network_id, |network| {
+        update(&mut network.accounts)?;
+        Ok(network.clone())
+    })
+}
+
+/// Returns the factor source with `factor_source_id` if it can be used to
+/// create new accounts.
+///
+/// Returns `Err` if Profile does not contain it, if its kind cannot create
+/// entities or if it does not support the curve of account keys.
+fn validate_entity_creating_factor_source(
+    profile: &Profile,
+    factor_source_id: &FactorSourceID,
+) -> Result<FactorSource> {
+    let factor_source =
+        profile
+            .factor_sources
+            .get(factor_source_id)
+            .cloned()
+            .ok_or(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                factor_source_id.clone(),
+            ))?;
+
+    if !factor_source.factor_source_kind().is_entity_creating() {
+        return Err(CommonError::FactorSourceCannotCreateEntities(
+            factor_source_id.clone(),
+        ));
+    }
+
+    let curve = DerivationPathScheme::Cap26.curve();
+    if !factor_source
+        .common()
+        .crypto_parameters
+        .supported_curves
+        .contains(&curve)
+    {
+        return Err(CommonError::FactorSourceDoesNotSupportCurve {
+            factor_source_id: factor_source_id.clone(),
+            curve,
+        });
+    }
+
+    Ok(factor_source)
+}
+
+/// The number of accounts on `network_id`, including hidden ones.
+fn number_of_accounts_on_network(
+    profile: &Profile,
+    network_id: NetworkID,
+) -> usize {
+    profile
+        .networks
+        .get(&network_id)
+        .map(|n| n.accounts.len())
+        .unwrap_or(0)
 }
 
 #[cfg(test)]
@@ -251,6 +598,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hide_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let account = Account::placeholder_mainnet_alice();
+        assert!(wallet
+            .hide_account(account.address.clone())
+            .unwrap()
+            .flags
+            .contains(&EntityFlag::DeletedByUser));
+        wallet.read(|p| {
+            assert!(p.networks[0].accounts[0]
+                .flags
+                .contains(&EntityFlag::DeletedByUser))
+        });
+    }
+
+    #[test]
+    fn hide_account_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.hide_account(Account::placeholder_mainnet_carol().address),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn unhide_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice();
+        wallet.hide_account(alice.address.clone()).unwrap();
+        assert!(wallet
+            .unhide_account(alice.address.clone())
+            .unwrap()
+            .flags
+            .is_empty());
+        assert_eq!(
+            wallet.visible_accounts_on_network(NetworkID::Mainnet),
+            vec![alice, Account::placeholder_mainnet_bob()]
+        );
+        assert!(wallet
+            .hidden_accounts_on_network(NetworkID::Mainnet)
+            .is_empty());
+    }
+
+    #[test]
+    fn unhide_account_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.unhide_account(Account::placeholder_mainnet_carol().address),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn visible_and_hidden_accounts_on_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let bob = Account::placeholder_mainnet_bob();
+        wallet.hide_account(bob.address.clone()).unwrap();
+        assert_eq!(
+            wallet.visible_accounts_on_network(NetworkID::Mainnet),
+            vec![Account::placeholder_mainnet_alice()]
+        );
+        assert_eq!(
+            wallet
+                .hidden_accounts_on_network(NetworkID::Mainnet)
+                .into_iter()
+                .map(|a| a.address)
+                .collect_vec(),
+            vec![bob.address]
+        );
+    }
+
+    #[test]
+    fn new_account_address_on_custom_network() {
+        use radix_engine_toolkit::functions::address::decode;
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        assert_eq!(
+            wallet.new_account_address_on_network(0x30, 0),
+            Err(CommonError::UnknownNetworkID(0x30))
+        );
+        wallet
+            .add_custom_network(CustomNetworkDefinition::placeholder())
+            .unwrap();
+
+        let address = wallet.new_account_address_on_network(0x30, 0).unwrap();
+        assert!(address.starts_with("account_tdx_30_1"));
+        let (network_id, _, hrp, data) = decode(&address).unwrap();
+        assert_eq!(network_id, 0x30);
+        assert_eq!(hrp, "account_tdx_30_");
+
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let public_key = private.derive_account_public_key_on_network(0x30, 0);
+        assert_eq!(
+            data,
+            decode(
+                &AccountAddress::new(public_key, NetworkID::Mainnet).address
+            )
+            .unwrap()
+            .3
+        );
+    }
+
+    #[test]
+    fn new_account_address_on_known_network_matches_created_account() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let account = Account::new(
+            PrivateHierarchicalDeterministicFactorSource::placeholder()
+                .derive_entity_creation_factor_instance(NetworkID::Stokenet, 7),
+            DisplayName::default(),
+            AppearanceID::default(),
+        );
+        assert_eq!(
+            wallet.new_account_address_on_network(
+                NetworkID::Stokenet.discriminant(),
+                7
+            ),
+            Ok(account.address.address)
+        );
+    }
+
+    #[test]
+    fn hidden_accounts_still_count_for_appearance_id_and_content_hint() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        wallet
+            .hide_account(Account::placeholder_mainnet_bob().address)
+            .unwrap();
+        let account = wallet
+            .create_and_save_new_account(
+                NetworkID::Mainnet,
+                DisplayName::new("Carol").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(account.appearance_id, AppearanceID::new(2).unwrap());
+        assert_eq!(
+            wallet.read(|p| p
+                .networks
+                .content_hint()
+                .number_of_accounts_on_all_networks_in_total),
+            5
+        );
+    }
+
     #[test]
     fn load_private_device_factor_source() {
         let private =
@@ -326,7 +819,7 @@ mod tests {
                 &self,
                 _key: SecureStorageKey,
             ) -> Result<Option<Vec<u8>>> {
-                todo!()
+                Ok(None) // no headers list saved yet
             }
 
             fn save_data(
@@ -527,4 +1020,401 @@ mod tests {
             assert_eq!(q.networks[0].accounts[2], a);
         })
     }
+
+    fn name() -> DisplayName {
+        DisplayName::new("Satoshi").unwrap()
+    }
+
+    #[test]
+    fn create_new_account_with_non_main_device_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let mwp = MnemonicWithPassphrase::placeholder_other();
+        let private = PrivateHierarchicalDeterministicFactorSource::new(
+            mwp.clone(),
+            DeviceFactorSource::babylon(false, mwp, WalletClientModel::Unknown),
+        );
+        wallet
+            .add_private_device_factor_source(private.clone())
+            .unwrap();
+
+        let account = wallet
+            .create_new_account_with_factor_source(
+                private.factor_source.factor_source_id(),
+                NetworkID::Mainnet,
+                name(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            account,
+            Account::new(
+                private.derive_entity_creation_factor_instance(
+                    NetworkID::Mainnet,
+                    0
+                ),
+                name(),
+                AppearanceID::new(2).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn create_new_account_with_unknown_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_ledger_other().into();
+        assert_eq!(
+            wallet.create_new_account_with_factor_source(
+                id.clone(),
+                NetworkID::Mainnet,
+                name()
+            ),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(id))
+        );
+    }
+
+    #[test]
+    fn create_new_account_with_non_entity_creating_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let trusted_contact = FactorSource::placeholder_trusted_contact();
+        wallet.add_factor_source(trusted_contact.clone()).unwrap();
+        assert_eq!(
+            wallet.create_new_account_with_factor_source(
+                trusted_contact.factor_source_id(),
+                NetworkID::Mainnet,
+                name()
+            ),
+            Err(CommonError::FactorSourceCannotCreateEntities(
+                trusted_contact.factor_source_id()
+            ))
+        );
+    }
+
+    #[test]
+    fn create_new_account_with_factor_source_unsupported_curve() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let olympia =
+            PrivateHierarchicalDeterministicFactorSource::placeholder_other();
+        wallet
+            .add_private_device_factor_source(olympia.clone())
+            .unwrap();
+        assert_eq!(
+            wallet.create_new_account_with_factor_source(
+                olympia.factor_source.factor_source_id(),
+                NetworkID::Mainnet,
+                name()
+            ),
+            Err(CommonError::FactorSourceDoesNotSupportCurve {
+                factor_source_id: olympia.factor_source.factor_source_id(),
+                curve: SLIP10Curve::Curve25519
+            })
+        );
+    }
+
+    #[test]
+    fn create_new_account_with_factor_source_ledger_requires_client() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_ledger().into();
+        assert_eq!(
+            wallet.create_new_account_with_factor_source(
+                id.clone(),
+                NetworkID::Mainnet,
+                name()
+            ),
+            Err(CommonError::LedgerHardwareWalletClientRequired(id))
+        );
+    }
+
+    #[test]
+    fn create_new_account_with_ledger() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = FactorSourceIDFromHash::placeholder_ledger();
+        let account = wallet
+            .create_new_account_with_ledger(
+                id.clone(),
+                NetworkID::Mainnet,
+                name(),
+                Arc::new(SimulatedLedgerHardwareWallet::placeholder()),
+            )
+            .unwrap();
+
+        let public_key = MnemonicWithPassphrase::placeholder()
+            .derive_private_key(AccountPath::new(
+                NetworkID::Mainnet,
+                CAP26KeyKind::TransactionSigning,
+                0,
+            ))
+            .public_key();
+        assert_eq!(
+            account,
+            Account::new(
+                HDFactorInstanceAccountCreation::new(
+                    HierarchicalDeterministicFactorInstance::new(
+                        id, public_key
+                    )
+                )
+                .unwrap(),
+                name(),
+                AppearanceID::new(2).unwrap()
+            )
+        );
+    }
+
+    fn mainnet_account_addresses(wallet: &Wallet) -> Vec<AccountAddress> {
+        wallet.read(|p| {
+            p.networks[0]
+                .accounts
+                .iter()
+                .map(|a| a.address.clone())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn reorder_accounts() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        let bob = Account::placeholder_mainnet_bob().address;
+        wallet
+            .reorder_accounts(
+                NetworkID::Mainnet,
+                vec![bob.clone(), alice.clone()],
+            )
+            .unwrap();
+        assert_eq!(
+            mainnet_account_addresses(&wallet),
+            vec![bob.clone(), alice.clone()]
+        );
+
+        // order is persisted
+        let saved =
+            wallet.load_profile_with_id(&wallet.profile().id()).unwrap();
+        assert_eq!(saved, wallet.profile());
+        assert_eq!(
+            saved.networks[0]
+                .accounts
+                .iter()
+                .map(|a| a.address.clone())
+                .collect_vec(),
+            vec![bob, alice]
+        );
+    }
+
+    #[test]
+    fn reorder_accounts_unknown_account() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.reorder_accounts(
+                NetworkID::Mainnet,
+                vec![
+                    Account::placeholder_mainnet_carol().address,
+                    Account::placeholder_mainnet_alice().address,
+                ]
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
+
+    #[test]
+    fn reorder_accounts_not_a_permutation() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.reorder_accounts(
+                NetworkID::Mainnet,
+                vec![Account::placeholder_mainnet_bob().address]
+            ),
+            Err(CommonError::InvalidAccountsOrder)
+        );
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
+
+    #[test]
+    fn reorder_accounts_unknown_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.reorder_accounts(NetworkID::Nebunet, Vec::new()),
+            Err(CommonError::UnknownNetworkForID(
+                NetworkID::Nebunet.discriminant()
+            ))
+        );
+    }
+
+    #[test]
+    fn move_account_up_and_down() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        let bob = Account::placeholder_mainnet_bob().address;
+
+        wallet.move_account_up(bob.clone()).unwrap();
+        assert_eq!(
+            mainnet_account_addresses(&wallet),
+            vec![bob.clone(), alice.clone()]
+        );
+
+        wallet.move_account_down(bob.clone()).unwrap();
+        assert_eq!(mainnet_account_addresses(&wallet), vec![alice, bob]);
+    }
+
+    #[test]
+    fn move_account_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let carol = Account::placeholder_mainnet_carol().address;
+        assert_eq!(
+            wallet.move_account_up(carol),
+            Err(CommonError::UnknownAccount)
+        );
+        assert_eq!(
+            wallet.move_account_down(Account::placeholder_nebunet().address),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    fn profile_without_alice() -> Profile {
+        let mut profile = Profile::placeholder();
+        profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            _ = n
+                .accounts
+                .remove_by_id(&Account::placeholder_mainnet_alice().address)
+        });
+        profile
+    }
+
+    fn derivation_index(account: &Account) -> HDPathValue {
+        account
+            .security_state
+            .as_unsecured()
+            .unwrap()
+            .transaction_signing
+            .derivation_path()
+            .last_component()
+            .index()
+    }
+
+    #[test]
+    fn create_and_save_new_accounts() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let accounts = wallet
+            .create_and_save_new_accounts(
+                NetworkID::Mainnet,
+                3,
+                "Savings".to_string(),
+                DerivationIndexGapStrategy::Fill,
+            )
+            .unwrap();
+
+        assert_eq!(
+            accounts.iter().map(derivation_index).collect_vec(),
+            vec![2, 3, 4]
+        );
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|a| a.display_name.value.clone())
+                .collect_vec(),
+            vec!["Savings 1", "Savings 2", "Savings 3"]
+        );
+        assert_eq!(
+            accounts.iter().map(|a| a.appearance_id).collect_vec(),
+            [2, 3, 4]
+                .into_iter()
+                .map(AppearanceID::from_number_of_accounts_on_network)
+                .collect_vec()
+        );
+        wallet.read(|p| {
+            assert_eq!(p.networks[0].accounts.len(), 5);
+            assert_eq!(p.networks[0].accounts[4], accounts[2]);
+        });
+    }
+
+    #[test]
+    fn create_and_save_new_accounts_fill_gap() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(profile_without_alice());
+        let accounts = wallet
+            .create_and_save_new_accounts(
+                NetworkID::Mainnet,
+                2,
+                "Savings".to_string(),
+                DerivationIndexGapStrategy::Fill,
+            )
+            .unwrap();
+        assert_eq!(accounts[0], {
+            let mut alice = Account::placeholder_mainnet_alice();
+            alice.display_name = DisplayName::new("Savings 1").unwrap();
+            alice.appearance_id =
+                AppearanceID::from_number_of_accounts_on_network(1);
+            alice
+        });
+        assert_eq!(
+            accounts.iter().map(derivation_index).collect_vec(),
+            vec![0, 2]
+        );
+    }
+
+    #[test]
+    fn create_and_save_new_accounts_skip_gap() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(profile_without_alice());
+        let accounts = wallet
+            .create_and_save_new_accounts(
+                NetworkID::Mainnet,
+                2,
+                "Savings".to_string(),
+                DerivationIndexGapStrategy::Skip,
+            )
+            .unwrap();
+        assert_eq!(
+            accounts.iter().map(derivation_index).collect_vec(),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn create_new_account_with_gap_does_not_reuse_used_index() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(profile_without_alice());
+        let account = wallet
+            .create_new_account(NetworkID::Mainnet, name())
+            .unwrap();
+        assert_eq!(derivation_index(&account), 2);
+    }
+
+    #[test]
+    fn create_and_save_new_accounts_invalid_name() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let name_prefix = "a".repeat(DisplayName::MAX_LEN);
+        assert_eq!(
+            wallet.create_and_save_new_accounts(
+                NetworkID::Mainnet,
+                2,
+                name_prefix,
+                DerivationIndexGapStrategy::Fill,
+            ),
+            Err(CommonError::InvalidDisplayNameTooLong {
+                expected: DisplayName::MAX_LEN,
+                found: DisplayName::MAX_LEN + 2
+            })
+        );
+        wallet.read(|p| assert_eq!(p.networks[0].accounts.len(), 2));
+    }
+
+    #[test]
+    fn add_accounts_already_present() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let carol = Account::placeholder_mainnet_carol();
+        let alice = Account::placeholder_mainnet_alice();
+        assert_eq!(
+            wallet.add_accounts(vec![carol.clone(), alice.clone()]),
+            Err(CommonError::AccountAlreadyPresent(alice.address))
+        );
+        assert_eq!(
+            wallet.add_accounts(vec![carol.clone(), carol.clone()]),
+            Err(CommonError::AccountAlreadyPresent(carol.address))
+        );
+        wallet.read(|p| assert_eq!(p.networks[0].accounts.len(), 2));
+    }
 }
@@ -0,0 +1,236 @@
+use crate::prelude::*;
+
+//========
+// SET - Authorized Dapp
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Grants the `authorized_dapp` access to the personas, accounts and
+    /// persona data it references, by adding it to the network it is on.
+    ///
+    /// Returns `Err` if the dApp is already authorized, if the network is
+    /// unknown or if any referenced persona, account or persona data entry
+    /// is unknown to the wallet.
+    pub fn grant_authorized_dapp(
+        &self,
+        authorized_dapp: AuthorizedDapp,
+    ) -> Result<()> {
+        let network_id = authorized_dapp.network_id;
+        let err_exists = CommonError::AuthorizedDappAlreadyPresent(
+            authorized_dapp.id().clone(),
+        );
+        self.try_write(|mut p| {
+            p.networks
+                .try_update_with(&network_id, |network| {
+                    network.validate_authorized_dapp(&authorized_dapp)?;
+                    if network
+                        .authorized_dapps
+                        .append(authorized_dapp.clone())
+                        .0
+                    {
+                        Ok(network.clone())
+                    } else {
+                        Err(err_exists.clone())
+                    }
+                })
+                .and_then(|r| {
+                    if r {
+                        Ok(())
+                    } else {
+                        Err(CommonError::UnknownNetworkForID(
+                            network_id.discriminant(),
+                        ))
+                    }
+                })
+        })
+    }
+
+    /// Replaces the already authorized dApp with the same dApp definition
+    /// address as `authorized_dapp`, e.g. after the user has logged in again
+    /// or shared other accounts or persona data.
+    ///
+    /// Returns `Err` if the dApp is not authorized or if any referenced
+    /// persona, account or persona data entry is unknown to the wallet.
+    pub fn update_authorized_dapp(
+        &self,
+        authorized_dapp: AuthorizedDapp,
+    ) -> Result<()> {
+        let network_id = authorized_dapp.network_id;
+        self.try_write(|mut p| {
+            p.networks
+                .try_update_with(&network_id, |network| {
+                    network.validate_authorized_dapp(&authorized_dapp)?;
+                    if network
+                        .authorized_dapps
+                        .update_with(&authorized_dapp.id(), |d| {
+                            *d = authorized_dapp.clone()
+                        })
+                    {
+                        Ok(network.clone())
+                    } else {
+                        Err(CommonError::UnknownAuthorizedDapp)
+                    }
+                })
+                .and_then(|r| {
+                    if r {
+                        Ok(())
+                    } else {
+                        Err(CommonError::UnknownAuthorizedDapp)
+                    }
+                })
+        })
+    }
+
+    /// Revokes the authorization of the dApp with `dapp_definition_address`,
+    /// by removing it from the network it is on, returning the removed dApp.
+    ///
+    /// Returns `Err` if the dApp is not authorized.
+    pub fn revoke_authorized_dapp(
+        &self,
+        dapp_definition_address: AccountAddress,
+    ) -> Result<AuthorizedDapp> {
+        let network_id = dapp_definition_address.network_id;
+        self.try_write(|mut p| {
+            let mut removed: Option<AuthorizedDapp> = None;
+            _ = p.networks.update_with(&network_id, |n| {
+                removed =
+                    n.authorized_dapps.remove_by_id(&dapp_definition_address)
+            });
+            removed.ok_or(CommonError::UnknownAuthorizedDapp)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    pub use pretty_assertions::{assert_eq, assert_ne};
+
+    fn profile_with_personas() -> Profile {
+        let mut profile = Profile::placeholder();
+        _ = profile.networks.update_with(&NetworkID::Mainnet, |n| {
+            n.personas = Personas::placeholder_mainnet()
+        });
+        profile
+    }
+
+    fn dapps_on_mainnet(wallet: &Wallet) -> AuthorizedDapps {
+        wallet.read(|p| {
+            p.networks
+                .get(&NetworkID::Mainnet)
+                .unwrap()
+                .authorized_dapps
+                .clone()
+        })
+    }
+
+    #[test]
+    fn grant_authorized_dapp() {
+        let (wallet, _) = Wallet::ephemeral(profile_with_personas());
+        let dapp = AuthorizedDapp::placeholder();
+        assert_eq!(wallet.grant_authorized_dapp(dapp.clone()), Ok(()));
+        assert_eq!(dapps_on_mainnet(&wallet).items(), [dapp]);
+    }
+
+    #[test]
+    fn grant_authorized_dapp_already_present() {
+        let (wallet, _) = Wallet::ephemeral(profile_with_personas());
+        let dapp = AuthorizedDapp::placeholder();
+        assert_eq!(wallet.grant_authorized_dapp(dapp.clone()), Ok(()));
+        assert_eq!(
+            wallet.grant_authorized_dapp(dapp.clone()),
+            Err(CommonError::AuthorizedDappAlreadyPresent(
+                dapp.dapp_definition_address
+            ))
+        );
+    }
+
+    #[test]
+    fn grant_authorized_dapp_unknown_persona() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.grant_authorized_dapp(AuthorizedDapp::placeholder()),
+            Err(CommonError::UnknownPersona)
+        );
+        assert!(dapps_on_mainnet(&wallet).is_empty());
+    }
+
+    #[test]
+    fn update_authorized_dapp() {
+        let (wallet, _) = Wallet::ephemeral(profile_with_personas());
+        let mut dapp = AuthorizedDapp::placeholder();
+        wallet.grant_authorized_dapp(dapp.clone()).unwrap();
+        dapp.display_name = Some("Dashboard".to_string());
+        assert_eq!(wallet.update_authorized_dapp(dapp.clone()), Ok(()));
+        assert_eq!(dapps_on_mainnet(&wallet).items(), [dapp]);
+    }
+
+    #[test]
+    fn update_authorized_dapp_unknown() {
+        let (wallet, _) = Wallet::ephemeral(profile_with_personas());
+        assert_eq!(
+            wallet.update_authorized_dapp(AuthorizedDapp::placeholder()),
+            Err(CommonError::UnknownAuthorizedDapp)
+        );
+    }
+
+    #[test]
+    fn revoke_authorized_dapp() {
+        let (wallet, _) = Wallet::ephemeral(profile_with_personas());
+        let dapp = AuthorizedDapp::placeholder();
+        wallet.grant_authorized_dapp(dapp.clone()).unwrap();
+        assert_eq!(
+            wallet.revoke_authorized_dapp(dapp.dapp_definition_address.clone()),
+            Ok(dapp.clone())
+        );
+        assert!(dapps_on_mainnet(&wallet).is_empty());
+        assert_eq!(
+            wallet.revoke_authorized_dapp(dapp.dapp_definition_address),
+            Err(CommonError::UnknownAuthorizedDapp)
+        );
+    }
+
+    #[test]
+    fn hide_persona_removes_references_in_authorized_dapps() {
+        let (wallet, _) = Wallet::ephemeral(profile_with_personas());
+        wallet
+            .grant_authorized_dapp(
+                AuthorizedDapp::placeholder_mainnet_dashboard(),
+            )
+            .unwrap();
+        wallet
+            .grant_authorized_dapp(
+                AuthorizedDapp::placeholder_mainnet_gumballclub(),
+            )
+            .unwrap();
+
+        wallet
+            .hide_persona(Persona::placeholder_batman().address)
+            .unwrap();
+
+        let dapps = dapps_on_mainnet(&wallet);
+        assert_eq!(dapps.len(), 1);
+        assert_eq!(
+            dapps[0].references_to_authorized_personas.items(),
+            [AuthorizedPersonaSimple::placeholder_satoshi()]
+        );
+    }
+
+    #[test]
+    fn hide_account_removes_references_in_authorized_dapps() {
+        let (wallet, _) = Wallet::ephemeral(profile_with_personas());
+        wallet
+            .grant_authorized_dapp(
+                AuthorizedDapp::placeholder_mainnet_dashboard(),
+            )
+            .unwrap();
+        let bob = Account::placeholder_mainnet_bob().address;
+
+        wallet.hide_account(bob.clone()).unwrap();
+
+        assert!(dapps_on_mainnet(&wallet)
+            .iter()
+            .flat_map(|d| d.references_to_authorized_personas.items())
+            .all(|p| !p.is_sharing_account(&bob)));
+    }
+}
@@ -0,0 +1,243 @@
+use crate::prelude::*;
+
+//========
+// SET - Gateways
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Saves `gateway` so that the user can switch to it later, without
+    /// changing the current Gateway.
+    ///
+    /// Throws if the gateway already is saved.
+    pub fn add_gateway(&self, gateway: Gateway) -> Result<()> {
+        self.try_write(|mut p| p.app_preferences.gateways.add(gateway.clone()))
+    }
+
+    /// Removes the saved `gateway`.
+    ///
+    /// Throws if `gateway` is the current Gateway, if it is a well known
+    /// Gateway or if it is not saved.
+    pub fn remove_gateway(&self, gateway: Gateway) -> Result<()> {
+        self.try_write(|mut p| p.app_preferences.gateways.remove(&gateway))
+    }
+
+    /// Makes `to` the current Gateway, saving it if it is new, and makes sure
+    /// Profile contains a `Network` for the network of `to`, unless it is a
+    /// custom network, since Profile only contains `Network`s of networks
+    /// known to this library.
+    ///
+    /// Returns `true` if the user already has accounts (including hidden ones)
+    /// on the network of `to`, `false` if the user must create an account
+    /// before using it, which is always the case for custom networks.
+    ///
+    /// Throws if the network of `to` is neither known nor a custom network
+    /// added with `add_custom_network`.
+    pub fn change_current_gateway(&self, to: Gateway) -> Result<bool> {
+        self.try_write(|mut p| {
+            let network_id = p.radix_network(to.network.id)?.id;
+            _ = p.app_preferences.gateways.change_current(to.clone())?;
+            let Ok(network_id) = NetworkID::try_from(network_id) else {
+                return Ok(false);
+            };
+            if !p.networks.contains_id(&network_id) {
+                p.networks.append(Network::new(
+                    network_id,
+                    Accounts::default(),
+                    Personas::default(),
+                    AuthorizedDapps::default(),
+                ));
+            }
+            Ok(p.networks
+                .get(&network_id)
+                .map(|n| !n.accounts.is_empty())
+                .unwrap_or_default())
+        })
+    }
+}
+
+//========
+// SET - Custom Networks
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Saves the definition of a network unknown to this library, e.g. a
+    /// private test network, see `CustomNetworkDefinition`.
+    ///
+    /// Throws if a custom network with the same ID already is defined.
+    pub fn add_custom_network(
+        &self,
+        definition: CustomNetworkDefinition,
+    ) -> Result<()> {
+        self.try_write(|mut p| {
+            if p.app_preferences
+                .custom_networks
+                .append(definition.clone())
+                .0
+            {
+                Ok(())
+            } else {
+                Err(CommonError::CustomNetworkAlreadyPresent(definition.id))
+            }
+        })
+    }
+}
+
+//========
+// GET - Gateways
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Creates a Gateway with `url` to the network with the raw ID
+    /// `network_id`, which might be a custom network added with
+    /// `add_custom_network`.
+    ///
+    /// Throws if `url` is invalid or if the network is unknown.
+    pub fn new_gateway(&self, url: String, network_id: u8) -> Result<Gateway> {
+        self.read(|p| p.new_gateway(url.clone(), network_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn saved_gateways(wallet: &Wallet) -> Gateways {
+        wallet
+            .load_profile_with_id(&wallet.profile().id())
+            .unwrap()
+            .app_preferences
+            .gateways
+    }
+
+    #[test]
+    fn add_gateway() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        wallet.add_gateway(Gateway::nebunet()).unwrap();
+        assert!(saved_gateways(&wallet).other.contains(&Gateway::nebunet()));
+    }
+
+    #[test]
+    fn add_gateway_already_present() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.add_gateway(Gateway::mainnet()),
+            Err(CommonError::GatewayAlreadyPresent(
+                Gateway::mainnet().url.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn remove_gateway() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        wallet.add_gateway(Gateway::nebunet()).unwrap();
+        wallet.remove_gateway(Gateway::nebunet()).unwrap();
+        assert_eq!(saved_gateways(&wallet), Gateways::placeholder());
+    }
+
+    #[test]
+    fn remove_gateway_current_or_wellknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.remove_gateway(Gateway::rcnet()),
+            Err(CommonError::CannotRemoveCurrentGateway)
+        );
+        assert_eq!(
+            wallet.remove_gateway(Gateway::mainnet()),
+            Err(CommonError::CannotRemoveWellKnownGateway(
+                Gateway::mainnet().url.to_string()
+            ))
+        );
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
+
+    #[test]
+    fn change_current_gateway_to_network_with_accounts() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(wallet.change_current_gateway(Gateway::mainnet()), Ok(true));
+        assert_eq!(saved_gateways(&wallet).current, Gateway::mainnet());
+        assert!(saved_gateways(&wallet).other.contains(&Gateway::rcnet()));
+        assert_eq!(wallet.profile().networks, Profile::placeholder().networks);
+    }
+
+    #[test]
+    fn add_custom_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let custom = CustomNetworkDefinition::placeholder();
+        wallet.add_custom_network(custom.clone()).unwrap();
+        assert_eq!(
+            wallet.add_custom_network(custom.clone()),
+            Err(CommonError::CustomNetworkAlreadyPresent(0x30))
+        );
+        let saved =
+            wallet.load_profile_with_id(&wallet.profile().id()).unwrap();
+        assert_eq!(
+            saved.app_preferences.custom_networks,
+            CustomNetworkDefinitions::from_iter([custom])
+        );
+    }
+
+    #[test]
+    fn change_current_gateway_to_custom_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let url = "https://gateway.ziggurat.example.com/".to_owned();
+        assert_eq!(
+            wallet.new_gateway(url.clone(), 0x30),
+            Err(CommonError::UnknownNetworkID(0x30))
+        );
+
+        wallet
+            .add_custom_network(CustomNetworkDefinition::placeholder())
+            .unwrap();
+        let gateway = wallet.new_gateway(url, 0x30).unwrap();
+        assert_eq!(gateway.network.id, 0x30);
+        assert_eq!(wallet.change_current_gateway(gateway.clone()), Ok(false));
+        assert_eq!(saved_gateways(&wallet).current, gateway);
+        assert_eq!(wallet.profile().networks, Profile::placeholder().networks);
+    }
+
+    #[test]
+    fn change_current_gateway_to_undefined_custom_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let gateway = Gateway::with_network(
+            "https://gateway.ziggurat.example.com/".to_owned(),
+            CustomNetworkDefinition::placeholder().radix_network(),
+        )
+        .unwrap();
+        assert_eq!(
+            wallet.change_current_gateway(gateway),
+            Err(CommonError::UnknownNetworkID(0x30))
+        );
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
+
+    #[test]
+    fn new_gateway_known_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.new_gateway(
+                "https://mainnet.radixdlt.com/".to_owned(),
+                NetworkID::Mainnet.discriminant()
+            ),
+            Ok(Gateway::mainnet())
+        );
+    }
+
+    #[test]
+    fn change_current_gateway_to_new_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.change_current_gateway(Gateway::nebunet()),
+            Ok(false)
+        );
+        let profile =
+            wallet.load_profile_with_id(&wallet.profile().id()).unwrap();
+        assert_eq!(
+            profile.app_preferences.gateways.current,
+            Gateway::nebunet()
+        );
+        let network = profile.networks.get(&NetworkID::Nebunet).unwrap();
+        assert!(network.accounts.is_empty());
+        assert!(network.personas.is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+use crate::prelude::*;
+
+//========
+// LEDGER - FactorSource
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Reads the device info of the Ledger connected through `ledger_client`
+    /// and adds a new `LedgerHardwareWalletFactorSource` for it, named `name`,
+    /// to Profile, returning it.
+    ///
+    /// Returns `Err` if `ledger_client` fails or if Profile already contains
+    /// the factor source of the connected Ledger.
+    pub fn add_ledger_factor_source(
+        &self,
+        name: String,
+        ledger_client: Arc<dyn LedgerHardwareWalletClient>,
+    ) -> Result<LedgerHardwareWalletFactorSource> {
+        let device_info = ledger_client.get_device_info()?;
+        let factor_source = LedgerHardwareWalletFactorSource::from_device_info(
+            device_info,
+            name.as_str(),
+        );
+        self.add_factor_source(factor_source.clone().into())?;
+        Ok(factor_source)
+    }
+
+    /// Derives the transaction signing keys of `count` new accounts on
+    /// `network_id`, at the indices after the highest derivation index used by
+    /// the `LedgerHardwareWalletFactorSource` with `factor_source_id`, using the
+    /// Ledger connected through `ledger_client`, after which the
+    /// `last_used_on` date of the factor source is updated.
+    ///
+    /// Returns `Err` if Profile does not contain a
+    /// `LedgerHardwareWalletFactorSource` with `factor_source_id`, if
+    /// `ledger_client` fails or if the Ledger responds with keys at other
+    /// derivation paths than requested.
+    pub fn derive_account_creation_factor_instances_with_ledger(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        network_id: NetworkID,
+        count: u16,
+        ledger_client: Arc<dyn LedgerHardwareWalletClient>,
+    ) -> Result<Vec<HierarchicalDeterministicFactorInstance>> {
+        let profile = self.profile();
+        let id: FactorSourceID = factor_source_id.clone().into();
+        let ledger = profile
+            .factor_source_by_id::<LedgerHardwareWalletFactorSource>(&id)?;
+
+        let derivation_paths = profile
+            .next_derivation_indices_for_entity_for_factor_source(
+                EntityKind::Accounts,
+                network_id,
+                &factor_source_id,
+                count as usize,
+                DerivationIndexGapStrategy::Skip,
+            )
+            .into_iter()
+            .map(|index| {
+                DerivationPath::from(AccountPath::new(
+                    network_id,
+                    CAP26KeyKind::TransactionSigning,
+                    index,
+                ))
+            })
+            .collect_vec();
+
+        let public_keys = ledger_client.derive_public_keys(
+            LedgerDerivePublicKeysRequest::new(
+                LedgerDevice::from(&ledger),
+                derivation_paths.clone(),
+            ),
+        )?;
+        if public_keys
+            .iter()
+            .map(|k| k.derivation_path.clone())
+            .collect_vec()
+            != derivation_paths
+        {
+            return Err(CommonError::UnexpectedLedgerResponse);
+        }
+
+        self.try_write(|mut p| {
+            p.update_factor_source(
+                &id,
+                |mut f: LedgerHardwareWalletFactorSource| {
+                    f.common.last_used_on = now();
+                    Ok(f)
+                },
+            )
+            .map(|_| ())
+        })?;
+
+        Ok(public_keys
+            .into_iter()
+            .map(|k| {
+                HierarchicalDeterministicFactorInstance::new(
+                    factor_source_id.clone(),
+                    k,
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// A Ledger which responds with keys of another account than requested.
+    #[derive(Debug)]
+    struct MisbehavingLedger(SimulatedLedgerHardwareWallet);
+
+    impl LedgerHardwareWalletClient for MisbehavingLedger {
+        fn get_device_info(&self) -> Result<LedgerDeviceInfo> {
+            self.0.get_device_info()
+        }
+
+        fn derive_public_keys(
+            &self,
+            mut request: LedgerDerivePublicKeysRequest,
+        ) -> Result<Vec<HierarchicalDeterministicPublicKey>> {
+            request.keys_parameters.reverse();
+            self.0.derive_public_keys(request)
+        }
+
+        fn sign_transaction(
+            &self,
+            request: LedgerSignTransactionRequest,
+        ) -> Result<Vec<LedgerSignature>> {
+            self.0.sign_transaction(request)
+        }
+
+        fn sign_auth(
+            &self,
+            request: LedgerSignAuthRequest,
+        ) -> Result<Vec<LedgerSignature>> {
+            self.0.sign_auth(request)
+        }
+
+        fn derive_and_display_address(
+            &self,
+            request: LedgerDeriveAndDisplayAddressRequest,
+        ) -> Result<LedgerDerivedAddress> {
+            self.0.derive_and_display_address(request)
+        }
+    }
+
+    fn ledger(
+        simulated: SimulatedLedgerHardwareWallet,
+    ) -> Arc<dyn LedgerHardwareWalletClient> {
+        Arc::new(simulated)
+    }
+
+    #[test]
+    fn add_ledger_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let simulated = SimulatedLedgerHardwareWallet::placeholder_other();
+        let id = simulated.id();
+        let added = wallet
+            .add_ledger_factor_source("Black".to_string(), ledger(simulated))
+            .unwrap();
+        assert_eq!(added.id.body, id);
+        assert_eq!(added.hint.model, LedgerHardwareWalletModel::NanoX);
+        assert_eq!(
+            wallet
+                .profile()
+                .factor_source_by_id(&added.factor_source_id()),
+            Ok(added)
+        );
+    }
+
+    #[test]
+    fn add_ledger_factor_source_already_present() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let simulated = SimulatedLedgerHardwareWallet::placeholder();
+        assert_eq!(
+            wallet.add_ledger_factor_source(
+                "Orange, scratched".to_string(),
+                ledger(simulated)
+            ),
+            Err(CommonError::UnableToSaveFactorSourceToProfile(
+                FactorSourceIDFromHash::placeholder_ledger().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn derive_account_creation_factor_instances_with_ledger() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let simulated = SimulatedLedgerHardwareWallet::placeholder();
+        let mwp = MnemonicWithPassphrase::placeholder();
+        let id = FactorSourceIDFromHash::placeholder_ledger();
+        let last_used_on = wallet
+            .profile()
+            .factor_source_by_id::<LedgerHardwareWalletFactorSource>(
+                &id.clone().into(),
+            )
+            .unwrap()
+            .common
+            .last_used_on;
+
+        let factor_instances = wallet
+            .derive_account_creation_factor_instances_with_ledger(
+                id.clone(),
+                NetworkID::Mainnet,
+                2,
+                ledger(simulated),
+            )
+            .unwrap();
+
+        assert_eq!(
+            factor_instances,
+            [0, 1]
+                .into_iter()
+                .map(|index| {
+                    HierarchicalDeterministicFactorInstance::new(
+                        id.clone(),
+                        mwp.derive_private_key(AccountPath::new(
+                            NetworkID::Mainnet,
+                            CAP26KeyKind::TransactionSigning,
+                            index,
+                        ))
+                        .public_key(),
+                    )
+                })
+                .collect_vec()
+        );
+        assert_ne!(
+            wallet
+                .profile()
+                .factor_source_by_id::<LedgerHardwareWalletFactorSource>(
+                    &id.into()
+                )
+                .unwrap()
+                .common
+                .last_used_on,
+            last_used_on
+        );
+    }
+
+    #[test]
+    fn derive_with_unknown_ledger() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = FactorSourceIDFromHash::placeholder_ledger_other();
+        assert_eq!(
+            wallet.derive_account_creation_factor_instances_with_ledger(
+                id.clone(),
+                NetworkID::Mainnet,
+                1,
+                ledger(SimulatedLedgerHardwareWallet::placeholder()),
+            ),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                id.into()
+            ))
+        );
+    }
+
+    #[test]
+    fn derive_with_wrong_ledger_connected() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.derive_account_creation_factor_instances_with_ledger(
+                FactorSourceIDFromHash::placeholder_ledger(),
+                NetworkID::Mainnet,
+                1,
+                ledger(SimulatedLedgerHardwareWallet::placeholder_other()),
+            ),
+            Err(CommonError::LedgerDeviceMismatch)
+        );
+    }
+
+    #[test]
+    fn derive_with_unexpected_response() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.derive_account_creation_factor_instances_with_ledger(
+                FactorSourceIDFromHash::placeholder_ledger(),
+                NetworkID::Mainnet,
+                2,
+                Arc::new(MisbehavingLedger(
+                    SimulatedLedgerHardwareWallet::placeholder()
+                )),
+            ),
+            Err(CommonError::UnexpectedLedgerResponse)
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use crate::prelude::*;
+
+//========
+// IMPORT - Olympia Accounts
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Returns the accounts in `accounts` which have already been imported
+    /// into Profile on `network_id`, i.e. for which Profile already contains
+    /// an account with the same address.
+    pub fn olympia_accounts_already_imported(
+        &self,
+        network_id: NetworkID,
+        accounts: Vec<OlympiaAccountToMigrate>,
+    ) -> Vec<OlympiaAccountToMigrate> {
+        let profile = self.profile();
+        accounts
+            .into_iter()
+            .filter(|a| {
+                profile
+                    .networks
+                    .get_account(&a.babylon_address(network_id))
+                    .is_some()
+            })
+            .collect()
+    }
+
+    /// Imports the Olympia `accounts` into Profile as Babylon accounts on
+    /// `network_id`, controlled by the factor source with `factor_source_id`,
+    /// returning the newly added accounts. Accounts which have already been
+    /// imported are skipped. All accounts are added at once, so Profile is
+    /// saved to SecureStorage once.
+    ///
+    /// If the factor source is a `DeviceFactorSource` its mnemonic is loaded
+    /// from SecureStorage to verify that it controls the public key of each
+    /// account, keys of Ledger accounts cannot be verified without the Ledger.
+    ///
+    /// Returns `Err` without importing any account if Profile does not
+    /// contain the factor source, if it does not support Olympia, if its kind
+    /// does not match the type of any of the `accounts`, if loading its
+    /// mnemonic fails, if it does not control the public key of any of the
+    /// `accounts` or if saving Profile to SecureStorage fails.
+    pub fn import_olympia_accounts(
+        &self,
+        network_id: NetworkID,
+        accounts: Vec<OlympiaAccountToMigrate>,
+        factor_source_id: FactorSourceIDFromHash,
+    ) -> Result<Vec<Account>> {
+        let profile = self.profile();
+        let id: FactorSourceID = factor_source_id.clone().into();
+        let factor_source = profile.factor_sources.get(&id).ok_or(
+            CommonError::ProfileDoesNotContainFactorSourceWithID(id.clone()),
+        )?;
+        if !factor_source.supports_olympia() {
+            return Err(CommonError::FactorSourceDoesNotSupportOlympia(id));
+        }
+        let found = factor_source.factor_source_kind();
+        if let Some(account) = accounts
+            .iter()
+            .find(|a| a.account_type.factor_source_kind() != found)
+        {
+            return Err(CommonError::CastFactorSourceWrongKind {
+                expected: account.account_type.factor_source_kind(),
+                found,
+            });
+        }
+        if let Some(device_factor_source) = factor_source.as_device() {
+            let private =
+                self.load_private_device_factor_source(device_factor_source)?;
+            if let Some(account) = accounts.iter().find(|a| {
+                private
+                    .mnemonic_with_passphrase
+                    .derive_private_key(a.path.clone())
+                    .public_key()
+                    .public_key
+                    != a.public_key.clone().into()
+            }) {
+                return Err(
+                    CommonError::OlympiaAccountNotControlledByFactorSource {
+                        public_key: account.public_key.to_hex(),
+                        factor_source_id: id,
+                    },
+                );
+            }
+        }
+
+        let number_of_accounts_on_network = profile
+            .networks
+            .get(&network_id)
+            .map(|n| n.accounts.len())
+            .unwrap_or(0);
+
+        let accounts = accounts
+            .into_iter()
+            .filter(|a| {
+                profile
+                    .networks
+                    .get_account(&a.babylon_address(network_id))
+                    .is_none()
+            })
+            .unique_by(|a| a.public_key.clone())
+            .enumerate()
+            .map(|(offset, a)| {
+                a.to_babylon_account(
+                    network_id,
+                    factor_source_id.clone(),
+                    AppearanceID::from_number_of_accounts_on_network(
+                        number_of_accounts_on_network + offset,
+                    ),
+                )
+            })
+            .collect_vec();
+
+        info!(
+            "Importing {} Olympia accounts on network {}",
+            accounts.len(),
+            network_id
+        );
+        if !accounts.is_empty() {
+            self.add_accounts(accounts.clone())?;
+        }
+
+        Ok(accounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn olympia_profile() -> Profile {
+        let mut profile = Profile::placeholder();
+        profile
+            .factor_sources
+            .append(FactorSource::placeholder_device_olympia());
+        profile
+    }
+
+    fn olympia_device_id() -> FactorSourceIDFromHash {
+        DeviceFactorSource::placeholder_olympia().id
+    }
+
+    fn olympia_mnemonic() -> MnemonicWithPassphrase {
+        MnemonicWithPassphrase::placeholder_other()
+    }
+
+    /// A software account controlled by `olympia_mnemonic` at `index`.
+    fn olympia_account(index: HDPathValue) -> OlympiaAccountToMigrate {
+        let public_key = olympia_mnemonic()
+            .derive_private_key(BIP44LikePath::new(index))
+            .public_key()
+            .public_key
+            .into_secp256k1()
+            .unwrap();
+        OlympiaAccountToMigrate::new(
+            OlympiaAccountType::Software,
+            public_key,
+            index,
+            DisplayName::new("Savings").ok(),
+        )
+    }
+
+    /// A wallet with the mnemonic of the Olympia `DeviceFactorSource` saved.
+    fn olympia_wallet() -> Wallet {
+        let (wallet, _) = Wallet::ephemeral(olympia_profile());
+        wallet
+            .wallet_client_storage
+            .save_mnemonic_with_passphrase(
+                &olympia_mnemonic(),
+                &olympia_device_id(),
+            )
+            .unwrap();
+        wallet
+    }
+
+    #[test]
+    fn import_olympia_accounts() {
+        let wallet = olympia_wallet();
+        let to_import = olympia_account(0);
+        let imported = wallet
+            .import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![to_import.clone()],
+                olympia_device_id(),
+            )
+            .unwrap();
+        assert_eq!(imported.len(), 1);
+        assert_eq!(
+            wallet.profile().networks.get_account(&imported[0].address),
+            Some(imported[0].clone())
+        );
+        assert_eq!(
+            imported[0].address,
+            to_import.babylon_address(NetworkID::Mainnet)
+        );
+    }
+
+    #[test]
+    fn import_olympia_accounts_assigns_next_appearance_ids() {
+        let wallet = olympia_wallet();
+        let number_of_accounts = wallet
+            .profile()
+            .networks
+            .get(&NetworkID::Mainnet)
+            .map(|n| n.accounts.len())
+            .unwrap_or(0);
+        let imported = wallet
+            .import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![olympia_account(0), olympia_account(1)],
+                olympia_device_id(),
+            )
+            .unwrap();
+        assert_eq!(
+            imported.iter().map(|a| a.appearance_id).collect_vec(),
+            vec![
+                AppearanceID::from_number_of_accounts_on_network(
+                    number_of_accounts
+                ),
+                AppearanceID::from_number_of_accounts_on_network(
+                    number_of_accounts + 1
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn import_olympia_accounts_saves_all_accounts() {
+        let wallet = olympia_wallet();
+        let imported = wallet
+            .import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![olympia_account(0), olympia_account(1)],
+                olympia_device_id(),
+            )
+            .unwrap();
+        let saved =
+            wallet.load_profile_with_id(&wallet.profile().id()).unwrap();
+        assert!(
+            imported
+                .iter()
+                .all(|a| saved.networks.get_account(&a.address)
+                    == Some(a.clone()))
+        );
+    }
+
+    #[test]
+    fn already_imported_are_detected_and_skipped() {
+        let wallet = olympia_wallet();
+        let to_import = olympia_account(0);
+        assert!(wallet
+            .olympia_accounts_already_imported(
+                NetworkID::Mainnet,
+                vec![to_import.clone()]
+            )
+            .is_empty());
+        wallet
+            .import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![to_import.clone()],
+                olympia_device_id(),
+            )
+            .unwrap();
+        assert_eq!(
+            wallet.olympia_accounts_already_imported(
+                NetworkID::Mainnet,
+                vec![to_import.clone()]
+            ),
+            vec![to_import.clone()]
+        );
+        assert_eq!(
+            wallet.import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![to_import],
+                olympia_device_id(),
+            ),
+            Ok(Vec::new())
+        );
+    }
+
+    #[test]
+    fn unknown_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![OlympiaAccountToMigrate::placeholder()],
+                olympia_device_id(),
+            ),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                olympia_device_id().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn factor_source_not_supporting_olympia() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = DeviceFactorSource::placeholder_babylon().id;
+        assert_eq!(
+            wallet.import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![OlympiaAccountToMigrate::placeholder()],
+                id.clone(),
+            ),
+            Err(CommonError::FactorSourceDoesNotSupportOlympia(id.into()))
+        );
+    }
+
+    #[test]
+    fn hardware_account_with_device_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(olympia_profile());
+        assert_eq!(
+            wallet.import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![OlympiaAccountToMigrate::placeholder_other()],
+                olympia_device_id(),
+            ),
+            Err(CommonError::CastFactorSourceWrongKind {
+                expected: FactorSourceKind::LedgerHQHardwareWallet,
+                found: FactorSourceKind::Device
+            })
+        );
+    }
+
+    #[test]
+    fn account_not_controlled_by_factor_source() {
+        let wallet = olympia_wallet();
+        let other = OlympiaAccountToMigrate::placeholder();
+        assert_eq!(
+            wallet.import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![olympia_account(0), other.clone()],
+                olympia_device_id(),
+            ),
+            Err(CommonError::OlympiaAccountNotControlledByFactorSource {
+                public_key: other.public_key.to_hex(),
+                factor_source_id: olympia_device_id().into()
+            })
+        );
+        assert_eq!(wallet.profile().networks, Profile::placeholder().networks);
+    }
+
+    #[test]
+    fn account_at_other_index_than_its_key() {
+        let wallet = olympia_wallet();
+        let mut to_import = olympia_account(0);
+        to_import.path = BIP44LikePath::new(1);
+        assert!(matches!(
+            wallet.import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![to_import],
+                olympia_device_id(),
+            ),
+            Err(CommonError::OlympiaAccountNotControlledByFactorSource { .. })
+        ));
+    }
+
+    #[test]
+    fn mnemonic_not_in_secure_storage() {
+        let (wallet, _) = Wallet::ephemeral(olympia_profile());
+        assert_eq!(
+            wallet.import_olympia_accounts(
+                NetworkID::Mainnet,
+                vec![olympia_account(0)],
+                olympia_device_id(),
+            ),
+            Err(CommonError::UnableToLoadMnemonicFromSecureStorage(
+                olympia_device_id()
+            ))
+        );
+    }
+}
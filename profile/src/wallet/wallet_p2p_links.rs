@@ -0,0 +1,139 @@
+use crate::prelude::*;
+use radix_engine_common::crypto::Hash;
+
+//========
+// SET - P2PLinks
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Saves `p2p_link`, throws if a link with the same connection password,
+    /// i.e. the same ID, is already saved.
+    pub fn add_p2p_link(&self, p2p_link: P2PLink) -> Result<()> {
+        self.try_write(|mut p| {
+            if p.app_preferences.p2p_links.append(p2p_link.clone()).0 {
+                Ok(())
+            } else {
+                Err(CommonError::P2PLinkAlreadyPresent)
+            }
+        })
+    }
+
+    /// Removes the P2PLink with `id`, as returned by `p2p_link_id`, and
+    /// returns it. Throws if no such link is saved.
+    pub fn remove_p2p_link(&self, id: Hex32Bytes) -> Result<P2PLink> {
+        self.try_write(|mut p| {
+            p.app_preferences
+                .p2p_links
+                .remove_by_id(&Hash(id.bytes()))
+                .ok_or(CommonError::UnknownP2PLink)
+        })
+    }
+
+    /// Changes the display name of the P2PLink with `id`, as returned by
+    /// `p2p_link_id`, and returns the updated link. Throws if no such link
+    /// is saved.
+    pub fn rename_p2p_link(
+        &self,
+        id: Hex32Bytes,
+        new_name: String,
+    ) -> Result<P2PLink> {
+        let id = Hash(id.bytes());
+        self.try_write(|mut p| {
+            let links = &mut p.app_preferences.p2p_links;
+            if links.update_with(&id, |l| l.display_name = new_name.clone()) {
+                links.get(&id).cloned().ok_or(CommonError::UnknownP2PLink)
+            } else {
+                Err(CommonError::UnknownP2PLink)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn saved_p2p_links(wallet: &Wallet) -> P2PLinks {
+        wallet
+            .load_profile_with_id(&wallet.profile().id())
+            .unwrap()
+            .app_preferences
+            .p2p_links
+    }
+
+    #[test]
+    fn add_p2p_link() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let link = P2PLink::placeholder_arc();
+        wallet.add_p2p_link(link.clone()).unwrap();
+        assert_eq!(
+            saved_p2p_links(&wallet).elements(),
+            [
+                &P2PLink::placeholder_brave(),
+                &P2PLink::placeholder_chrome(),
+                &link
+            ]
+        );
+    }
+
+    #[test]
+    fn add_p2p_link_duplicate_password() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let duplicate = P2PLink::new(
+            RadixConnectPassword::placeholder_babe(),
+            "Another name".to_owned(),
+        );
+        assert_eq!(
+            wallet.add_p2p_link(duplicate),
+            Err(CommonError::P2PLinkAlreadyPresent)
+        );
+        assert_eq!(saved_p2p_links(&wallet), P2PLinks::placeholder());
+    }
+
+    #[test]
+    fn remove_p2p_link() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let brave = P2PLink::placeholder_brave();
+        assert_eq!(wallet.remove_p2p_link(p2p_link_id(&brave)), Ok(brave));
+        assert_eq!(
+            saved_p2p_links(&wallet),
+            P2PLinks::from_iter([P2PLink::placeholder_chrome()])
+        );
+    }
+
+    #[test]
+    fn remove_p2p_link_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.remove_p2p_link(p2p_link_id(&P2PLink::placeholder_arc())),
+            Err(CommonError::UnknownP2PLink)
+        );
+    }
+
+    #[test]
+    fn rename_p2p_link() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = p2p_link_id(&P2PLink::placeholder_chrome());
+        let renamed = wallet
+            .rename_p2p_link(id, "Chrome on Linux".to_owned())
+            .unwrap();
+        assert_eq!(renamed.display_name, "Chrome on Linux");
+        assert_eq!(
+            renamed.connection_password,
+            RadixConnectPassword::placeholder_cafe()
+        );
+        assert_eq!(saved_p2p_links(&wallet)[1], renamed);
+    }
+
+    #[test]
+    fn rename_p2p_link_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.rename_p2p_link(
+                p2p_link_id(&P2PLink::placeholder_arc()),
+                "Arc".to_owned()
+            ),
+            Err(CommonError::UnknownP2PLink)
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::prelude::*;
+
+//========
+// SET - Persona
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Creates a new non securified persona **WITHOUT** add it to Profile, using the *main* "Babylon"
+    /// `DeviceFactorSource` and the "next" index for this FactorSource as derivation path.
+    ///
+    /// If you want to add it to Profile, call `wallet.add_persona(persona)`
+    pub fn create_new_persona(
+        &self,
+        network_id: NetworkID,
+        name: DisplayName,
+    ) -> Result<Persona> {
+        let profile = &self.profile();
+        let bdfs = profile.bdfs();
+        let index = profile
+            .next_derivation_index_for_entity(EntityKind::Persona, network_id);
+
+        let factor_instance =
+            self.load_private_device_factor_source(&bdfs).map(|p| {
+                p.derive_entity_creation_factor_instance(network_id, index)
+            })?;
+
+        let persona =
+            Persona::new(factor_instance, name, PersonaData::default());
+
+        Ok(persona)
+    }
+
+    /// Returns `Ok(())` if the `persona` was new and successfully added. If saving failed or if the persona was already present in Profile, an
+    /// error is returned.
+    pub fn add_persona(&self, persona: Persona) -> Result<()> {
+        let network_id = persona.network_id;
+        let err_exists =
+            CommonError::PersonaAlreadyPresent(persona.id().clone());
+        self.try_write(|mut p| {
+            let networks = &mut p.networks;
+            if networks.contains_id(&network_id) {
+                networks
+                    .try_update_with(&network_id, |network| {
+                        if network.personas.append(persona.clone()).0 {
+                            Ok(network.clone())
+                        } else {
+                            Err(err_exists.clone())
+                        }
+                    })
+                    .and_then(
+                        |r| if r { Ok(()) } else { Err(err_exists.clone()) },
+                    )
+            } else {
+                let network = Network::new(
+                    network_id,
+                    Accounts::default(),
+                    Personas::from_iter([persona.to_owned()]),
+                    AuthorizedDapps::default(),
+                );
+                networks.append(network);
+                Ok(())
+            }
+        })
+    }
+
+    /// Create a new Persona and adds it to the active Profile.
+    pub fn create_and_save_new_persona(
+        &self,
+        network_id: NetworkID,
+        name: DisplayName,
+    ) -> Result<Persona> {
+        let persona = self.create_new_persona(network_id, name)?;
+        self.add_persona(persona.clone())?;
+        Ok(persona)
+    }
+
+    /// Updates the display name of persona with the provided address, throws an error if the persona is unknown to the wallet.
+    pub fn change_name_of_persona(
+        &self,
+        address: IdentityAddress,
+        to: DisplayName,
+    ) -> Result<Persona> {
+        self.write(|mut p| {
+            p.update_persona(&address, |persona| {
+                persona.display_name = to.to_owned()
+            })
+        })
+        .ok_or(CommonError::UnknownPersona)
+    }
+
+    /// Marks the persona with the provided address as hidden by the user, the
+    /// persona is still kept in Profile. Throws an error if the persona is unknown
+    /// to the wallet.
+    ///
+    /// Any reference to the persona in the authorized dApps on its network is
+    /// removed, and any dApp left without any referenced persona is removed.
+    pub fn hide_persona(&self, address: IdentityAddress) -> Result<Persona> {
+        self.write(|mut p| {
+            let persona = p.update_persona(&address, |persona| {
+                _ = persona.flags.insert_flag(EntityFlag::DeletedByUser)
+            })?;
+            _ = p.networks.update_with(&address.network_id, |n| {
+                n.remove_references_to_persona(&address)
+            });
+            Some(persona)
+        })
+        .ok_or(CommonError::UnknownPersona)
+    }
+
+    /// Makes the persona with the provided address visible again, throws an
+    /// error if the persona is unknown to the wallet.
+    ///
+    /// References to the persona in authorized dApps removed when it was
+    /// hidden are not restored.
+    pub fn unhide_persona(&self, address: IdentityAddress) -> Result<Persona> {
+        self.write(|mut p| {
+            p.update_persona(&address, |persona| {
+                _ = persona.flags.remove_flag(&EntityFlag::DeletedByUser)
+            })
+        })
+        .ok_or(CommonError::UnknownPersona)
+    }
+}
+
+//========
+// GET - Persona
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Returns the personas on `network_id` which are not hidden by the user.
+    pub fn visible_personas_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Persona> {
+        self.read(|p| p.visible_personas_on_network(network_id))
+    }
+
+    /// Returns the personas on `network_id` which are hidden by the user.
+    pub fn hidden_personas_on_network(
+        &self,
+        network_id: NetworkID,
+    ) -> Vec<Persona> {
+        self.read(|p| p.hidden_personas_on_network(network_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    pub use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn create_new_persona_first_is_not_saved() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::new(
+                PrivateHierarchicalDeterministicFactorSource::placeholder(),
+                "Test",
+            ));
+        let persona = wallet
+            .create_new_persona(
+                NetworkID::Mainnet,
+                DisplayName::new("Satoshi").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(persona, Persona::placeholder_satoshi());
+        assert_eq!(wallet.profile().networks.len(), 0);
+    }
+
+    #[test]
+    fn create_and_save_new_persona_first_and_second() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let satoshi = wallet
+            .create_and_save_new_persona(
+                NetworkID::Mainnet,
+                DisplayName::new("Satoshi").unwrap(),
+            )
+            .unwrap();
+        let batman = wallet
+            .create_and_save_new_persona(
+                NetworkID::Mainnet,
+                DisplayName::new("Batman").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(satoshi, Persona::placeholder_satoshi());
+        assert_eq!(batman, Persona::placeholder_batman());
+        wallet.read(|p| {
+            assert_eq!(p.networks[0].personas, Personas::placeholder_mainnet())
+        });
+    }
+
+    #[test]
+    fn add_persona_on_new_network() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder_other());
+        assert!(wallet.add_persona(Persona::placeholder_satoshi()).is_ok());
+        assert_eq!(
+            wallet
+                .profile()
+                .networks
+                .get(&NetworkID::Mainnet)
+                .unwrap()
+                .personas
+                .items(),
+            [Persona::placeholder_satoshi()]
+        );
+    }
+
+    #[test]
+    fn add_persona_fails_when_already_present() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let persona = Persona::placeholder();
+        assert!(wallet.add_persona(persona.clone()).is_ok());
+        assert_eq!(
+            wallet.add_persona(persona.clone()),
+            Err(CommonError::PersonaAlreadyPresent(persona.address))
+        );
+    }
+
+    #[test]
+    fn change_display_name_of_persona() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let persona = Persona::placeholder_batman();
+        wallet.add_persona(persona.clone()).unwrap();
+        assert_eq!(
+            wallet
+                .change_name_of_persona(
+                    persona.address.clone(),
+                    DisplayName::new("Bruce").unwrap()
+                )
+                .unwrap()
+                .display_name
+                .value,
+            "Bruce"
+        );
+        wallet.read(|p| {
+            assert_eq!(p.networks[0].personas[0].display_name.value, "Bruce")
+        });
+
+        assert_eq!(
+            wallet.change_name_of_persona(
+                Persona::placeholder_satoshi().address,
+                DisplayName::new("not used").unwrap()
+            ),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn hide_persona() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let persona = Persona::placeholder();
+        wallet.add_persona(persona.clone()).unwrap();
+        assert!(wallet
+            .hide_persona(persona.address.clone())
+            .unwrap()
+            .flags
+            .contains(&EntityFlag::DeletedByUser));
+        wallet.read(|p| {
+            assert!(p.networks[0].personas[0]
+                .flags
+                .contains(&EntityFlag::DeletedByUser))
+        });
+        assert!(wallet
+            .visible_personas_on_network(NetworkID::Mainnet)
+            .is_empty());
+        assert_eq!(
+            wallet
+                .hidden_personas_on_network(NetworkID::Mainnet)
+                .into_iter()
+                .map(|p| p.address)
+                .collect_vec(),
+            vec![persona.address]
+        );
+    }
+
+    #[test]
+    fn unhide_persona() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let persona = Persona::placeholder();
+        wallet.add_persona(persona.clone()).unwrap();
+        wallet.hide_persona(persona.address.clone()).unwrap();
+        assert!(wallet
+            .unhide_persona(persona.address.clone())
+            .unwrap()
+            .flags
+            .is_empty());
+        assert_eq!(
+            wallet.visible_personas_on_network(NetworkID::Mainnet),
+            vec![persona]
+        );
+        assert!(wallet
+            .hidden_personas_on_network(NetworkID::Mainnet)
+            .is_empty());
+    }
+
+    #[test]
+    fn unhide_persona_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.unhide_persona(Persona::placeholder().address),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+
+    #[test]
+    fn hide_persona_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.hide_persona(Persona::placeholder().address),
+            Err(CommonError::UnknownPersona)
+        );
+    }
+}
@@ -4,14 +4,60 @@ use crate::prelude::*;
 // Wallet + SecureStorage
 //========
 impl Wallet {
+    /// Saves the `profile` snapshot and updates its header in the
+    /// `HeadersList`, appending it if it is a new Profile.
     pub(crate) fn save_profile(&self, profile: &Profile) -> Result<()> {
         self.wallet_client_storage.save(
             SecureStorageKey::ProfileSnapshot {
                 profile_id: profile.header.id.clone(),
             },
             profile,
-        )
+        )?;
+        self.save_header(&profile.header)
+    }
+
+    pub(crate) fn load_profile_with_id(
+        &self,
+        profile_id: &ProfileID,
+    ) -> Result<Profile> {
+        self.wallet_client_storage.load_profile_with_id(profile_id)
+    }
+
+    pub(crate) fn delete_profile_snapshot(
+        &self,
+        profile_id: &ProfileID,
+    ) -> Result<()> {
+        self.wallet_client_storage
+            .delete(SecureStorageKey::ProfileSnapshot {
+                profile_id: profile_id.clone(),
+            })
+    }
+
+    /// Loads the `HeadersList` from SecureStorage, returns an empty list if
+    /// none has been saved yet.
+    pub(crate) fn load_headers_list(&self) -> Result<HeadersList> {
+        self.wallet_client_storage
+            .load(SecureStorageKey::SnapshotHeadersList)
+            .map(|o| o.unwrap_or_else(HeadersList::new))
+            .map_err(|_| CommonError::FailedToLoadProfileHeadersList)
+    }
+
+    pub(crate) fn save_headers_list(
+        &self,
+        headers: &HeadersList,
+    ) -> Result<()> {
+        self.wallet_client_storage
+            .save(SecureStorageKey::SnapshotHeadersList, headers)
+    }
+
+    fn save_header(&self, header: &Header) -> Result<()> {
+        let mut headers = self.load_headers_list()?;
+        if !headers.update_with(&header.id, |h| *h = header.clone()) {
+            _ = headers.append(header.clone());
+        }
+        self.save_headers_list(&headers)
     }
+
     pub(crate) fn save_active_profile_id(
         &self,
         profile_id: &ProfileID,
@@ -82,7 +128,7 @@ mod tests {
                 &self,
                 _key: SecureStorageKey,
             ) -> Result<Option<Vec<u8>>> {
-                todo!()
+                Ok(None) // no headers list saved yet
             }
 
             fn save_data(
@@ -0,0 +1,227 @@
+use crate::prelude::*;
+
+//========
+// GET - Profiles
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Returns the headers of all Profiles saved in SecureStorage, which
+    /// includes the active Profile.
+    pub fn list_profile_headers(&self) -> Result<HeadersList> {
+        self.load_headers_list()
+    }
+}
+
+//========
+// SET - Profiles
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Loads the Profile with `profile_id` from SecureStorage, replacing the
+    /// Profile of this wallet with it and marking it as the active Profile.
+    ///
+    /// Returns `Err` if no Profile snapshot with `profile_id` was found.
+    pub fn switch_to_profile(&self, profile_id: ProfileID) -> Result<()> {
+        let profile = self.load_profile_with_id(&profile_id)?;
+        self.try_write(|mut p| {
+            *p = profile.clone();
+            Ok(())
+        })?;
+        self.save_active_profile_id(&profile_id)
+    }
+
+    /// Deletes the Profile with `profile_id` from SecureStorage and removes
+    /// its header from the headers list. The mnemonics of the device factor
+    /// sources of the deleted Profile are also deleted, unless they are used
+    /// by the active Profile or any other saved Profile.
+    ///
+    /// Returns `Err` if `profile_id` is the ID of the active Profile, if no
+    /// Profile snapshot with `profile_id` was found or if any other saved
+    /// Profile cannot be loaded.
+    pub fn delete_profile(&self, profile_id: ProfileID) -> Result<()> {
+        let active_profile = self.profile();
+        if active_profile.id() == profile_id {
+            return Err(CommonError::UnableToDeleteActiveProfile(profile_id));
+        }
+
+        let profile_to_delete = self.load_profile_with_id(&profile_id)?;
+        let mut headers = self.load_headers_list()?;
+
+        let mut device_factor_source_ids_in_use =
+            device_factor_source_ids(&active_profile);
+        for header in headers
+            .iter()
+            .filter(|h| h.id != profile_id && h.id != active_profile.id())
+        {
+            let profile = self.load_profile_with_id(&header.id)?;
+            device_factor_source_ids_in_use
+                .extend(device_factor_source_ids(&profile));
+        }
+
+        device_factor_source_ids(&profile_to_delete)
+            .difference(&device_factor_source_ids_in_use)
+            .try_for_each(|id| {
+                self.wallet_client_storage.delete_mnemonic(id)
+            })?;
+
+        self.delete_profile_snapshot(&profile_id)?;
+
+        _ = headers.remove_by_id(&profile_id);
+        self.save_headers_list(&headers)
+    }
+}
+
+fn device_factor_source_ids(
+    profile: &Profile,
+) -> HashSet<FactorSourceIDFromHash> {
+    profile
+        .factor_sources
+        .iter()
+        .filter_map(|f| f.as_device().map(|d| d.id.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    pub use pretty_assertions::{assert_eq, assert_ne};
+
+    fn mnemonic_key(id: FactorSourceIDFromHash) -> SecureStorageKey {
+        SecureStorageKey::DeviceFactorSourceMnemonic {
+            factor_source_id: id,
+        }
+    }
+
+    /// Returns a wallet with `Profile::placeholder()` as active Profile, and
+    /// `Profile::placeholder_other()` also saved in the returned storage.
+    fn wallet_with_two_profiles() -> (Wallet, Arc<EphemeralSecureStorage>) {
+        let (_, storage) = Wallet::ephemeral(Profile::placeholder_other());
+        let wallet = Wallet::by_importing_profile(
+            Profile::placeholder(),
+            storage.clone(),
+        );
+        (wallet, storage)
+    }
+
+    #[test]
+    fn list_profile_headers_after_import() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert_eq!(
+            wallet.list_profile_headers().unwrap().items(),
+            [Profile::placeholder().header]
+        );
+    }
+
+    #[test]
+    fn list_profile_headers_two_profiles() {
+        let (wallet, _) = wallet_with_two_profiles();
+        assert_eq!(
+            wallet.list_profile_headers().unwrap().items(),
+            [
+                Profile::placeholder_other().header,
+                Profile::placeholder().header
+            ]
+        );
+    }
+
+    #[test]
+    fn list_profile_headers_is_updated_on_write() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        wallet.write(|mut p| {
+            p.header.content_hint = ContentHint::with_counters(1, 2, 3)
+        });
+        assert_eq!(
+            wallet.list_profile_headers().unwrap()[0].content_hint,
+            ContentHint::with_counters(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn list_profile_headers_fails_to_load() {
+        let (wallet, storage) = Wallet::ephemeral(Profile::placeholder());
+        assert!(storage
+            .save_data(SecureStorageKey::SnapshotHeadersList, vec![0xde])
+            .is_ok());
+        assert_eq!(
+            wallet.list_profile_headers(),
+            Err(CommonError::FailedToLoadProfileHeadersList)
+        );
+    }
+
+    #[test]
+    fn switch_to_profile() {
+        let (wallet, storage) = wallet_with_two_profiles();
+        let other = Profile::placeholder_other();
+        assert_eq!(wallet.switch_to_profile(other.id()), Ok(()));
+        assert_eq!(wallet.profile(), other);
+        assert_eq!(
+            storage.load_data(SecureStorageKey::ActiveProfileID),
+            Ok(Some(serde_json::to_vec(&other.id()).unwrap()))
+        );
+    }
+
+    #[test]
+    fn switch_to_profile_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = Profile::placeholder_other().id();
+        assert_eq!(
+            wallet.switch_to_profile(id.clone()),
+            Err(CommonError::ProfileSnapshotNotFound(id))
+        );
+        assert_eq!(wallet.profile(), Profile::placeholder());
+    }
+
+    #[test]
+    fn delete_profile() {
+        let (wallet, storage) = wallet_with_two_profiles();
+        let id = Profile::placeholder_other().id();
+        assert_eq!(wallet.delete_profile(id.clone()), Ok(()));
+        assert_eq!(
+            storage.load_data(SecureStorageKey::ProfileSnapshot {
+                profile_id: id
+            }),
+            Ok(None)
+        );
+        assert_eq!(
+            wallet.list_profile_headers().unwrap().items(),
+            [Profile::placeholder().header]
+        );
+    }
+
+    #[test]
+    fn delete_profile_only_deletes_mnemonics_not_in_use() {
+        let (wallet, storage) = wallet_with_two_profiles();
+        let shared = mnemonic_key(DeviceFactorSource::placeholder_babylon().id);
+        let not_shared =
+            mnemonic_key(DeviceFactorSource::placeholder_olympia().id);
+        assert!(storage.save_data(shared.clone(), vec![0xde]).is_ok());
+        assert!(storage.save_data(not_shared.clone(), vec![0xad]).is_ok());
+
+        wallet
+            .delete_profile(Profile::placeholder_other().id())
+            .unwrap();
+
+        assert_eq!(storage.load_data(shared), Ok(Some(vec![0xde])));
+        assert_eq!(storage.load_data(not_shared), Ok(None));
+    }
+
+    #[test]
+    fn delete_profile_active() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = Profile::placeholder().id();
+        assert_eq!(
+            wallet.delete_profile(id.clone()),
+            Err(CommonError::UnableToDeleteActiveProfile(id))
+        );
+    }
+
+    #[test]
+    fn delete_profile_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = Profile::placeholder_other().id();
+        assert_eq!(
+            wallet.delete_profile(id.clone()),
+            Err(CommonError::ProfileSnapshotNotFound(id))
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use crate::prelude::*;
+
+//========
+// GET - Security Structure Configurations
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Returns the security structure configurations the user has created.
+    pub fn security_structure_configurations(
+        &self,
+    ) -> Vec<SecurityStructureConfigurationReference> {
+        self.read(|p| {
+            p.app_preferences
+                .security
+                .structure_configuration_references
+                .clone()
+                .into_iter()
+                .collect()
+        })
+    }
+}
+
+//========
+// SET - Security Structure Configurations
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Adds the security structure `configuration` to Profile.
+    ///
+    /// Returns `Err` if the configuration is invalid, if Profile does not
+    /// contain any of the factor sources it references or if Profile already
+    /// contains a configuration with the same ID.
+    pub fn add_security_structure_configuration(
+        &self,
+        configuration: SecurityStructureConfigurationReference,
+    ) -> Result<()> {
+        self.try_write(|mut p| {
+            validate_security_structure_configuration(&p, &configuration)?;
+            if p.app_preferences
+                .security
+                .structure_configuration_references
+                .append(configuration.clone())
+                .0
+            {
+                Ok(())
+            } else {
+                Err(CommonError::SecurityStructureConfigurationAlreadyPresent(
+                    configuration.id(),
+                ))
+            }
+        })
+    }
+
+    /// Replaces the security structure configuration with the same ID as
+    /// `configuration`, e.g. after the user has renamed it or changed its
+    /// factors, setting its `last_updated_on` date to now.
+    ///
+    /// Returns `Err` if the configuration is invalid, if Profile does not
+    /// contain any of the factor sources it references or if Profile does
+    /// not contain any configuration with the same ID.
+    pub fn update_security_structure_configuration(
+        &self,
+        configuration: SecurityStructureConfigurationReference,
+    ) -> Result<()> {
+        self.try_write(|mut p| {
+            validate_security_structure_configuration(&p, &configuration)?;
+            if p.app_preferences
+                .security
+                .structure_configuration_references
+                .update_with(&configuration.id(), |c| {
+                    *c = configuration.clone();
+                    c.metadata.last_updated_on = now();
+                })
+            {
+                Ok(())
+            } else {
+                Err(CommonError::UnknownSecurityStructureConfiguration(
+                    configuration.id(),
+                ))
+            }
+        })
+    }
+
+    /// Deletes the security structure configuration with `id`, returning the
+    /// deleted configuration.
+    ///
+    /// Returns `Err` if any securified account or persona is controlled by
+    /// the configuration or if Profile does not contain any configuration
+    /// with `id`.
+    pub fn delete_security_structure_configuration(
+        &self,
+        id: SecurityStructureID,
+    ) -> Result<SecurityStructureConfigurationReference> {
+        self.try_write(|mut p| {
+            let is_in_use = p.networks.iter().any(|n| {
+                n.accounts
+                    .iter()
+                    .map(|a| &a.security_state)
+                    .chain(n.personas.iter().map(|x| &x.security_state))
+                    .filter_map(|s| s.as_securified())
+                    .any(|c| c.security_structure.security_structure_id == id)
+            });
+            if is_in_use {
+                return Err(CommonError::SecurityStructureConfigurationInUse(
+                    id,
+                ));
+            }
+            p.app_preferences
+                .security
+                .structure_configuration_references
+                .remove_by_id(&id)
+                .ok_or(CommonError::UnknownSecurityStructureConfiguration(id))
+        })
+    }
+}
+
+/// Returns `Err` if `configuration` is invalid or if `profile` does not
+/// contain all factor sources it references.
+fn validate_security_structure_configuration(
+    profile: &Profile,
+    configuration: &SecurityStructureConfigurationReference,
+) -> Result<()> {
+    configuration.validate()?;
+    if let Some(id) = configuration
+        .matrix_of_factors
+        .all_factors()
+        .into_iter()
+        .find(|id| !profile.factor_sources.contains_id(id))
+    {
+        return Err(CommonError::ProfileDoesNotContainFactorSourceWithID(id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn configuration() -> SecurityStructureConfigurationReference {
+        SecurityStructureConfigurationReference::new(
+            DisplayName::new("Spending Account").unwrap(),
+            SecurityStructureConfigurationReference::DEFAULT_NUMBER_OF_DAYS_UNTIL_AUTO_CONFIRMATION,
+            MatrixOfFactorSourceIDs::placeholder(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn add() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert!(wallet.security_structure_configurations().is_empty());
+        let sut = configuration();
+        wallet
+            .add_security_structure_configuration(sut.clone())
+            .unwrap();
+        assert_eq!(wallet.security_structure_configurations(), vec![sut]);
+    }
+
+    #[test]
+    fn add_already_present() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let sut = configuration();
+        wallet
+            .add_security_structure_configuration(sut.clone())
+            .unwrap();
+        assert_eq!(
+            wallet.add_security_structure_configuration(sut.clone()),
+            Err(CommonError::SecurityStructureConfigurationAlreadyPresent(
+                sut.id()
+            ))
+        );
+    }
+
+    #[test]
+    fn add_invalid() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let mut sut = configuration();
+        sut.matrix_of_factors.recovery_role =
+            RoleOfFactorSourceIDs::placeholder();
+        assert_eq!(
+            wallet.add_security_structure_configuration(sut),
+            Err(CommonError::FactorSourceInBothPrimaryAndRecoveryRole(
+                FactorSourceIDFromHash::placeholder_device().into()
+            ))
+        );
+        assert!(wallet.security_structure_configurations().is_empty());
+    }
+
+    #[test]
+    fn add_unknown_factor_source() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let unknown: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_ledger_other().into();
+        let mut sut = configuration();
+        sut.matrix_of_factors.confirmation_role =
+            RoleOfFactorSourceIDs::new([], 0, [unknown.clone()]).unwrap();
+        assert_eq!(
+            wallet.add_security_structure_configuration(sut),
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID(
+                unknown
+            ))
+        );
+    }
+
+    #[test]
+    fn update() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let mut sut = configuration();
+        wallet
+            .add_security_structure_configuration(sut.clone())
+            .unwrap();
+        sut.metadata.display_name = DisplayName::new("Savings").unwrap();
+        sut.number_of_days_until_auto_confirmation = 30;
+        wallet
+            .update_security_structure_configuration(sut.clone())
+            .unwrap();
+        let updated = wallet.security_structure_configurations()[0].clone();
+        assert_eq!(updated.metadata.display_name, sut.metadata.display_name);
+        assert_eq!(updated.number_of_days_until_auto_confirmation, 30);
+        assert!(updated.metadata.last_updated_on >= sut.metadata.created_on);
+    }
+
+    #[test]
+    fn update_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let sut = configuration();
+        assert_eq!(
+            wallet.update_security_structure_configuration(sut.clone()),
+            Err(CommonError::UnknownSecurityStructureConfiguration(sut.id()))
+        );
+    }
+
+    #[test]
+    fn delete() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let sut = configuration();
+        wallet
+            .add_security_structure_configuration(sut.clone())
+            .unwrap();
+        assert_eq!(
+            wallet.delete_security_structure_configuration(sut.id()),
+            Ok(sut)
+        );
+        assert!(wallet.security_structure_configurations().is_empty());
+    }
+
+    #[test]
+    fn delete_unknown() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        let id = SecurityStructureConfigurationReference::placeholder().id();
+        assert_eq!(
+            wallet.delete_security_structure_configuration(id),
+            Err(CommonError::UnknownSecurityStructureConfiguration(id))
+        );
+    }
+
+    #[test]
+    fn delete_in_use() {
+        let mut profile = Profile::placeholder();
+        profile.update_account(
+            &Account::placeholder_mainnet_alice().address,
+            |a| a.security_state = SecuredEntityControl::placeholder().into(),
+        );
+        let (wallet, _) = Wallet::ephemeral(profile);
+        let sut = SecurityStructureConfigurationReference::placeholder();
+        wallet
+            .add_security_structure_configuration(sut.clone())
+            .unwrap();
+        assert_eq!(
+            wallet.delete_security_structure_configuration(sut.id()),
+            Err(CommonError::SecurityStructureConfigurationInUse(sut.id()))
+        );
+    }
+}
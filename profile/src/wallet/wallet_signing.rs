@@ -0,0 +1,416 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::Hash;
+
+//========
+// SIGN - Transaction
+//========
+#[uniffi::export]
+impl Wallet {
+    /// Signs the `intent_hash` with the transaction signing key of each
+    /// account in `signers`, returning the signatures together with the
+    /// public keys of the signers, in the order of `signers`.
+    ///
+    /// The signers are grouped by the `DeviceFactorSource` controlling them,
+    /// so that each mnemonic is only loaded from SecureStorage once, after which
+    /// the `last_used_on` date of each used factor source is updated.
+    ///
+    /// Returns `Err` if any account is unknown, if any account is securified,
+    /// if any account is controlled by a factor source which is not a
+    /// `DeviceFactorSource` or if loading of a mnemonic from SecureStorage
+    /// fails.
+    pub fn sign_transaction_intent_hash(
+        &self,
+        intent_hash: Hex32Bytes,
+        signers: Vec<AccountAddress>,
+    ) -> Result<Vec<SignatureWithPublicKey>> {
+        let profile = self.profile();
+        let hash = Hash::from_bytes(intent_hash.bytes());
+
+        let factor_instances =
+            transaction_signing_factor_instances(&profile, signers)?
+                .into_iter()
+                .map(|(_, fi)| fi)
+                .collect_vec();
+
+        let private_factor_sources = factor_instances
+            .iter()
+            .map(|fi| fi.factor_source_id.clone())
+            .unique()
+            .map(|id| {
+                self.load_private_device_factor_source_by_id(&id)
+                    .map(|private| (id, private))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        let signatures = factor_instances
+            .iter()
+            .map(|fi| {
+                private_factor_sources[&fi.factor_source_id]
+                    .sign(&hash, &fi.derivation_path())
+            })
+            .collect_vec();
+
+        self.try_write(|mut p| {
+            private_factor_sources.keys().try_for_each(|id| {
+                p.update_factor_source(
+                    &id.clone().into(),
+                    |mut dfs: DeviceFactorSource| {
+                        dfs.common.last_used_on = now();
+                        Ok(dfs)
+                    },
+                )
+                .map(|_| ())
+            })
+        })?;
+
+        Ok(signatures)
+    }
+
+    /// Signs the `intent_hash` with the transaction signing key of each
+    /// account in `signers`, using the `mnemonic_with_passphrase` of the
+    /// `OffDeviceMnemonicFactorSource` with `factor_source_id`, which the user
+    /// has input just in time, returning the signatures together with the
+    /// public keys of the signers, in the order of `signers`.
+    ///
+    /// The mnemonic is never saved, but it is validated to hash to
+    /// `factor_source_id` before it is used, after which the `last_used_on`
+    /// date of the factor source is updated.
+    ///
+    /// Returns `Err` if Profile does not contain an
+    /// `OffDeviceMnemonicFactorSource` with `factor_source_id`, if the
+    /// mnemonic does not match it, if any account is unknown, if any account
+    /// is securified or if any account is not controlled by the factor source.
+    pub fn sign_transaction_intent_hash_with_off_device_mnemonic(
+        &self,
+        intent_hash: Hex32Bytes,
+        signers: Vec<AccountAddress>,
+        factor_source_id: FactorSourceIDFromHash,
+        mnemonic_with_passphrase: MnemonicWithPassphrase,
+    ) -> Result<Vec<SignatureWithPublicKey>> {
+        let profile = self.profile();
+        let id: FactorSourceID = factor_source_id.clone().into();
+        profile
+            .factor_source_by_id::<OffDeviceMnemonicFactorSource>(&id)?
+            .validate_mnemonic_with_passphrase(&mnemonic_with_passphrase)?;
+
+        let factor_instances =
+            transaction_signing_factor_instances(&profile, signers)?;
+        if let Some((address, _)) = factor_instances
+            .iter()
+            .find(|(_, fi)| fi.factor_source_id != factor_source_id)
+        {
+            return Err(CommonError::SignerNotControlledByFactorSource(
+                address.clone(),
+            ));
+        }
+
+        let hash = Hash::from_bytes(intent_hash.bytes());
+        let signatures = factor_instances
+            .iter()
+            .map(|(_, fi)| {
+                mnemonic_with_passphrase
+                    .derive_private_key(fi.derivation_path())
+                    .sign(&hash)
+            })
+            .collect_vec();
+
+        self.try_write(|mut p| {
+            p.update_factor_source(
+                &id,
+                |mut f: OffDeviceMnemonicFactorSource| {
+                    f.common.last_used_on = now();
+                    Ok(f)
+                },
+            )
+            .map(|_| ())
+        })?;
+
+        Ok(signatures)
+    }
+}
+
+/// Returns the transaction signing factor instance of each unique account in
+/// `signers`, together with its address, in the order of `signers`.
+///
+/// Returns `Err` if any account is unknown or if any account is securified.
+fn transaction_signing_factor_instances(
+    profile: &Profile,
+    signers: Vec<AccountAddress>,
+) -> Result<Vec<(AccountAddress, HierarchicalDeterministicFactorInstance)>> {
+    signers
+        .into_iter()
+        .unique()
+        .map(|address| {
+            profile
+                .networks
+                .get_account(&address)
+                .ok_or(CommonError::UnknownAccount)
+                .and_then(|account| match account.security_state {
+                    EntitySecurityState::Unsecured { value } => {
+                        Ok((address, value.transaction_signing))
+                    }
+                    EntitySecurityState::Securified { .. } => Err(
+                        CommonError::SigningWithSecurifiedAccountNotSupported(
+                            address,
+                        ),
+                    ),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    pub use pretty_assertions::{assert_eq, assert_ne};
+
+    use radix_engine_common::crypto::Hash;
+
+    #[test]
+    fn sign_transaction_intent_hash() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let intent_hash = Hex32Bytes::placeholder();
+        let alice = Account::placeholder_mainnet_alice();
+        let bob = Account::placeholder_mainnet_bob();
+
+        let signatures = wallet
+            .sign_transaction_intent_hash(
+                intent_hash.clone(),
+                vec![alice.address.clone(), bob.address.clone()],
+            )
+            .unwrap();
+
+        let hash = Hash::from_bytes(intent_hash.bytes());
+        let expected_keys = [alice, bob]
+            .into_iter()
+            .map(|a| {
+                a.security_state
+                    .as_unsecured()
+                    .unwrap()
+                    .transaction_signing
+                    .public_key
+                    .public_key
+                    .clone()
+            })
+            .collect_vec();
+        assert_eq!(
+            signatures.iter().map(|s| s.public_key()).collect_vec(),
+            expected_keys
+        );
+        assert!(signatures.iter().all(|s| s.verify(&hash)));
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_dedupes_signers() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        assert_eq!(
+            wallet
+                .sign_transaction_intent_hash(
+                    Hex32Bytes::placeholder(),
+                    vec![alice.clone(), alice],
+                )
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_updates_last_used_on() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let id = PrivateHierarchicalDeterministicFactorSource::placeholder()
+            .factor_source
+            .id;
+        let last_used_on = || {
+            wallet
+                .profile()
+                .device_factor_source_by_id(&id)
+                .unwrap()
+                .common
+                .last_used_on
+        };
+        let before = last_used_on();
+
+        wallet
+            .sign_transaction_intent_hash(
+                Hex32Bytes::placeholder(),
+                vec![Account::placeholder_mainnet_alice().address],
+            )
+            .unwrap();
+
+        assert_ne!(last_used_on(), before);
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_unknown_account() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        assert_eq!(
+            wallet.sign_transaction_intent_hash(
+                Hex32Bytes::placeholder(),
+                vec![Account::placeholder_mainnet_carol().address],
+            ),
+            Err(CommonError::UnknownAccount)
+        );
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_securified_account() {
+        let (wallet, _) =
+            Wallet::ephemeral_with_bdfs_mnemonic_saved(Profile::placeholder());
+        let alice = Account::placeholder_mainnet_alice().address;
+        wallet.write(|mut p| {
+            p.update_account(&alice, |a| {
+                a.security_state = SecuredEntityControl::placeholder().into()
+            })
+        });
+        assert_eq!(
+            wallet.sign_transaction_intent_hash(
+                Hex32Bytes::placeholder(),
+                vec![alice.clone()],
+            ),
+            Err(CommonError::SigningWithSecurifiedAccountNotSupported(alice))
+        );
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_fails_without_mnemonic() {
+        let (wallet, _) = Wallet::ephemeral(Profile::placeholder());
+        assert!(wallet
+            .sign_transaction_intent_hash(
+                Hex32Bytes::placeholder(),
+                vec![Account::placeholder_mainnet_alice().address],
+            )
+            .is_err());
+    }
+
+    fn wallet_with_off_device_mnemonic_account() -> (Wallet, Account) {
+        let private =
+            PrivateHierarchicalDeterministicFactorSource::placeholder();
+        let mut account = Account::new(
+            private
+                .derive_entity_creation_factor_instance(NetworkID::Mainnet, 5),
+            DisplayName::new("Off device").unwrap(),
+            AppearanceID::from_number_of_accounts_on_network(2),
+        );
+        account
+            .security_state
+            .as_unsecured_mut()
+            .unwrap()
+            .transaction_signing
+            .factor_source_id =
+            FactorSourceIDFromHash::placeholder_off_device();
+        let mut profile = Profile::placeholder();
+        profile
+            .factor_sources
+            .append(FactorSource::placeholder_off_device_mnemonic());
+        let (wallet, _) = Wallet::ephemeral(profile);
+        wallet.add_account(account.clone()).unwrap();
+        (wallet, account)
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_with_off_device_mnemonic() {
+        let (wallet, account) = wallet_with_off_device_mnemonic_account();
+        let intent_hash = Hex32Bytes::placeholder();
+        let signatures = wallet
+            .sign_transaction_intent_hash_with_off_device_mnemonic(
+                intent_hash.clone(),
+                vec![account.address.clone()],
+                FactorSourceIDFromHash::placeholder_off_device(),
+                MnemonicWithPassphrase::placeholder(),
+            )
+            .unwrap();
+        assert_eq!(signatures.len(), 1);
+        assert_eq!(
+            signatures[0].public_key(),
+            account
+                .security_state
+                .as_unsecured()
+                .unwrap()
+                .transaction_signing
+                .public_key
+                .public_key
+                .clone()
+        );
+        assert!(signatures[0].verify(&Hash::from_bytes(intent_hash.bytes())));
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_with_off_device_mnemonic_updates_last_used_on(
+    ) {
+        let (wallet, account) = wallet_with_off_device_mnemonic_account();
+        let id: FactorSourceID =
+            FactorSourceIDFromHash::placeholder_off_device().into();
+        let last_used_on = || {
+            wallet
+                .profile()
+                .factor_source_by_id::<OffDeviceMnemonicFactorSource>(&id)
+                .unwrap()
+                .common
+                .last_used_on
+        };
+        let before = last_used_on();
+        wallet
+            .sign_transaction_intent_hash_with_off_device_mnemonic(
+                Hex32Bytes::placeholder(),
+                vec![account.address],
+                FactorSourceIDFromHash::placeholder_off_device(),
+                MnemonicWithPassphrase::placeholder(),
+            )
+            .unwrap();
+        assert_ne!(last_used_on(), before);
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_with_wrong_off_device_mnemonic() {
+        let (wallet, account) = wallet_with_off_device_mnemonic_account();
+        let id = FactorSourceIDFromHash::placeholder_off_device();
+        assert_eq!(
+            wallet.sign_transaction_intent_hash_with_off_device_mnemonic(
+                Hex32Bytes::placeholder(),
+                vec![account.address],
+                id.clone(),
+                MnemonicWithPassphrase::placeholder_other(),
+            ),
+            Err(CommonError::MnemonicDoesNotMatchFactorSource(id.into()))
+        );
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_with_off_device_mnemonic_wrong_signer() {
+        let (wallet, _) = wallet_with_off_device_mnemonic_account();
+        let alice = Account::placeholder_mainnet_alice().address;
+        assert_eq!(
+            wallet.sign_transaction_intent_hash_with_off_device_mnemonic(
+                Hex32Bytes::placeholder(),
+                vec![alice.clone()],
+                FactorSourceIDFromHash::placeholder_off_device(),
+                MnemonicWithPassphrase::placeholder(),
+            ),
+            Err(CommonError::SignerNotControlledByFactorSource(alice))
+        );
+    }
+
+    #[test]
+    fn sign_transaction_intent_hash_with_off_device_mnemonic_not_off_device() {
+        let (wallet, account) = wallet_with_off_device_mnemonic_account();
+        assert_eq!(
+            wallet.sign_transaction_intent_hash_with_off_device_mnemonic(
+                Hex32Bytes::placeholder(),
+                vec![account.address],
+                FactorSourceIDFromHash::placeholder_device(),
+                MnemonicWithPassphrase::placeholder(),
+            ),
+            Err(CommonError::CastFactorSourceWrongKind {
+                expected: FactorSourceKind::OffDeviceMnemonic,
+                found: FactorSourceKind::Device
+            })
+        );
+    }
+}
@@ -289,4 +289,223 @@ pub enum CommonError {
 
     #[error("Expected IdentityPath but got something else.")]
     ExpectedIdentityPathButGotSomethingElse = 10081,
+
+    #[error("Persona Already Present {0}")]
+    PersonaAlreadyPresent(IdentityAddress) = 10082,
+
+    #[error("Unknown persona.")]
+    UnknownPersona = 10083,
+
+    #[error("Invalid PersonaData - name, family name and given names are both empty.")]
+    PersonaDataInvalidNameEmpty = 10084,
+
+    #[error("Invalid PersonaData - email address is empty.")]
+    PersonaDataInvalidEmailAddressEmpty = 10085,
+
+    #[error("Invalid PersonaData - phone number is empty.")]
+    PersonaDataInvalidPhoneNumberEmpty = 10086,
+
+    #[error("Authorized dApp Already Present {0}")]
+    AuthorizedDappAlreadyPresent(AccountAddress) = 10087,
+
+    #[error("Unknown authorized dApp.")]
+    UnknownAuthorizedDapp = 10088,
+
+    #[error("Authorized dApp on wrong network, expected: {expected}, found: {found}")]
+    AuthorizedDappOnWrongNetwork {
+        expected: NetworkID,
+        found: NetworkID,
+    } = 10089,
+
+    #[error("Unknown PersonaData entry with ID: {0}")]
+    UnknownPersonaDataEntry(PersonaDataEntryID) = 10090,
+
+    #[error("Failed to create Ed25519 Signature from bytes {0:?}.")]
+    InvalidEd25519SignatureFromBytes(Vec<u8>) = 10091,
+
+    #[error("Failed to create Secp256k1 Signature from bytes {0:?}.")]
+    InvalidSecp256k1SignatureFromBytes(Vec<u8>) = 10092,
+
+    #[error("Failed to create SignatureWithPublicKey, curve of signature and public key does not match.")]
+    SignatureWithPublicKeyCurveMismatch = 10093,
+
+    #[error("Unable to delete the active Profile with ID {0}, switch to another Profile first.")]
+    UnableToDeleteActiveProfile(ProfileID) = 10094,
+
+    #[error("Failed to decrypt encrypted Profile snapshot, wrong password or corrupted data.")]
+    InvalidPasswordForEncryptedProfileSnapshot = 10095,
+
+    #[error("Unsupported encrypted Profile snapshot version: {0}")]
+    UnsupportedEncryptedProfileSnapshotVersion(u32) = 10096,
+
+    #[error("Unsupported encryption scheme version: {0}")]
+    UnsupportedEncryptionScheme(u32) = 10097,
+
+    #[error("Unsupported key derivation scheme version: {0}")]
+    UnsupportedKeyDerivationScheme(u32) = 10098,
+
+    #[error("Profile snapshot version {found} is too new, current version is {current}")]
+    ProfileSnapshotVersionTooNew { found: u16, current: u16 } = 10099,
+
+    #[error("Unsupported Profile snapshot version: {0}")]
+    UnsupportedProfileSnapshotVersion(u16) = 10100,
+
+    #[error("Invalid Olympia wallet export payload: {0}")]
+    InvalidOlympiaWalletExportPayload(String) = 10101,
+
+    #[error("Olympia wallet export is incomplete, expected {expected} payloads, found {found}")]
+    OlympiaWalletExportIncomplete { expected: u32, found: u32 } = 10102,
+
+    #[error("FactorSource with ID {0} does not support Olympia")]
+    FactorSourceDoesNotSupportOlympia(FactorSourceID) = 10103,
+
+    #[error("Role threshold {threshold} is greater than the number of threshold factors {threshold_factor_count}")]
+    InvalidRoleThreshold {
+        threshold: u16,
+        threshold_factor_count: u16,
+    } = 10104,
+
+    #[error("Signing with securified account {0} is not supported")]
+    SigningWithSecurifiedAccountNotSupported(AccountAddress) = 10105,
+
+    #[error("Security structure role must contain at least one factor")]
+    SecurityStructureRoleWithoutFactors = 10106,
+
+    #[error("FactorSource with ID {0} is used in both the primary and the recovery role")]
+    FactorSourceInBothPrimaryAndRecoveryRole(FactorSourceID) = 10107,
+
+    #[error("Invalid number of days until auto confirmation: {0}")]
+    InvalidNumberOfDaysUntilAutoConfirmation(u16) = 10108,
+
+    #[error("Security structure configuration with ID {0} already exists")]
+    SecurityStructureConfigurationAlreadyPresent(SecurityStructureID) = 10109,
+
+    #[error("Unknown security structure configuration with ID {0}")]
+    UnknownSecurityStructureConfiguration(SecurityStructureID) = 10110,
+
+    #[error("Security structure configuration with ID {0} is in use by a securified entity")]
+    SecurityStructureConfigurationInUse(SecurityStructureID) = 10111,
+
+    #[error("Expected OffDeviceMnemonicFactorSource")]
+    ExpectedOffDeviceMnemonicFactorSourceGotSomethingElse = 10112,
+
+    #[error("Mnemonic does not match FactorSource with ID {0}")]
+    MnemonicDoesNotMatchFactorSource(FactorSourceID) = 10113,
+
+    #[error("Account {0} is not controlled by the FactorSource used to sign")]
+    SignerNotControlledByFactorSource(AccountAddress) = 10114,
+
+    #[error("Expected SecurityQuestionsFactorSource")]
+    ExpectedSecurityQuestionsFactorSourceGotSomethingElse = 10115,
+
+    #[error("Invalid number of security questions, expected {expected}, found {found}")]
+    InvalidSecurityQuestionsCount { expected: u32, found: u32 } = 10116,
+
+    #[error("Security question with ID {0} is used more than once")]
+    DuplicateSecurityQuestion(u16) = 10117,
+
+    #[error("Answer to security question with ID {0} is empty")]
+    EmptySecurityQuestionAnswer(u16) = 10118,
+
+    #[error("Too few answers to the security questions are correct")]
+    InvalidSecurityQuestionsAnswers = 10119,
+
+    #[error("Expected TrustedContactFactorSource")]
+    ExpectedTrustedContactFactorSourceGotSomethingElse = 10120,
+
+    #[error("FactorSource with ID {0} is a trusted contact, which can only be used in the recovery role")]
+    TrustedContactOnlyAllowedInRecoveryRole(FactorSourceID) = 10121,
+
+    #[error("Connected Ledger device is not the requested one")]
+    LedgerDeviceMismatch = 10122,
+
+    #[error("Ledger response does not match the request")]
+    UnexpectedLedgerResponse = 10123,
+
+    #[error("FactorSource with ID {0} cannot be used to create entities")]
+    FactorSourceCannotCreateEntities(FactorSourceID) = 10124,
+
+    #[error("FactorSource with ID {factor_source_id} does not support curve {curve:?}")]
+    FactorSourceDoesNotSupportCurve {
+        factor_source_id: FactorSourceID,
+        curve: SLIP10Curve,
+    } = 10125,
+
+    #[error("FactorSource with ID {0} is a Ledger, which requires a LedgerHardwareWalletClient")]
+    LedgerHardwareWalletClientRequired(FactorSourceID) = 10126,
+
+    #[error("New order of accounts must contain every account on the network exactly once")]
+    InvalidAccountsOrder = 10127,
+
+    #[error("Gateway with URL {0} is already saved")]
+    GatewayAlreadyPresent(String) = 10128,
+
+    #[error("Gateway with URL {0} is not saved")]
+    UnknownGateway(String) = 10129,
+
+    #[error(
+        "Cannot remove the current Gateway, change to another Gateway first"
+    )]
+    CannotRemoveCurrentGateway = 10130,
+
+    #[error("Cannot remove well known Gateway with URL {0}")]
+    CannotRemoveWellKnownGateway(String) = 10131,
+
+    #[error("Network with ID {0} is known and cannot be defined as a custom network")]
+    CustomNetworkIDIsKnownNetwork(u8) = 10132,
+
+    #[error("Invalid logical name of custom network, must be lowercase and non empty, found: '{0}'")]
+    InvalidCustomNetworkLogicalName(String) = 10133,
+
+    #[error("Invalid HRP suffix of custom network, expected: '{expected}', found: '{found}'")]
+    InvalidCustomNetworkHRPSuffix { expected: String, found: String } = 10134,
+
+    #[error("Custom network with ID {0} is already defined")]
+    CustomNetworkAlreadyPresent(u8) = 10135,
+
+    #[error("P2PLink with the same connection password is already saved")]
+    P2PLinkAlreadyPresent = 10136,
+
+    #[error("Unknown P2PLink")]
+    UnknownP2PLink = 10137,
+
+    #[error("Invalid Radix Connect QR code payload, expected 32 bytes as 64 hex characters")]
+    InvalidRadixConnectPasswordQRPayload = 10138,
+
+    #[error("Failed to decrypt Radix Connect message, wrong connection password or tampered message")]
+    RadixConnectMessageDecryptionFailed = 10139,
+
+    #[error("Radix Connect message is missing its MetaData package")]
+    RadixConnectMessageMissingMetaData = 10140,
+
+    #[error("Radix Connect packages belong to different messages")]
+    RadixConnectMessageIDMismatch = 10141,
+
+    #[error("Radix Connect message chunks are missing or duplicated")]
+    InvalidRadixConnectMessageChunks = 10142,
+
+    #[error("Radix Connect message byte count mismatch, expected: {expected}, found: {found}")]
+    RadixConnectMessageByteCountMismatch { expected: u64, found: u64 } = 10143,
+
+    #[error(
+        "Radix Connect message hash mismatch, reassembled chunks are corrupt"
+    )]
+    RadixConnectMessageHashMismatch = 10144,
+
+    #[error(
+        "Role threshold must be at least 1 when the role has threshold factors"
+    )]
+    RoleThresholdZeroWithThresholdFactors = 10145,
+
+    #[error("Olympia account with public key {public_key} is not controlled by factor source {factor_source_id}")]
+    OlympiaAccountNotControlledByFactorSource {
+        public_key: String,
+        factor_source_id: FactorSourceID,
+    } = 10146,
+
+    #[error("Account recovery scan found active accounts at all of the first {0} indices")]
+    AccountRecoveryScanIndexLimitReached(HDPathValue) = 10147,
+
+    #[error("Factor source with ID {0} is used more than once in role")]
+    DuplicateFactorSourceInRole(FactorSourceID) = 10148,
 }
@@ -1,23 +1,21 @@
 use crate::prelude::*;
-use radix_engine_common::math::Decimal as NativeDecimal;
+use radix_engine_common::math::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal as NativeDecimal,
+};
 use radix_engine_toolkit_json::models::common::SerializableDecimal;
 
-// FIXME: Use RET's type!
-#[derive(Clone, Debug, Eq, uniffi::Record, Default)]
-pub struct Decimal {
-    base10_string: String,
-}
+/// A fixed-point decimal number with 18 decimal places, the same as the
+/// `Decimal` of the Radix Engine. Passed across the FFI boundary as a base 10
+/// string, which is validated when lifted, so a `Decimal` is always valid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal(NativeDecimal);
 
-impl PartialEq for Decimal {
-    fn eq(&self, other: &Self) -> bool {
-        self.native().eq(&other.native())
-    }
-}
-impl std::hash::Hash for Decimal {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.native().hash(state);
+impl Default for Decimal {
+    fn default() -> Self {
+        Self::zero()
     }
 }
+
 impl PartialOrd for Decimal {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -39,12 +37,10 @@ impl Ord for Decimal {
 }
 impl Decimal {
     fn native(&self) -> NativeDecimal {
-        NativeDecimal::from_str(&self.base10_string).unwrap()
+        self.0
     }
     fn from_native(decimal: NativeDecimal) -> Self {
-        Self {
-            base10_string: decimal.to_string(),
-        }
+        Self(decimal)
     }
 }
 
@@ -113,6 +109,256 @@ impl Decimal {
     }
 }
 
+impl Decimal {
+    fn checked_operation<F>(&self, other: &Self, operation: F) -> Result<Self>
+    where
+        F: FnOnce(NativeDecimal, NativeDecimal) -> Option<NativeDecimal>,
+    {
+        operation(self.native(), other.native())
+            .map(Self::from_native)
+            .ok_or(CommonError::DecimalError)
+    }
+
+    /// Returns `self + other`, or `Err` if the result overflows.
+    pub fn checked_add(&self, other: &Self) -> Result<Self> {
+        self.checked_operation(other, |lhs, rhs| lhs.checked_add(rhs))
+    }
+
+    /// Returns `self - other`, or `Err` if the result overflows.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self> {
+        self.checked_operation(other, |lhs, rhs| lhs.checked_sub(rhs))
+    }
+
+    /// Returns `self * other`, or `Err` if the result overflows.
+    pub fn checked_mul(&self, other: &Self) -> Result<Self> {
+        self.checked_operation(other, |lhs, rhs| lhs.checked_mul(rhs))
+    }
+
+    /// Returns `self / other`, or `Err` if `other` is zero or if the result
+    /// overflows.
+    pub fn checked_div(&self, other: &Self) -> Result<Self> {
+        self.checked_operation(other, |lhs, rhs| lhs.checked_div(rhs))
+    }
+
+    /// Returns the absolute value of `self`, or `Err` if it overflows, which
+    /// only happens for the smallest representable `Decimal`.
+    pub fn abs(&self) -> Result<Self> {
+        self.native()
+            .checked_abs()
+            .map(Self::from_native)
+            .ok_or(CommonError::DecimalError)
+    }
+
+    /// Rounds `self` to `decimal_places` decimal places using `mode`, or
+    /// returns `Err` if the result overflows.
+    ///
+    /// Rounding to 18 or more decimal places, the maximum precision of
+    /// `Decimal`, leaves the value unchanged.
+    pub fn round(
+        &self,
+        decimal_places: u8,
+        mode: RoundingMode,
+    ) -> Result<Self> {
+        if decimal_places as u32 >= NativeDecimal::SCALE {
+            return Ok(*self);
+        }
+        self.native()
+            .checked_round(decimal_places, mode.into())
+            .map(Self::from_native)
+            .ok_or(CommonError::DecimalError)
+    }
+
+    /// Converts `self` to an `i64`, returns `Err` if `self` has a fractional
+    /// part or does not fit in an `i64`, use `round` first to truncate it.
+    pub fn to_i64(&self) -> Result<i64> {
+        if self.round(0, RoundingMode::ToZero)? != *self {
+            return Err(CommonError::DecimalError);
+        }
+        self.to_string()
+            .parse::<i64>()
+            .map_err(|_| CommonError::DecimalError)
+    }
+
+    /// Converts `self` to the closest `f32`, which might lose precision,
+    /// returns `Err` if `self` is too large to fit in an `f32`.
+    pub fn to_f32(&self) -> Result<f32> {
+        self.to_string()
+            .parse::<f32>()
+            .ok()
+            .filter(|f| f.is_finite())
+            .ok_or(CommonError::DecimalError)
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Self::from_native(NativeDecimal::from(value))
+    }
+}
+
+impl TryFrom<f32> for Decimal {
+    type Error = crate::CommonError;
+
+    /// Returns `Err` if `value` is not finite. Decimal places beyond the
+    /// 18 supported by `Decimal` are rounded away.
+    fn try_from(value: f32) -> Result<Self> {
+        if !value.is_finite() {
+            return Err(CommonError::DecimalError);
+        }
+        Self::try_from_str(&value.to_string())
+            .or_else(|_| Self::try_from_str(&format!("{:.18}", value)))
+    }
+}
+
+impl Decimal {
+    /// Formats `self` for display using the separators of `locale`, rounded
+    /// to at most `max_significant_digits` significant digits (at least one),
+    /// e.g. `"1,234,567.9"` for `1234567.89` with 8 significant digits in
+    /// `en-US`.
+    ///
+    /// Values so close to the bounds of `Decimal` that rounding them would
+    /// overflow, e.g. the largest `Decimal` rounded up, are formatted
+    /// unrounded with all their significant digits.
+    pub fn formatted(
+        &self,
+        locale: &LocaleConfig,
+        max_significant_digits: u8,
+        use_grouping_separator: bool,
+    ) -> String {
+        let rounded =
+            self.rounded_to_significant_digits(max_significant_digits.max(1));
+        let string = rounded.to_string();
+        let (sign, unsigned) = match string.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", string.as_str()),
+        };
+        let (integer, fraction) =
+            unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let integer = if use_grouping_separator {
+            integer
+                .chars()
+                .collect_vec()
+                .rchunks(3)
+                .rev()
+                .map(|digits| digits.iter().collect::<String>())
+                .join(&locale.grouping_separator)
+        } else {
+            integer.to_owned()
+        };
+        if fraction.is_empty() {
+            format!("{}{}", sign, integer)
+        } else {
+            format!(
+                "{}{}{}{}",
+                sign, integer, locale.decimal_separator, fraction
+            )
+        }
+    }
+
+    /// Rounds `self` to `digits` significant digits, midpoints away from zero.
+    /// Returns `self` unchanged if rounding would overflow.
+    fn rounded_to_significant_digits(&self, digits: u8) -> Self {
+        let string = self.to_string();
+        let unsigned = string.trim_start_matches('-');
+        let (integer, fraction) =
+            unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        // Position of the most significant digit relative to the decimal point.
+        let magnitude = if integer != "0" {
+            integer.len() as i32
+        } else {
+            -(fraction.chars().take_while(|c| *c == '0').count() as i32)
+        };
+        let decimal_places = digits as i32 - magnitude;
+        let mode = RoundingMode::ToNearestMidpointAwayFromZero;
+
+        let rounded = if decimal_places >= 0 {
+            self.round(decimal_places.min(u8::MAX as i32) as u8, mode)
+        } else {
+            Self::new(format!("1{}", "0".repeat(-decimal_places as usize)))
+                .and_then(|factor| {
+                    self.checked_div(&factor)
+                        .and_then(|d| d.round(0, mode))
+                        .and_then(|d| d.checked_mul(&factor))
+                })
+        };
+        rounded.unwrap_or_else(|_| *self)
+    }
+}
+
+#[uniffi::export]
+pub fn new_decimal_from_string(string: String) -> Result<Decimal> {
+    Decimal::new(string)
+}
+
+#[uniffi::export]
+pub fn new_decimal_from_i64(value: i64) -> Decimal {
+    value.into()
+}
+
+#[uniffi::export]
+pub fn new_decimal_from_f32(value: f32) -> Result<Decimal> {
+    value.try_into()
+}
+
+#[uniffi::export]
+pub fn decimal_to_i64(decimal: &Decimal) -> Result<i64> {
+    decimal.to_i64()
+}
+
+#[uniffi::export]
+pub fn decimal_to_f32(decimal: &Decimal) -> Result<f32> {
+    decimal.to_f32()
+}
+
+/// `lhs + rhs`, throws if the result overflows.
+#[uniffi::export]
+pub fn decimal_add(lhs: &Decimal, rhs: &Decimal) -> Result<Decimal> {
+    lhs.checked_add(rhs)
+}
+
+/// `lhs - rhs`, throws if the result overflows.
+#[uniffi::export]
+pub fn decimal_sub(lhs: &Decimal, rhs: &Decimal) -> Result<Decimal> {
+    lhs.checked_sub(rhs)
+}
+
+/// `lhs * rhs`, throws if the result overflows.
+#[uniffi::export]
+pub fn decimal_mul(lhs: &Decimal, rhs: &Decimal) -> Result<Decimal> {
+    lhs.checked_mul(rhs)
+}
+
+/// `lhs / rhs`, throws if `rhs` is zero or if the result overflows.
+#[uniffi::export]
+pub fn decimal_div(lhs: &Decimal, rhs: &Decimal) -> Result<Decimal> {
+    lhs.checked_div(rhs)
+}
+
+#[uniffi::export]
+pub fn decimal_abs(decimal: &Decimal) -> Result<Decimal> {
+    decimal.abs()
+}
+
+#[uniffi::export]
+pub fn decimal_round(
+    decimal: &Decimal,
+    decimal_places: u8,
+    rounding_mode: RoundingMode,
+) -> Result<Decimal> {
+    decimal.round(decimal_places, rounding_mode)
+}
+
+#[uniffi::export]
+pub fn decimal_formatted(
+    decimal: &Decimal,
+    locale: LocaleConfig,
+    max_significant_digits: u8,
+    use_grouping_separator: bool,
+) -> String {
+    decimal.formatted(&locale, max_significant_digits, use_grouping_separator)
+}
+
 impl TryInto<Decimal> for &str {
     type Error = crate::CommonError;
 
@@ -265,6 +511,204 @@ mod tests {
         assert_json_value_ne_after_roundtrip(&a, json!("3.1415"));
     }
 
+    const MAX: &str =
+        "3138550867693340381917894711603833208051.177722232017256447";
+    const MIN: &str =
+        "-3138550867693340381917894711603833208051.177722232017256448";
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::try_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn checked_add() {
+        assert_eq!(dec("1.5").checked_add(&dec("2.25")), Ok(dec("3.75")));
+        assert_eq!(
+            dec(MAX).checked_add(&Decimal::one()),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn checked_sub() {
+        assert_eq!(dec("1.5").checked_sub(&dec("2.25")), Ok(dec("-0.75")));
+        assert_eq!(
+            dec(MIN).checked_sub(&Decimal::one()),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn checked_mul() {
+        assert_eq!(dec("1.5").checked_mul(&dec("-4")), Ok(dec("-6")));
+        assert_eq!(
+            dec(MAX).checked_mul(&dec("2")),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn checked_div() {
+        assert_eq!(dec("1").checked_div(&dec("8")), Ok(dec("0.125")));
+        assert_eq!(
+            Decimal::one().checked_div(&Decimal::zero()),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(dec("-1.5").abs(), Ok(dec("1.5")));
+        assert_eq!(dec("1.5").abs(), Ok(dec("1.5")));
+        assert_eq!(dec(MIN).abs(), Err(CommonError::DecimalError));
+    }
+
+    #[test]
+    fn round() {
+        let sut = dec("-2.55");
+        let round = |mode| sut.round(1, mode).unwrap();
+        assert_eq!(round(RoundingMode::ToPositiveInfinity), dec("-2.5"));
+        assert_eq!(round(RoundingMode::ToNegativeInfinity), dec("-2.6"));
+        assert_eq!(round(RoundingMode::ToZero), dec("-2.5"));
+        assert_eq!(round(RoundingMode::AwayFromZero), dec("-2.6"));
+        assert_eq!(
+            round(RoundingMode::ToNearestMidpointTowardZero),
+            dec("-2.5")
+        );
+        assert_eq!(
+            round(RoundingMode::ToNearestMidpointAwayFromZero),
+            dec("-2.6")
+        );
+        assert_eq!(round(RoundingMode::ToNearestMidpointToEven), dec("-2.6"));
+    }
+
+    #[test]
+    fn round_beyond_scale_is_unchanged() {
+        let sut = dec("0.123456789012345678");
+        assert_eq!(sut.round(18, RoundingMode::ToZero), Ok(sut));
+        assert_eq!(sut.round(u8::MAX, RoundingMode::ToZero), Ok(sut));
+    }
+
+    #[test]
+    fn round_overflow() {
+        assert_eq!(
+            dec(MAX).round(0, RoundingMode::AwayFromZero),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn from_i64() {
+        assert_eq!(Decimal::from(-1337i64), dec("-1337"));
+        assert_eq!(Decimal::from(i64::MAX), dec(&i64::MAX.to_string()));
+    }
+
+    #[test]
+    fn to_i64() {
+        assert_eq!(dec("-1337").to_i64(), Ok(-1337));
+        assert_eq!(Decimal::from(i64::MIN).to_i64(), Ok(i64::MIN));
+        assert_eq!(dec("1.5").to_i64(), Err(CommonError::DecimalError));
+        assert_eq!(
+            Decimal::from(i64::MAX)
+                .checked_add(&Decimal::one())
+                .unwrap()
+                .to_i64(),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn try_from_f32() {
+        assert_eq!(Decimal::try_from(1.5f32), Ok(dec("1.5")));
+        assert_eq!(Decimal::try_from(-0.25f32), Ok(dec("-0.25")));
+        assert_eq!(Decimal::try_from(1e-30f32), Ok(Decimal::zero()));
+        assert_eq!(Decimal::try_from(f32::NAN), Err(CommonError::DecimalError));
+        assert_eq!(
+            Decimal::try_from(f32::INFINITY),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn to_f32() {
+        assert_eq!(dec("1.5").to_f32(), Ok(1.5));
+        assert_eq!(dec("-0.25").to_f32(), Ok(-0.25));
+    }
+
+    #[test]
+    fn to_f32_too_large() {
+        assert_eq!(dec(MAX).to_f32(), Err(CommonError::DecimalError));
+        assert_eq!(dec(MIN).to_f32(), Err(CommonError::DecimalError));
+    }
+
+    #[test]
+    fn formatted_english_us() {
+        let locale = LocaleConfig::english_us();
+        let sut = dec("1234567.89");
+        assert_eq!(sut.formatted(&locale, 10, true), "1,234,567.89");
+        assert_eq!(sut.formatted(&locale, 8, true), "1,234,567.9");
+        assert_eq!(sut.formatted(&locale, 3, true), "1,230,000");
+        assert_eq!(sut.formatted(&locale, 10, false), "1234567.89");
+    }
+
+    #[test]
+    fn formatted_other_locale() {
+        let locale = LocaleConfig::placeholder_other();
+        assert_eq!(
+            dec("-1234567.89").formatted(&locale, 10, true),
+            "-1 234 567,89"
+        );
+    }
+
+    #[test]
+    fn formatted_small_and_zero() {
+        let locale = LocaleConfig::english_us();
+        assert_eq!(dec("0.00012345").formatted(&locale, 3, true), "0.000123");
+        assert_eq!(dec("0.5").formatted(&locale, 0, true), "0.5");
+        assert_eq!(Decimal::zero().formatted(&locale, 8, true), "0");
+        assert_eq!(dec("999.96").formatted(&locale, 4, true), "1,000");
+    }
+
+    #[test]
+    fn formatted_overflowing_rounding_is_unrounded() {
+        // rounding to one decimal place would round `MAX` up, overflowing
+        assert_eq!(
+            dec(MAX).formatted(&LocaleConfig::english_us(), 41, false),
+            MAX
+        );
+        assert_eq!(
+            dec(MAX).formatted(&LocaleConfig::english_us(), 1, false),
+            format!("3{}", "0".repeat(39))
+        );
+    }
+
+    #[test]
+    fn default_is_zero() {
+        assert_eq!(Decimal::default(), Decimal::zero());
+    }
+
+    #[test]
+    fn uniffi_lift_invalid_string_fails() {
+        use crate::UniffiCustomTypeConverter;
+        assert!(<Decimal as UniffiCustomTypeConverter>::into_custom(
+            "apabanan".to_owned()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn uniffi_lift_lower_roundtrip() {
+        use crate::UniffiCustomTypeConverter;
+        let sut = dec(MAX);
+        let lowered = <Decimal as UniffiCustomTypeConverter>::from_custom(sut);
+        assert_eq!(lowered, MAX);
+        assert_eq!(
+            <Decimal as UniffiCustomTypeConverter>::into_custom(lowered)
+                .unwrap(),
+            sut
+        );
+    }
+
     #[test]
     fn hash() {
         let n = 100;
@@ -276,3 +720,60 @@ mod tests {
         assert_eq!(set.len(), n);
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    fn dec(s: &str) -> Decimal {
+        new_decimal_from_string(s.to_owned()).unwrap()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = dec("3");
+        let b = dec("2");
+        assert_eq!(decimal_add(&a, &b), Ok(dec("5")));
+        assert_eq!(decimal_sub(&a, &b), Ok(dec("1")));
+        assert_eq!(decimal_mul(&a, &b), Ok(dec("6")));
+        assert_eq!(decimal_div(&a, &b), Ok(dec("1.5")));
+        assert_eq!(
+            decimal_div(&a, &Decimal::zero()),
+            Err(CommonError::DecimalError)
+        );
+        assert_eq!(decimal_abs(&dec("-3")), Ok(a));
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(
+            decimal_round(
+                &dec("2.5"),
+                0,
+                RoundingMode::ToNearestMidpointToEven
+            ),
+            Ok(dec("2"))
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(new_decimal_from_i64(42), dec("42"));
+        assert_eq!(decimal_to_i64(&dec("42")), Ok(42));
+        assert_eq!(new_decimal_from_f32(0.5), Ok(dec("0.5")));
+        assert_eq!(decimal_to_f32(&dec("0.5")), Ok(0.5));
+    }
+
+    #[test]
+    fn formatted() {
+        assert_eq!(
+            decimal_formatted(
+                &dec("1234.5"),
+                LocaleConfig::placeholder_other(),
+                4,
+                true
+            ),
+            "1 235"
+        );
+    }
+}
@@ -1,9 +1,7 @@
 use crate::prelude::*;
 
 use radix_engine_common::crypto::IsHash;
-use transaction::signing::ed25519::{
-    Ed25519PrivateKey as EngineEd25519PrivateKey, Ed25519Signature,
-};
+use transaction::signing::ed25519::Ed25519PrivateKey as EngineEd25519PrivateKey;
 
 /// An Ed25519 private key used to create cryptographic signatures, using
 /// EdDSA scheme.
@@ -44,7 +42,7 @@ impl IsPrivateKey<Ed25519PublicKey> for Ed25519PrivateKey {
     }
 
     fn sign(&self, msg_hash: &impl IsHash) -> Ed25519Signature {
-        self.0.sign(msg_hash)
+        self.0.sign(msg_hash).into()
     }
 }
 
@@ -142,8 +140,6 @@ impl Ed25519PrivateKey {
 mod tests {
     use crate::prelude::*;
 
-    use transaction::signing::ed25519::Ed25519Signature;
-
     #[test]
     fn equality() {
         assert_eq!(
@@ -3,9 +3,7 @@ use crate::prelude::*;
 use radix_engine_common::crypto::{
     Ed25519PublicKey as EngineEd25519PublicKey, IsHash,
 };
-use transaction::{
-    signing::ed25519::Ed25519Signature, validation::verify_ed25519,
-};
+use transaction::validation::verify_ed25519;
 
 /// An Ed25519 public key used to verify cryptographic signatures (EdDSA signatures).
 #[serde_as]
@@ -78,7 +76,11 @@ impl IsPublicKey<Ed25519Signature> for Ed25519PublicKey {
         signature: &Ed25519Signature,
         for_hash: &impl IsHash,
     ) -> bool {
-        verify_ed25519(for_hash.as_hash(), &self.to_engine(), signature)
+        verify_ed25519(
+            for_hash.as_hash(),
+            &self.to_engine(),
+            &signature.to_engine(),
+        )
     }
 }
 
@@ -0,0 +1,179 @@
+use crate::prelude::*;
+
+use transaction::signing::ed25519::Ed25519Signature as EngineEd25519Signature;
+
+/// An EdDSA signature over Curve25519, produced by an `Ed25519PrivateKey`,
+/// which always serializes as a **hex** string.
+#[serde_as]
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    derive_more::Debug,
+    uniffi::Record,
+)]
+#[serde(transparent)]
+#[display("{}", self.to_hex())]
+#[debug("{}", self.to_hex())]
+pub struct Ed25519Signature {
+    #[serde_as(as = "serde_with::hex::Hex")]
+    value: Vec<u8>, // FIXME: change to `transaction::signing::ed25519::Ed25519Signature` once we have proper UniFFI lift/lower/UniffiCustomTypeConverter
+}
+
+#[uniffi::export]
+pub fn new_ed25519_signature_from_bytes(
+    bytes: Vec<u8>,
+) -> Result<Ed25519Signature> {
+    Ed25519Signature::from_bytes(bytes)
+}
+
+/// Encodes the `Ed25519Signature` (64 bytes) to a hexadecimal string, lowercased,
+/// without any `0x` prefix.
+#[uniffi::export]
+pub fn ed25519_signature_to_hex(signature: &Ed25519Signature) -> String {
+    signature.to_hex()
+}
+
+impl Ed25519Signature {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        EngineEd25519Signature::try_from(bytes.as_slice())
+            .map_err(|_| CommonError::InvalidEd25519SignatureFromBytes(bytes))
+            .map(Self::from_engine)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.value.clone()
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex_encode(self.to_bytes())
+    }
+}
+
+impl Ed25519Signature {
+    pub(crate) fn to_engine(&self) -> EngineEd25519Signature {
+        EngineEd25519Signature::try_from(self.value.as_slice()).unwrap()
+    }
+
+    pub(crate) fn from_engine(engine: EngineEd25519Signature) -> Self {
+        Self {
+            value: engine.to_vec(),
+        }
+    }
+}
+
+impl From<EngineEd25519Signature> for Ed25519Signature {
+    fn from(value: EngineEd25519Signature) -> Self {
+        Self::from_engine(value)
+    }
+}
+
+impl FromStr for Ed25519Signature {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        hex_decode(s)
+            .map_err(|_| CommonError::StringNotHex(s.to_owned()))
+            .and_then(Self::from_bytes)
+    }
+}
+
+impl HasPlaceholder for Ed25519Signature {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Ed25519PrivateKey::placeholder_alice().sign(&hash("Test"))
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Ed25519PrivateKey::placeholder_bob().sign(&hash("Test"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            Ed25519Signature::placeholder(),
+            Ed25519Signature::placeholder()
+        );
+        assert_eq!(
+            Ed25519Signature::placeholder_other(),
+            Ed25519Signature::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            Ed25519Signature::placeholder(),
+            Ed25519Signature::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let hex = "cf0ca64435609b85ab170da339d415bbac87d678dfd505969be20adc6b5971f4ee4b4620c602bcbc34fd347596546675099d696265f4a42a16df343da1af980e";
+        let sut = Ed25519Signature::from_str(hex).unwrap();
+        assert_eq!(sut.to_hex(), hex);
+        assert_eq!(sut.to_string(), hex);
+    }
+
+    #[test]
+    fn engine_roundtrip() {
+        let sut = Ed25519Signature::placeholder();
+        assert_eq!(Ed25519Signature::from_engine(sut.to_engine()), sut);
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        assert_eq!(
+            Ed25519Signature::from_bytes(vec![0u8; 5]),
+            Err(CommonError::InvalidEd25519SignatureFromBytes(vec![0u8; 5]))
+        );
+    }
+
+    #[test]
+    fn invalid_hex() {
+        assert_eq!(
+            Ed25519Signature::from_str("not hex"),
+            Err(CommonError::StringNotHex("not hex".to_owned()))
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Ed25519Signature::from_str("cf0ca64435609b85ab170da339d415bbac87d678dfd505969be20adc6b5971f4ee4b4620c602bcbc34fd347596546675099d696265f4a42a16df343da1af980e").unwrap();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!("cf0ca64435609b85ab170da339d415bbac87d678dfd505969be20adc6b5971f4ee4b4620c602bcbc34fd347596546675099d696265f4a42a16df343da1af980e"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn bytes_roundtrip() {
+        let sut = Ed25519Signature::placeholder();
+        assert_eq!(
+            new_ed25519_signature_from_bytes(sut.to_bytes()).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn to_hex() {
+        let sut = Ed25519Signature::placeholder();
+        assert_eq!(ed25519_signature_to_hex(&sut), sut.to_hex());
+    }
+}
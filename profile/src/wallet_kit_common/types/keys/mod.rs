@@ -4,6 +4,8 @@ mod is_public_key;
 mod private_key;
 mod public_key;
 mod secp256k1;
+mod signature;
+mod signature_with_public_key;
 mod slip10_curve;
 
 pub use ed25519::*;
@@ -12,4 +14,6 @@ pub use is_public_key::*;
 pub use private_key::*;
 pub use public_key::*;
 pub use secp256k1::*;
+pub use signature::*;
+pub use signature_with_public_key::*;
 pub use slip10_curve::*;
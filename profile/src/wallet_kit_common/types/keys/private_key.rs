@@ -1,4 +1,7 @@
 use crate::prelude::*;
+
+use radix_engine_common::crypto::IsHash;
+
 /// A tagged union of supported private keys on different curves, supported
 /// curves are `secp256k1` and `Curve25519`
 #[derive(Debug, PartialEq, Eq, EnumAsInner)]
@@ -53,6 +56,21 @@ impl PrivateKey {
         }
     }
 
+    /// Signs the `msg_hash` with the inner private key, returning the signature
+    /// together with the public key of the inner private key.
+    pub fn sign(&self, msg_hash: &impl IsHash) -> SignatureWithPublicKey {
+        match self {
+            PrivateKey::Ed25519(key) => SignatureWithPublicKey::Ed25519 {
+                public_key: key.public_key(),
+                signature: key.sign(msg_hash),
+            },
+            PrivateKey::Secp256k1(key) => SignatureWithPublicKey::Secp256k1 {
+                public_key: key.public_key(),
+                signature: key.sign(msg_hash),
+            },
+        }
+    }
+
     /// Returns the hex representation of the inner private key's bytes as a `Vec`.
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
@@ -134,6 +152,29 @@ mod tests {
         assert_eq!(set.len(), n);
     }
 
+    #[test]
+    fn sign_ed25519() {
+        let msg = hash("Test");
+        let key = Ed25519PrivateKey::placeholder();
+        let sut: PrivateKey = Ed25519PrivateKey::placeholder().into();
+        assert_eq!(
+            sut.sign(&msg),
+            SignatureWithPublicKey::Ed25519 {
+                public_key: key.public_key(),
+                signature: key.sign(&msg)
+            }
+        );
+    }
+
+    #[test]
+    fn sign_secp256k1() {
+        let msg = hash("Test");
+        let key = Secp256k1PrivateKey::placeholder();
+        let sut: PrivateKey = Secp256k1PrivateKey::placeholder().into();
+        assert_eq!(sut.sign(&msg).public_key(), sut.public_key());
+        assert_eq!(sut.sign(&msg).signature(), key.sign(&msg).into());
+    }
+
     #[test]
     fn secp256k1_to_bytes() {
         let bytes = generate_32_bytes();
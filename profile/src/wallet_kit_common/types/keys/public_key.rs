@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-use radix_engine_common::crypto::PublicKey as EnginePublicKey;
+use radix_engine_common::crypto::{Hash, IsHash, PublicKey as EnginePublicKey};
 
 /// A tagged union of supported public keys on different curves, supported
 /// curves are `secp256k1` and `Curve25519`
@@ -73,6 +73,40 @@ impl PublicKey {
     }
 }
 
+/// Returns `true` if `signature` is a valid signature of `hash` by the private
+/// key of `public_key`.
+#[uniffi::export]
+pub fn public_key_verify(
+    public_key: &PublicKey,
+    signature: &Signature,
+    hash: Hex32Bytes,
+) -> bool {
+    public_key.verify(signature, &Hash::from_bytes(hash.bytes()))
+}
+
+impl PublicKey {
+    /// Verifies that `signature` is a valid signature of `for_hash` by the
+    /// private key of this public key, always returns `false` if the curve
+    /// of `signature` differs from the curve of this public key.
+    pub fn verify(
+        &self,
+        signature: &Signature,
+        for_hash: &impl IsHash,
+    ) -> bool {
+        match (self, signature) {
+            (
+                PublicKey::Ed25519 { value: key },
+                Signature::Ed25519 { value: signature },
+            ) => key.is_valid(signature, for_hash),
+            (
+                PublicKey::Secp256k1 { value: key },
+                Signature::Secp256k1 { value: signature },
+            ) => key.is_valid(signature, for_hash),
+            _ => false,
+        }
+    }
+}
+
 impl PublicKey {
     /// Returns a `SLIP10Curve`, being the curve of the `PublicKey`.
     pub fn curve(&self) -> SLIP10Curve {
@@ -299,6 +333,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_ed25519() {
+        let private_key = Ed25519PrivateKey::placeholder();
+        let sut: PublicKey = private_key.public_key().into();
+        let signature: Signature = private_key.sign(&hash("Test")).into();
+        assert!(sut.verify(&signature, &hash("Test")));
+        assert!(!sut.verify(&signature, &hash("Tost")));
+    }
+
+    #[test]
+    fn verify_secp256k1() {
+        let private_key = Secp256k1PrivateKey::placeholder();
+        let sut: PublicKey = private_key.public_key().into();
+        let signature: Signature = private_key.sign(&hash("Test")).into();
+        assert!(sut.verify(&signature, &hash("Test")));
+        assert!(!sut.verify(&signature, &hash("Tost")));
+    }
+
+    #[test]
+    fn verify_signature_on_other_curve_is_invalid() {
+        assert!(!PublicKey::placeholder_ed25519()
+            .verify(&Signature::placeholder_other(), &hash("Test")));
+        assert!(!PublicKey::placeholder_secp256k1()
+            .verify(&Signature::placeholder(), &hash("Test")));
+    }
+
+    #[test]
+    fn verify_signature_by_other_key_is_invalid() {
+        assert!(!PublicKey::placeholder_ed25519_bob()
+            .verify(&Signature::placeholder(), &hash("Test")));
+    }
+
     #[test]
     fn inequality_secp256k1() {
         assert_ne!(
@@ -422,3 +488,25 @@ mod tests {
         assert_eq!(key.as_secp256k1().unwrap(), &secp256k1);
     }
 }
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    use radix_engine_common::crypto::Hash;
+
+    #[test]
+    fn verify() {
+        let hash = Hex32Bytes::placeholder();
+        let private_key = Secp256k1PrivateKey::placeholder();
+        let public_key: PublicKey = private_key.public_key().into();
+        let signature: Signature =
+            private_key.sign(&Hash::from_bytes(hash.bytes())).into();
+        assert!(public_key_verify(&public_key, &signature, hash));
+        assert!(!public_key_verify(
+            &public_key,
+            &signature,
+            Hex32Bytes::placeholder_other()
+        ));
+    }
+}
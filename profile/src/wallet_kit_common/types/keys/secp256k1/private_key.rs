@@ -1,9 +1,7 @@
 use crate::prelude::*;
 
 use radix_engine_common::crypto::IsHash;
-use transaction::signing::secp256k1::{
-    Secp256k1PrivateKey as EngineSecp256k1PrivateKey, Secp256k1Signature,
-};
+use transaction::signing::secp256k1::Secp256k1PrivateKey as EngineSecp256k1PrivateKey;
 
 /// A secp256k1 private key used to create cryptographic signatures, more specifically
 /// ECDSA signatures, that offer recovery of the public key.
@@ -96,7 +94,7 @@ impl IsPrivateKey<Secp256k1PublicKey> for Secp256k1PrivateKey {
     }
 
     fn sign(&self, msg_hash: &impl IsHash) -> Secp256k1Signature {
-        self.0.sign(msg_hash)
+        self.0.sign(msg_hash).into()
     }
 }
 
@@ -145,7 +143,6 @@ impl Secp256k1PrivateKey {
 mod tests {
 
     use crate::prelude::*;
-    use transaction::signing::secp256k1::Secp256k1Signature;
 
     #[test]
     fn equality() {
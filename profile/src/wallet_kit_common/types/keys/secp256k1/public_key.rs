@@ -4,9 +4,7 @@ use bip32::secp256k1::PublicKey as BIP32Secp256k1PublicKey;
 use radix_engine_common::crypto::{
     Hash, IsHash, Secp256k1PublicKey as EngineSecp256k1PublicKey,
 };
-use transaction::{
-    signing::secp256k1::Secp256k1Signature, validation::verify_secp256k1,
-};
+use transaction::validation::verify_secp256k1;
 
 /// A `secp256k1` public key used to verify cryptographic signatures (ECDSA signatures).
 #[serde_as]
@@ -81,7 +79,11 @@ impl IsPublicKey<Secp256k1Signature> for Secp256k1PublicKey {
         signature: &Secp256k1Signature,
         for_hash: &impl IsHash,
     ) -> bool {
-        verify_secp256k1(for_hash.as_hash(), &self.to_engine(), signature)
+        verify_secp256k1(
+            for_hash.as_hash(),
+            &self.to_engine(),
+            &signature.to_engine(),
+        )
     }
 }
 
@@ -0,0 +1,183 @@
+use crate::prelude::*;
+
+use transaction::signing::secp256k1::Secp256k1Signature as EngineSecp256k1Signature;
+
+/// An ECDSA signature over secp256k1, produced by a `Secp256k1PrivateKey`,
+/// which offers recovery of the public key and always serializes as a **hex**
+/// string.
+#[serde_as]
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    derive_more::Debug,
+    uniffi::Record,
+)]
+#[serde(transparent)]
+#[display("{}", self.to_hex())]
+#[debug("{}", self.to_hex())]
+pub struct Secp256k1Signature {
+    #[serde_as(as = "serde_with::hex::Hex")]
+    value: Vec<u8>, // FIXME: change to `transaction::signing::secp256k1::Secp256k1Signature` once we have proper UniFFI lift/lower/UniffiCustomTypeConverter
+}
+
+#[uniffi::export]
+pub fn new_secp256k1_signature_from_bytes(
+    bytes: Vec<u8>,
+) -> Result<Secp256k1Signature> {
+    Secp256k1Signature::from_bytes(bytes)
+}
+
+/// Encodes the `Secp256k1Signature` (65 bytes) to a hexadecimal string, lowercased,
+/// without any `0x` prefix.
+#[uniffi::export]
+pub fn secp256k1_signature_to_hex(signature: &Secp256k1Signature) -> String {
+    signature.to_hex()
+}
+
+impl Secp256k1Signature {
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        EngineSecp256k1Signature::try_from(bytes.as_slice())
+            .map_err(|_| CommonError::InvalidSecp256k1SignatureFromBytes(bytes))
+            .map(Self::from_engine)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.value.clone()
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex_encode(self.to_bytes())
+    }
+}
+
+impl Secp256k1Signature {
+    pub(crate) fn to_engine(&self) -> EngineSecp256k1Signature {
+        EngineSecp256k1Signature::try_from(self.value.as_slice()).unwrap()
+    }
+
+    pub(crate) fn from_engine(engine: EngineSecp256k1Signature) -> Self {
+        Self {
+            value: engine.to_vec(),
+        }
+    }
+}
+
+impl From<EngineSecp256k1Signature> for Secp256k1Signature {
+    fn from(value: EngineSecp256k1Signature) -> Self {
+        Self::from_engine(value)
+    }
+}
+
+impl FromStr for Secp256k1Signature {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        hex_decode(s)
+            .map_err(|_| CommonError::StringNotHex(s.to_owned()))
+            .and_then(Self::from_bytes)
+    }
+}
+
+impl HasPlaceholder for Secp256k1Signature {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Secp256k1PrivateKey::placeholder_alice().sign(&hash("Test"))
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Secp256k1PrivateKey::placeholder_bob().sign(&hash("Test"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            Secp256k1Signature::placeholder(),
+            Secp256k1Signature::placeholder()
+        );
+        assert_eq!(
+            Secp256k1Signature::placeholder_other(),
+            Secp256k1Signature::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            Secp256k1Signature::placeholder(),
+            Secp256k1Signature::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let hex = "00eb8dcd5bb841430dd0a6f45565a1b8bdb4a204eb868832cd006f963a89a662813ab844a542fcdbfda4086a83fbbde516214113051b9c8e42a206c98d564d7122";
+        let sut = Secp256k1Signature::from_str(hex).unwrap();
+        assert_eq!(sut.to_hex(), hex);
+        assert_eq!(sut.to_string(), hex);
+    }
+
+    #[test]
+    fn engine_roundtrip() {
+        let sut = Secp256k1Signature::placeholder();
+        assert_eq!(Secp256k1Signature::from_engine(sut.to_engine()), sut);
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        assert_eq!(
+            Secp256k1Signature::from_bytes(vec![0u8; 5]),
+            Err(CommonError::InvalidSecp256k1SignatureFromBytes(vec![
+                0u8;
+                5
+            ]))
+        );
+    }
+
+    #[test]
+    fn invalid_hex() {
+        assert_eq!(
+            Secp256k1Signature::from_str("not hex"),
+            Err(CommonError::StringNotHex("not hex".to_owned()))
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Secp256k1Signature::from_str("00eb8dcd5bb841430dd0a6f45565a1b8bdb4a204eb868832cd006f963a89a662813ab844a542fcdbfda4086a83fbbde516214113051b9c8e42a206c98d564d7122").unwrap();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!("00eb8dcd5bb841430dd0a6f45565a1b8bdb4a204eb868832cd006f963a89a662813ab844a542fcdbfda4086a83fbbde516214113051b9c8e42a206c98d564d7122"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn bytes_roundtrip() {
+        let sut = Secp256k1Signature::placeholder();
+        assert_eq!(
+            new_secp256k1_signature_from_bytes(sut.to_bytes()).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn to_hex() {
+        let sut = Secp256k1Signature::placeholder();
+        assert_eq!(secp256k1_signature_to_hex(&sut), sut.to_hex());
+    }
+}
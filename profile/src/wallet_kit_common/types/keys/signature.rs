@@ -0,0 +1,210 @@
+use crate::prelude::*;
+
+/// A tagged union of supported signatures on different curves, supported
+/// curves are `secp256k1` and `Curve25519`, which always serializes as a
+/// **hex** string.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumAsInner,
+    SerializeDisplay,
+    DeserializeFromStr,
+    uniffi::Enum,
+)]
+pub enum Signature {
+    /// An EdDSA signature produced by an `Ed25519PrivateKey`.
+    Ed25519 { value: Ed25519Signature },
+
+    /// An ECDSA signature produced by a `Secp256k1PrivateKey`, which offers
+    /// recovery of the public key.
+    Secp256k1 { value: Secp256k1Signature },
+}
+
+#[uniffi::export]
+pub fn new_signature_from_hex(hex: String) -> Result<Signature> {
+    Signature::from_str(&hex)
+}
+
+/// Encodes the inner signature to a hexadecimal string, lowercased, without
+/// any `0x` prefix.
+#[uniffi::export]
+pub fn signature_to_hex(signature: &Signature) -> String {
+    signature.to_hex()
+}
+
+impl From<Ed25519Signature> for Signature {
+    fn from(value: Ed25519Signature) -> Self {
+        Self::Ed25519 { value }
+    }
+}
+
+impl From<Secp256k1Signature> for Signature {
+    fn from(value: Secp256k1Signature) -> Self {
+        Self::Secp256k1 { value }
+    }
+}
+
+impl Signature {
+    /// Returns a `SLIP10Curve`, being the curve of the `Signature`.
+    pub fn curve(&self) -> SLIP10Curve {
+        match self {
+            Signature::Ed25519 { value: _ } => SLIP10Curve::Curve25519,
+            Signature::Secp256k1 { value: _ } => SLIP10Curve::Secp256k1,
+        }
+    }
+
+    /// Returns a hex encoding of the inner signature.
+    pub fn to_hex(&self) -> String {
+        match self {
+            Signature::Ed25519 { value } => value.to_hex(),
+            Signature::Secp256k1 { value } => value.to_hex(),
+        }
+    }
+
+    /// Returns a clone of the bytes of the inner signature as a `Vec`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Signature::Ed25519 { value } => value.to_bytes(),
+            Signature::Secp256k1 { value } => value.to_bytes(),
+        }
+    }
+}
+
+impl std::fmt::Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl FromStr for Signature {
+    type Err = CommonError;
+
+    /// Tries to decode the hex string `s` into a `Signature`, the curve is
+    /// inferred from the number of bytes, 64 for `Ed25519Signature` and 65
+    /// for `Secp256k1Signature`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex_decode(s)
+            .map_err(|_| CommonError::StringNotHex(s.to_owned()))?;
+        if bytes.len() == 64 {
+            Ed25519Signature::from_bytes(bytes).map(Self::from)
+        } else {
+            Secp256k1Signature::from_bytes(bytes).map(Self::from)
+        }
+    }
+}
+
+impl HasPlaceholder for Signature {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Ed25519Signature::placeholder().into()
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        Secp256k1Signature::placeholder().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Signature::placeholder(), Signature::placeholder());
+        assert_eq!(
+            Signature::placeholder_other(),
+            Signature::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Signature::placeholder(), Signature::placeholder_other());
+    }
+
+    #[test]
+    fn ed25519_into_as_roundtrip() {
+        let signature = Ed25519Signature::placeholder();
+        let sut: Signature = signature.clone().into();
+        assert_eq!(sut.as_ed25519(), Some(&signature));
+        assert!(sut.as_secp256k1().is_none());
+    }
+
+    #[test]
+    fn secp256k1_into_as_roundtrip() {
+        let signature = Secp256k1Signature::placeholder();
+        let sut: Signature = signature.clone().into();
+        assert_eq!(sut.as_secp256k1(), Some(&signature));
+        assert!(sut.as_ed25519().is_none());
+    }
+
+    #[test]
+    fn curve() {
+        assert_eq!(Signature::placeholder().curve(), SLIP10Curve::Curve25519);
+        assert_eq!(
+            Signature::placeholder_other().curve(),
+            SLIP10Curve::Secp256k1
+        );
+    }
+
+    #[test]
+    fn hex_roundtrip_ed25519() {
+        let hex = "cf0ca64435609b85ab170da339d415bbac87d678dfd505969be20adc6b5971f4ee4b4620c602bcbc34fd347596546675099d696265f4a42a16df343da1af980e";
+        let sut = Signature::from_str(hex).unwrap();
+        assert_eq!(sut.curve(), SLIP10Curve::Curve25519);
+        assert_eq!(sut.to_hex(), hex);
+        assert_eq!(sut.to_string(), hex);
+    }
+
+    #[test]
+    fn hex_roundtrip_secp256k1() {
+        let hex = "00eb8dcd5bb841430dd0a6f45565a1b8bdb4a204eb868832cd006f963a89a662813ab844a542fcdbfda4086a83fbbde516214113051b9c8e42a206c98d564d7122";
+        let sut = Signature::from_str(hex).unwrap();
+        assert_eq!(sut.curve(), SLIP10Curve::Secp256k1);
+        assert_eq!(sut.to_hex(), hex);
+        assert_eq!(sut.to_string(), hex);
+    }
+
+    #[test]
+    fn from_str_invalid_length() {
+        assert_eq!(
+            Signature::from_str("dead"),
+            Err(CommonError::InvalidSecp256k1SignatureFromBytes(vec![
+                0xde, 0xad
+            ]))
+        );
+    }
+
+    #[test]
+    fn to_bytes() {
+        let sut = Signature::placeholder();
+        assert_eq!(sut.to_bytes(), Ed25519Signature::placeholder().to_bytes());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Signature::placeholder_other();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!(Secp256k1Signature::placeholder().to_hex()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn hex_roundtrip() {
+        let sut = Signature::placeholder();
+        assert_eq!(
+            new_signature_from_hex(signature_to_hex(&sut)).unwrap(),
+            sut
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use crate::prelude::*;
+
+use radix_engine_common::crypto::{Hash, IsHash};
+
+/// A signature together with the public key of the private key which
+/// produced it, on one of the supported curves.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, EnumAsInner, uniffi::Enum)]
+pub enum SignatureWithPublicKey {
+    /// An ECDSA signature and the `Secp256k1PublicKey` of the signer.
+    Secp256k1 {
+        public_key: Secp256k1PublicKey,
+        signature: Secp256k1Signature,
+    },
+
+    /// An EdDSA signature and the `Ed25519PublicKey` of the signer.
+    Ed25519 {
+        public_key: Ed25519PublicKey,
+        signature: Ed25519Signature,
+    },
+}
+
+/// Returns `true` if the signature in `signature_with_public_key` is a valid
+/// signature of `hash` by the private key of its public key.
+#[uniffi::export]
+pub fn signature_with_public_key_verify(
+    signature_with_public_key: &SignatureWithPublicKey,
+    hash: Hex32Bytes,
+) -> bool {
+    signature_with_public_key.verify(&Hash::from_bytes(hash.bytes()))
+}
+
+impl SignatureWithPublicKey {
+    /// Instantiates a new `SignatureWithPublicKey` from a `PublicKey` and a
+    /// `Signature`, fails if they are not on the same curve.
+    pub fn new(public_key: PublicKey, signature: Signature) -> Result<Self> {
+        match (public_key, signature) {
+            (
+                PublicKey::Secp256k1 { value: public_key },
+                Signature::Secp256k1 { value: signature },
+            ) => Ok(Self::Secp256k1 {
+                public_key,
+                signature,
+            }),
+            (
+                PublicKey::Ed25519 { value: public_key },
+                Signature::Ed25519 { value: signature },
+            ) => Ok(Self::Ed25519 {
+                public_key,
+                signature,
+            }),
+            _ => Err(CommonError::SignatureWithPublicKeyCurveMismatch),
+        }
+    }
+
+    /// Returns the public key of the signer, wrapped in the `PublicKey`
+    /// tagged union.
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Secp256k1 { public_key, .. } => public_key.clone().into(),
+            Self::Ed25519 { public_key, .. } => public_key.clone().into(),
+        }
+    }
+
+    /// Returns the signature, wrapped in the `Signature` tagged union.
+    pub fn signature(&self) -> Signature {
+        match self {
+            Self::Secp256k1 { signature, .. } => signature.clone().into(),
+            Self::Ed25519 { signature, .. } => signature.clone().into(),
+        }
+    }
+
+    /// Returns `true` if the signature is a valid signature of `for_hash` by
+    /// the private key of the public key.
+    pub fn verify(&self, for_hash: &impl IsHash) -> bool {
+        match self {
+            Self::Secp256k1 {
+                public_key,
+                signature,
+            } => public_key.is_valid(signature, for_hash),
+            Self::Ed25519 {
+                public_key,
+                signature,
+            } => public_key.is_valid(signature, for_hash),
+        }
+    }
+}
+
+impl HasPlaceholder for SignatureWithPublicKey {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        PrivateKey::from(Ed25519PrivateKey::placeholder()).sign(&hash("Test"))
+    }
+
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder_other() -> Self {
+        PrivateKey::from(Secp256k1PrivateKey::placeholder()).sign(&hash("Test"))
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureWithPublicKey {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        #[derive(Deserialize, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wrapper {
+            public_key: PublicKey,
+            signature: Signature,
+        }
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        Self::new(wrapper.public_key, wrapper.signature)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for SignatureWithPublicKey {
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state =
+            serializer.serialize_struct("SignatureWithPublicKey", 2)?;
+        state.serialize_field("publicKey", &self.public_key())?;
+        state.serialize_field("signature", &self.signature())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            SignatureWithPublicKey::placeholder(),
+            SignatureWithPublicKey::placeholder()
+        );
+        assert_eq!(
+            SignatureWithPublicKey::placeholder_other(),
+            SignatureWithPublicKey::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            SignatureWithPublicKey::placeholder(),
+            SignatureWithPublicKey::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn ed25519_public_key_and_signature() {
+        let msg = hash("Test");
+        let private_key = Ed25519PrivateKey::placeholder();
+        let sut = SignatureWithPublicKey::placeholder();
+        assert_eq!(sut.public_key(), private_key.public_key().into());
+        assert_eq!(sut.signature(), private_key.sign(&msg).into());
+    }
+
+    #[test]
+    fn secp256k1_public_key_and_signature() {
+        let msg = hash("Test");
+        let private_key = Secp256k1PrivateKey::placeholder();
+        let sut = SignatureWithPublicKey::placeholder_other();
+        assert_eq!(sut.public_key(), private_key.public_key().into());
+        assert_eq!(sut.signature(), private_key.sign(&msg).into());
+    }
+
+    #[test]
+    fn new_curve_mismatch() {
+        assert_eq!(
+            SignatureWithPublicKey::new(
+                PublicKey::placeholder_ed25519(),
+                Signature::placeholder_other()
+            ),
+            Err(CommonError::SignatureWithPublicKeyCurveMismatch)
+        );
+    }
+
+    #[test]
+    fn verify() {
+        assert!(SignatureWithPublicKey::placeholder().verify(&hash("Test")));
+        assert!(
+            SignatureWithPublicKey::placeholder_other().verify(&hash("Test"))
+        );
+    }
+
+    #[test]
+    fn verify_wrong_hash() {
+        assert!(!SignatureWithPublicKey::placeholder().verify(&hash("Tost")));
+        assert!(
+            !SignatureWithPublicKey::placeholder_other().verify(&hash("Tost"))
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_ed25519() {
+        let sut = SignatureWithPublicKey::placeholder();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "publicKey": {
+                    "curve": "curve25519",
+                    "compressedData": Ed25519PublicKey::placeholder().to_hex()
+                },
+                "signature": Ed25519Signature::placeholder().to_hex()
+            }),
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_secp256k1() {
+        let sut = SignatureWithPublicKey::placeholder_other();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "publicKey": {
+                    "curve": "secp256k1",
+                    "compressedData": Secp256k1PublicKey::placeholder().to_hex()
+                },
+                "signature": Secp256k1Signature::placeholder().to_hex()
+            }),
+        );
+    }
+
+    #[test]
+    fn json_fails_curve_mismatch() {
+        assert_json_value_fails::<SignatureWithPublicKey>(json!({
+            "publicKey": {
+                "curve": "curve25519",
+                "compressedData": Ed25519PublicKey::placeholder().to_hex()
+            },
+            "signature": Secp256k1Signature::placeholder().to_hex()
+        }));
+    }
+}
+
+#[cfg(test)]
+mod uniffi_tests {
+    use crate::prelude::*;
+
+    use radix_engine_common::crypto::Hash;
+
+    #[test]
+    fn verify() {
+        let hash = Hex32Bytes::placeholder();
+        let private_key: PrivateKey = Ed25519PrivateKey::placeholder().into();
+        let sut = private_key.sign(&Hash::from_bytes(hash.bytes()));
+        assert!(signature_with_public_key_verify(&sut, hash));
+        assert!(!signature_with_public_key_verify(
+            &sut,
+            Hex32Bytes::placeholder_other()
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+
+/// The separators used when formatting numbers such as `Decimal` for display
+/// in a specific locale.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct LocaleConfig {
+    /// Separates the integer part from the fractional part, e.g. `"."` in
+    /// `en-US` and `","` in `sv-SE`.
+    pub decimal_separator: String,
+
+    /// Separates groups of three digits in the integer part, e.g. `","` in
+    /// `en-US` and `" "` in `sv-SE`.
+    pub grouping_separator: String,
+}
+
+impl LocaleConfig {
+    pub fn new(
+        decimal_separator: impl AsRef<str>,
+        grouping_separator: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            decimal_separator: decimal_separator.as_ref().to_owned(),
+            grouping_separator: grouping_separator.as_ref().to_owned(),
+        }
+    }
+
+    /// The separators of the `en-US` locale, `"."` and `","`.
+    pub fn english_us() -> Self {
+        Self::new(".", ",")
+    }
+}
+
+impl Default for LocaleConfig {
+    /// Uses the separators of the `en-US` locale.
+    fn default() -> Self {
+        Self::english_us()
+    }
+}
+
+impl HasPlaceholder for LocaleConfig {
+    /// A placeholder used to facilitate unit tests.
+    fn placeholder() -> Self {
+        Self::english_us()
+    }
+
+    /// A placeholder used to facilitate unit tests, the separators of `sv-SE`.
+    fn placeholder_other() -> Self {
+        Self::new(",", " ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(LocaleConfig::placeholder(), LocaleConfig::placeholder());
+        assert_eq!(
+            LocaleConfig::placeholder_other(),
+            LocaleConfig::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            LocaleConfig::placeholder(),
+            LocaleConfig::placeholder_other()
+        );
+    }
+
+    #[test]
+    fn default_is_english_us() {
+        assert_eq!(LocaleConfig::default(), LocaleConfig::new(".", ","));
+    }
+}
@@ -2,12 +2,16 @@ mod decimal;
 mod entity_kind;
 mod hex_32bytes;
 mod keys;
+mod locale_config;
 mod logged_result;
+mod rounding_mode;
 mod safe_to_log;
 
 pub use decimal::*;
 pub use entity_kind::*;
 pub use hex_32bytes::*;
 pub use keys::*;
+pub use locale_config::*;
 pub use logged_result::*;
+pub use rounding_mode::*;
 pub use safe_to_log::*;
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use radix_engine_common::math::RoundingMode as NativeRoundingMode;
+
+/// Defines the rounding strategy used when rounding a `Decimal`, mirrors
+/// the rounding modes of the Radix Engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum RoundingMode {
+    /// The number is always rounded toward positive infinity, e.g. `3.1 -> 4`, `-3.1 -> -3`.
+    ToPositiveInfinity,
+
+    /// The number is always rounded toward negative infinity, e.g. `3.1 -> 3`, `-3.1 -> -4`.
+    ToNegativeInfinity,
+
+    /// The number is always rounded toward zero, e.g. `3.1 -> 3`, `-3.1 -> -3`.
+    ToZero,
+
+    /// The number is always rounded away from zero, e.g. `3.1 -> 4`, `-3.1 -> -4`.
+    AwayFromZero,
+
+    /// The number is rounded to the nearest, and when it is halfway between two
+    /// others, it's rounded toward zero, e.g. `3.5 -> 3`, `-3.5 -> -3`.
+    ToNearestMidpointTowardZero,
+
+    /// The number is rounded to the nearest, and when it is halfway between two
+    /// others, it's rounded away from zero, e.g. `3.5 -> 4`, `-3.5 -> -4`.
+    ToNearestMidpointAwayFromZero,
+
+    /// The number is rounded to the nearest, and when it is halfway between two
+    /// others, it's rounded toward the nearest even number, e.g. `2.5 -> 2`, `3.5 -> 4`.
+    ToNearestMidpointToEven,
+}
+
+impl From<RoundingMode> for NativeRoundingMode {
+    fn from(value: RoundingMode) -> Self {
+        match value {
+            RoundingMode::ToPositiveInfinity => {
+                NativeRoundingMode::ToPositiveInfinity
+            }
+            RoundingMode::ToNegativeInfinity => {
+                NativeRoundingMode::ToNegativeInfinity
+            }
+            RoundingMode::ToZero => NativeRoundingMode::ToZero,
+            RoundingMode::AwayFromZero => NativeRoundingMode::AwayFromZero,
+            RoundingMode::ToNearestMidpointTowardZero => {
+                NativeRoundingMode::ToNearestMidpointTowardZero
+            }
+            RoundingMode::ToNearestMidpointAwayFromZero => {
+                NativeRoundingMode::ToNearestMidpointAwayFromZero
+            }
+            RoundingMode::ToNearestMidpointToEven => {
+                NativeRoundingMode::ToNearestMidpointToEven
+            }
+        }
+    }
+}
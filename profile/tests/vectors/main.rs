@@ -33,15 +33,21 @@ pub enum TestingError {
 
 /// `name` is file name without extension, assuming it is json file
 #[cfg(not(tarpaulin_include))]
-fn fixture<'a, T>(name: impl AsRef<OsStr>) -> Result<T, TestingError>
-where
-    T: for<'de> Deserialize<'de>,
-{
+fn fixture_string(name: impl AsRef<OsStr>) -> Result<String, TestingError> {
     let base = append_to_path(crate_dir(), "/tests/vectors/fixtures/");
     let base_file_path = append_to_path(base, name);
     let path = append_to_path(base_file_path, ".json");
     fs::read_to_string(path.clone())
         .map_err(|_| TestingError::FailedToOpenFile(path))
+}
+
+/// `name` is file name without extension, assuming it is json file
+#[cfg(not(tarpaulin_include))]
+fn fixture<'a, T>(name: impl AsRef<OsStr>) -> Result<T, TestingError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    fixture_string(name)
         .and_then(|j| {
             serde_json::Value::from_str(j.as_str())
                 .map_err(|_| TestingError::FailedDoesNotContainValidJSON(j))
@@ -65,6 +71,114 @@ mod profile_snapshot_tests {
             ProfileSnapshotVersion::V100
         );
     }
+
+    /// Wallet apps write UUIDs uppercased, we write them lowercased.
+    fn lowercase_uuids(json: serde_json::Value) -> serde_json::Value {
+        use serde_json::Value;
+        match json {
+            Value::String(s) if uuid::Uuid::try_parse(&s).is_ok() => {
+                Value::String(s.to_lowercase())
+            }
+            Value::Array(a) => {
+                Value::Array(a.into_iter().map(lowercase_uuids).collect())
+            }
+            Value::Object(o) => Value::Object(
+                o.into_iter()
+                    .map(|(k, v)| (k, lowercase_uuids(v)))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    #[test]
+    fn v100_100_persona_data_roundtrip_without_loss() {
+        let json = serde_json::Value::from_str(
+            &fixture_string("only_plaintext_profile_snapshot_version_100")
+                .unwrap(),
+        )
+        .unwrap();
+        let profile =
+            fixture::<Profile>("only_plaintext_profile_snapshot_version_100")
+                .unwrap();
+        let personas = profile
+            .networks
+            .into_iter()
+            .flat_map(|n| n.personas.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let persona_data_json = json["networks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|n| n["personas"].as_array().unwrap().clone())
+            .map(|p| p["personaData"].clone())
+            .collect::<Vec<_>>();
+        assert!(!personas.is_empty());
+        assert_eq!(personas.len(), persona_data_json.len());
+        personas.iter().zip(persona_data_json).for_each(|(p, j)| {
+            assert_eq!(
+                serde_json::to_value(&p.persona_data).unwrap(),
+                lowercase_uuids(j)
+            )
+        });
+    }
+
+    #[test]
+    fn v100_100_deleted_by_user_persona_is_hidden() {
+        let profile =
+            fixture::<Profile>("only_plaintext_profile_snapshot_version_100")
+                .unwrap();
+        assert_eq!(
+            profile
+                .hidden_personas_on_network(NetworkID::Stokenet)
+                .into_iter()
+                .map(|p| p.display_name.value)
+                .collect::<Vec<_>>(),
+            vec!["Stokeman".to_owned(), "Dan".to_owned()]
+        );
+    }
+
+    #[test]
+    fn v100_100_from_json_bytes() {
+        let json =
+            fixture_string("only_plaintext_profile_snapshot_version_100")
+                .unwrap();
+        assert_eq!(
+            Profile::from_json_bytes(json.as_bytes()).unwrap(),
+            fixture::<Profile>("only_plaintext_profile_snapshot_version_100")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn v99_unsupported() {
+        let json =
+            fixture_string("only_plaintext_profile_snapshot_version_100")
+                .unwrap()
+                .replace("\"snapshotVersion\": 100", "\"snapshotVersion\": 99");
+        assert_eq!(
+            Profile::from_json_bytes(json.as_bytes()),
+            Err(CommonError::UnsupportedProfileSnapshotVersion(99))
+        );
+    }
+
+    #[test]
+    fn v101_too_new() {
+        let json =
+            fixture_string("only_plaintext_profile_snapshot_version_100")
+                .unwrap()
+                .replace(
+                    "\"snapshotVersion\": 100",
+                    "\"snapshotVersion\": 101",
+                );
+        assert_eq!(
+            Profile::from_json_bytes(json.as_bytes()),
+            Err(CommonError::ProfileSnapshotVersionTooNew {
+                found: 101,
+                current: 100
+            })
+        );
+    }
 }
 
 #[cfg(test)]